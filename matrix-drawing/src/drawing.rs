@@ -56,6 +56,11 @@ pub fn draw_panels<D: DrawTarget<Color = Rgb888>>(
         return Ok(());
     }
 
+    let game_time = match state.tenths_in_period {
+        Some(tenths) => secs_to_tenths_time_string(state.secs_in_period, tenths),
+        None => secs_to_time_string(state.secs_in_period),
+    };
+
     let game_color = match state.timeout {
         TimeoutSnapshot::PenaltyShot(_) => RED,
         TimeoutSnapshot::Ref(_) => YELLOW,
@@ -69,7 +74,7 @@ pub fn draw_panels<D: DrawTarget<Color = Rgb888>>(
             | GamePeriod::PreOvertime
             | GamePeriod::OvertimeHalfTime
             | GamePeriod::PreSuddenDeath => YELLOW,
-            GamePeriod::SuddenDeath => RED,
+            GamePeriod::SuddenDeath | GamePeriod::Shootout => RED,
         },
     };
 
@@ -82,9 +87,61 @@ pub fn draw_panels<D: DrawTarget<Color = Rgb888>>(
 
     // EVERYTHING TO BE DISPLAYED ON THE CENTER 2 TIME PANELS
     match state.timeout {
+        TimeoutSnapshot::None if state.current_period == GamePeriod::Shootout => {
+            let (left_shootout, right_shootout, left_color, right_color) = if white_on_right {
+                (state.b_shootout, state.w_shootout, BLUE, WHITE)
+            } else {
+                (state.w_shootout, state.b_shootout, WHITE, BLUE)
+            };
+
+            let mut round_string = ArrayString::<16>::new();
+            write!(
+                &mut round_string,
+                "SHOOTOUT RND {}",
+                ShootoutTally::current_round(state.b_shootout, state.w_shootout)
+            )
+            .unwrap();
+
+            Text::with_text_style(
+                &round_string,
+                Point::new(127, 2),
+                MonoTextStyle::new(&FONT_7X15, game_color),
+                CENTERED,
+            )
+            .draw(display)?;
+
+            let mut goals_string = ArrayString::<3>::new();
+            write!(&mut goals_string, "{}", left_shootout.goals).unwrap();
+            Text::with_text_style(
+                &goals_string,
+                Point::new(115, 18),
+                MonoTextStyle::new(&FONT_20X46, left_color),
+                RIGHT_ALGN,
+            )
+            .draw(display)?;
+
+            Text::with_text_style(
+                "-",
+                Point::new(127, 18),
+                MonoTextStyle::new(&FONT_20X46, game_color),
+                CENTERED,
+            )
+            .draw(display)?;
+
+            goals_string.clear();
+            write!(&mut goals_string, "{}", right_shootout.goals).unwrap();
+            Text::with_text_style(
+                &goals_string,
+                Point::new(140, 18),
+                MonoTextStyle::new(&FONT_20X46, right_color),
+                LEFT_ALGN,
+            )
+            .draw(display)?;
+        }
+
         TimeoutSnapshot::None => {
             Text::with_text_style(
-                &secs_to_time_string(state.secs_in_period),
+                &game_time,
                 Point::new(127, 18),
                 MonoTextStyle::new(&FONT_20X46, game_color),
                 CENTERED,
@@ -102,6 +159,7 @@ pub fn draw_panels<D: DrawTarget<Color = Rgb888>>(
                 GamePeriod::OvertimeSecondHalf => "O/T 2ND HALF",
                 GamePeriod::PreSuddenDeath => "PRE-SUDDEN DEATH",
                 GamePeriod::SuddenDeath => "SUDDEN DEATH",
+                GamePeriod::Shootout => "SHOOTOUT",
             };
 
             Text::with_text_style(
@@ -116,7 +174,7 @@ pub fn draw_panels<D: DrawTarget<Color = Rgb888>>(
         _ => {
             // There is currently a timeout
             Text::with_text_style(
-                &secs_to_time_string(state.secs_in_period),
+                &game_time,
                 Point::new(152, 33),
                 MonoTextStyle::new(&FONT_14X31, game_color),
                 CENTERED,
@@ -134,6 +192,7 @@ pub fn draw_panels<D: DrawTarget<Color = Rgb888>>(
                 GamePeriod::OvertimeSecondHalf => "OT 2ND\nHALF",
                 GamePeriod::PreSuddenDeath => "PRE SD\nBREAK",
                 GamePeriod::SuddenDeath => "SUDDEN\nDEATH",
+                GamePeriod::Shootout => "SHOOT\nOUT",
             };
 
             Text::with_text_style(
@@ -221,6 +280,8 @@ pub fn draw_panels<D: DrawTarget<Color = Rgb888>>(
 
     let left_penalties;
     let right_penalties;
+    let left_hidden;
+    let right_hidden;
     let left_score;
     let right_score;
     let left_color;
@@ -229,6 +290,8 @@ pub fn draw_panels<D: DrawTarget<Color = Rgb888>>(
     if white_on_right {
         left_penalties = state.b_penalties;
         right_penalties = state.w_penalties;
+        left_hidden = state.b_hidden_penalties;
+        right_hidden = state.w_hidden_penalties;
         left_score = state.b_score;
         right_score = state.w_score;
         left_color = BLUE;
@@ -236,6 +299,8 @@ pub fn draw_panels<D: DrawTarget<Color = Rgb888>>(
     } else {
         left_penalties = state.w_penalties;
         right_penalties = state.b_penalties;
+        left_hidden = state.w_hidden_penalties;
+        right_hidden = state.b_hidden_penalties;
         left_score = state.w_score;
         right_score = state.b_score;
         left_color = WHITE;
@@ -305,27 +370,46 @@ pub fn draw_panels<D: DrawTarget<Color = Rgb888>>(
     };
 
     // Define layout for Penalties
-    let mut draw_penalty =
-        |x_pos: i32, y_pos: i32, color, penalty: &PenaltySnapshot| -> Result<(), D::Error> {
-            let mut penalty_string = ArrayString::<3>::new();
-            write!(&mut penalty_string, "#{}", penalty.player_number).unwrap();
-            Text::with_text_style(
-                &penalty_string,
-                Point::new(x_pos, y_pos),
-                MonoTextStyle::new(&FONT_5X8, color),
-                CENTERED,
-            )
-            .draw(display)?;
-            let time: ArrayString<4> = match penalty.time {
-                PenaltyTime::Seconds(secs) => {
-                    ArrayString::from(secs_to_time_string(secs).trim()).unwrap()
-                }
-                PenaltyTime::TotalDismissal => ArrayString::from("DSMS").unwrap(),
-            };
+    let draw_penalty = |display: &mut D,
+                        x_pos: i32,
+                        y_pos: i32,
+                        color,
+                        penalty: &PenaltySnapshot|
+     -> Result<(), D::Error> {
+        let mut penalty_string = ArrayString::<3>::new();
+        write!(&mut penalty_string, "#{}", penalty.player_number).unwrap();
+        Text::with_text_style(
+            &penalty_string,
+            Point::new(x_pos, y_pos),
+            MonoTextStyle::new(&FONT_5X8, color),
+            CENTERED,
+        )
+        .draw(display)?;
+        let time: ArrayString<4> = match penalty.time {
+            PenaltyTime::Seconds(secs) => {
+                ArrayString::from(secs_to_time_string(secs).trim()).unwrap()
+            }
+            PenaltyTime::TotalDismissal => ArrayString::from("DSMS").unwrap(),
+        };
+        Text::with_text_style(
+            &time,
+            Point::new(x_pos, y_pos + 8),
+            MonoTextStyle::new(&FONT_5X8, RED),
+            CENTERED,
+        )
+        .draw(display)?;
+        Ok(())
+    };
+
+    // Shown in the slot after the last penalty when some are on other pages
+    let draw_hidden =
+        |display: &mut D, x_pos: i32, y_pos: i32, hidden: u8| -> Result<(), D::Error> {
+            let mut hidden_string = ArrayString::<3>::new();
+            write!(&mut hidden_string, "+{}", hidden).unwrap();
             Text::with_text_style(
-                &time,
-                Point::new(x_pos, y_pos + 8),
-                MonoTextStyle::new(&FONT_5X8, RED),
+                &hidden_string,
+                Point::new(x_pos, y_pos + 4),
+                MonoTextStyle::new(&FONT_5X8, YELLOW),
                 CENTERED,
             )
             .draw(display)?;
@@ -336,18 +420,26 @@ pub fn draw_panels<D: DrawTarget<Color = Rgb888>>(
     if left_score < 10 {
         // Vertical Penalties (Up to 3) - Justified Left/Outside/Away from Time Panels
         // Penalties "Fall-Off" the Bottom as they run out
+        let x_pos = 15;
         for (i, penalty) in [0i32, 1, 2].into_iter().zip(left_penalties.iter()) {
-            let x_pos = 15;
             let y_pos = 47 - i * 22;
-            draw_penalty(x_pos, y_pos, left_color, penalty)?;
+            draw_penalty(display, x_pos, y_pos, left_color, penalty)?;
+        }
+        if left_hidden > 0 {
+            let y_pos = 47 - left_penalties.len() as i32 * 22;
+            draw_hidden(display, x_pos, y_pos, left_hidden)?;
         }
     } else {
         // Horizontal Penalties (Up to 2) - Justified Left/Outside/Away from Time Panels
         // Penalties "Fall-Off" the Left Side as they run out
+        let y_pos = 47;
         for (i, penalty) in [0i32, 1].into_iter().zip(left_penalties.iter()) {
             let x_pos = 17 + i * 29;
-            let y_pos = 47;
-            draw_penalty(x_pos, y_pos, left_color, penalty)?;
+            draw_penalty(display, x_pos, y_pos, left_color, penalty)?;
+        }
+        if left_hidden > 0 {
+            let x_pos = 17 + left_penalties.len() as i32 * 29;
+            draw_hidden(display, x_pos, y_pos, left_hidden)?;
         }
     }
 
@@ -355,23 +447,161 @@ pub fn draw_panels<D: DrawTarget<Color = Rgb888>>(
     if right_score < 10 {
         // Vertical Penalties (Up to 3) - Justified Right/Outside/Away from Time Panels
         // Penalties "Fall-Off" the Bottom as they run out
+        let x_pos = 240;
         for (i, penalty) in [0i32, 1, 2].into_iter().zip(right_penalties.iter()) {
-            let x_pos = 240;
             let y_pos = 47i32 - i * 22i32;
-            draw_penalty(x_pos, y_pos, right_color, penalty)?;
+            draw_penalty(display, x_pos, y_pos, right_color, penalty)?;
+        }
+        if right_hidden > 0 {
+            let y_pos = 47 - right_penalties.len() as i32 * 22;
+            draw_hidden(display, x_pos, y_pos, right_hidden)?;
         }
     } else {
         // Horizontal Penalties (Up to 2) - Justified Right/Outside/Away from Time Panels
         // Penalties "Fall-Off" the Right Side as they run out
+        let y_pos = 47;
         for (i, penalty) in [0i32, 1].into_iter().zip(right_penalties.iter()) {
             let x_pos = 238 - i * 29;
-            let y_pos = 47;
-            draw_penalty(x_pos, y_pos, right_color, penalty)?;
+            draw_penalty(display, x_pos, y_pos, right_color, penalty)?;
+        }
+        if right_hidden > 0 {
+            let x_pos = 238 - right_penalties.len() as i32 * 29;
+            draw_hidden(display, x_pos, y_pos, right_hidden)?;
         }
     }
     Ok(())
 }
 
+/// The number of penalty rows shown for each team by `draw_penalty_box`
+pub const PENALTY_BOX_ROWS: usize = 6;
+
+/// Draws the full penalty lists for both teams, with the cap number, infraction and time remaining
+/// of each penalty, for the penalty box official. Assumes the display is 256x64
+///
+/// Penalties that have run out are not shown. If a team has more penalties than fit, the last row
+/// shows how many are hidden
+#[cfg(feature = "std")]
+pub fn draw_penalty_box<D: DrawTarget<Color = Rgb888>>(
+    display: &mut D,
+    state: &GameSnapshot,
+    white_on_right: bool,
+) -> Result<(), D::Error> {
+    const RED: Rgb888 = Rgb888::RED;
+    const YELLOW: Rgb888 = Rgb888::YELLOW;
+    const BLUE: Rgb888 = Rgb888::new(64, 128, 255);
+    const WHITE: Rgb888 = Rgb888::WHITE;
+
+    const CENTERED: TextStyle = TextStyleBuilder::new()
+        .alignment(Alignment::Center)
+        .baseline(Baseline::Top)
+        .line_height(LineHeight::Percent(100))
+        .build();
+
+    const LEFT_ALGN: TextStyle = TextStyleBuilder::new()
+        .alignment(Alignment::Left)
+        .baseline(Baseline::Top)
+        .line_height(LineHeight::Percent(100))
+        .build();
+
+    const RIGHT_ALGN: TextStyle = TextStyleBuilder::new()
+        .alignment(Alignment::Right)
+        .baseline(Baseline::Top)
+        .line_height(LineHeight::Percent(100))
+        .build();
+
+    let mut draw_team = |x_pos: i32,
+                         name: &str,
+                         color: Rgb888,
+                         penalties: &[PenaltySnapshot]|
+     -> Result<(), D::Error> {
+        Text::with_text_style(
+            name,
+            Point::new(x_pos + 64, 0),
+            MonoTextStyle::new(&FONT_7X15, color),
+            CENTERED,
+        )
+        .draw(display)?;
+
+        let mut penalties: Vec<_> = penalties
+            .iter()
+            .filter(|pen| pen.time != PenaltyTime::Seconds(0))
+            .collect();
+        penalties.sort_by_key(|pen| pen.time);
+
+        let shown = if penalties.len() > PENALTY_BOX_ROWS {
+            PENALTY_BOX_ROWS - 1
+        } else {
+            penalties.len()
+        };
+
+        for (i, penalty) in penalties.iter().take(shown).enumerate() {
+            let y_pos = 16 + i as i32 * 8;
+
+            let mut number = ArrayString::<4>::new();
+            write!(&mut number, "#{}", penalty.player_number).unwrap();
+            Text::with_text_style(
+                &number,
+                Point::new(x_pos + 2, y_pos),
+                MonoTextStyle::new(&FONT_5X8, color),
+                LEFT_ALGN,
+            )
+            .draw(display)?;
+
+            let mut infraction = ArrayString::<15>::new();
+            for c in penalty.infraction.short_name().chars() {
+                if infraction.try_push(c.to_ascii_uppercase()).is_err() {
+                    break;
+                }
+            }
+            Text::with_text_style(
+                &infraction,
+                Point::new(x_pos + 22, y_pos),
+                MonoTextStyle::new(&FONT_5X8, YELLOW),
+                LEFT_ALGN,
+            )
+            .draw(display)?;
+
+            let time: ArrayString<5> = match penalty.time {
+                PenaltyTime::Seconds(secs) => {
+                    ArrayString::from(secs_to_time_string(secs).trim()).unwrap()
+                }
+                PenaltyTime::TotalDismissal => ArrayString::from("DSMS").unwrap(),
+            };
+            Text::with_text_style(
+                &time,
+                Point::new(x_pos + 126, y_pos),
+                MonoTextStyle::new(&FONT_5X8, RED),
+                RIGHT_ALGN,
+            )
+            .draw(display)?;
+        }
+
+        if shown < penalties.len() {
+            let mut more = ArrayString::<10>::new();
+            write!(&mut more, "+{} MORE", penalties.len() - shown).unwrap();
+            Text::with_text_style(
+                &more,
+                Point::new(x_pos + 64, 16 + shown as i32 * 8),
+                MonoTextStyle::new(&FONT_5X8, color),
+                CENTERED,
+            )
+            .draw(display)?;
+        }
+
+        Ok(())
+    };
+
+    if white_on_right {
+        draw_team(0, "BLACK", BLUE, &state.b_penalties)?;
+        draw_team(128, "WHITE", WHITE, &state.w_penalties)?;
+    } else {
+        draw_team(0, "WHITE", WHITE, &state.w_penalties)?;
+        draw_team(128, "BLACK", BLUE, &state.b_penalties)?;
+    }
+
+    Ok(())
+}
+
 pub fn secs_to_time_string<T>(secs: T) -> ArrayString<5>
 where
     T: Div<T> + Rem<T> + From<u16> + Copy + Ord + Debug,
@@ -409,3 +639,133 @@ where
     write!(&mut time_string, ":{:02}", secs).unwrap();
     time_string
 }
+
+/// Formats a time in the final minute of a period, including the tenths of a second. Values that
+/// are out of range are clamped to 59.9
+pub fn secs_to_tenths_time_string<T>(secs: T, tenths: u8) -> ArrayString<5>
+where
+    T: From<u8> + Ord + Copy + Display + Debug,
+{
+    let secs = secs.min(T::from(59u8));
+    let tenths = tenths.min(9);
+    let mut time_string = ArrayString::new();
+    write!(&mut time_string, "{:2}.{}", secs, tenths).unwrap();
+    time_string
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use super::*;
+    use core::convert::Infallible;
+
+    const WIDTH: usize = 256;
+    const HEIGHT: usize = 64;
+
+    struct TestDisplay {
+        pixels: Vec<Rgb888>,
+    }
+
+    impl TestDisplay {
+        fn new() -> Self {
+            Self {
+                pixels: vec![Rgb888::BLACK; WIDTH * HEIGHT],
+            }
+        }
+
+        fn region(&self, x: core::ops::Range<usize>, y: core::ops::Range<usize>) -> Vec<Rgb888> {
+            y.flat_map(|y| self.pixels[y * WIDTH + x.start..y * WIDTH + x.end].to_vec())
+                .collect()
+        }
+    }
+
+    impl OriginDimensions for TestDisplay {
+        fn size(&self) -> Size {
+            Size::new(WIDTH as u32, HEIGHT as u32)
+        }
+    }
+
+    impl DrawTarget for TestDisplay {
+        type Color = Rgb888;
+        type Error = Infallible;
+
+        fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Pixel<Self::Color>>,
+        {
+            for Pixel(point, color) in pixels {
+                if (0..WIDTH as i32).contains(&point.x) && (0..HEIGHT as i32).contains(&point.y) {
+                    self.pixels[point.y as usize * WIDTH + point.x as usize] = color;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    fn penalty(player_number: u8, secs: u16) -> PenaltySnapshot {
+        PenaltySnapshot {
+            player_number,
+            time: PenaltyTime::Seconds(secs),
+            infraction: Infraction::Unknown,
+        }
+    }
+
+    #[test]
+    fn test_draw_penalty_box() {
+        const BLUE: Rgb888 = Rgb888::new(64, 128, 255);
+
+        // Seven running penalties and one that has run out for black, one for white
+        let state = GameSnapshot {
+            b_penalties: vec![
+                penalty(1, 60),
+                penalty(2, 0),
+                penalty(3, 70),
+                penalty(4, 80),
+                penalty(5, 90),
+                penalty(6, 100),
+                penalty(7, 110),
+                penalty(8, 120),
+            ],
+            w_penalties: vec![penalty(9, 30)],
+            ..Default::default()
+        };
+
+        let mut display = TestDisplay::new();
+        draw_penalty_box(&mut display, &state, false).unwrap();
+
+        // White is on the left, black on the right
+        let white_name = display.region(0..128, 0..16);
+        assert!(white_name.contains(&Rgb888::WHITE));
+        assert!(!white_name.contains(&BLUE));
+        let black_name = display.region(128..256, 0..16);
+        assert!(black_name.contains(&BLUE));
+        assert!(!black_name.contains(&Rgb888::WHITE));
+
+        // White's single penalty uses one row
+        assert!(display.region(0..128, 16..24).contains(&Rgb888::RED));
+        assert!(display
+            .region(0..128, 24..64)
+            .iter()
+            .all(|&pixel| pixel == Rgb888::BLACK));
+
+        // Black's expired penalty is dropped, and the last row shows the two that don't fit
+        for row in 0..PENALTY_BOX_ROWS - 1 {
+            let y = 16 + row * 8;
+            assert!(display.region(128..256, y..y + 8).contains(&Rgb888::RED));
+        }
+        let more_row = display.region(128..256, 56..64);
+        assert!(more_row.contains(&BLUE));
+        assert!(!more_row.contains(&Rgb888::RED));
+
+        // Swapping sides swaps the halves of the display
+        let mut swapped = TestDisplay::new();
+        draw_penalty_box(&mut swapped, &state, true).unwrap();
+        assert_eq!(
+            swapped.region(0..128, 0..64),
+            display.region(128..256, 0..64)
+        );
+        assert_eq!(
+            swapped.region(128..256, 0..64),
+            display.region(0..128, 0..64)
+        );
+    }
+}
@@ -11,14 +11,26 @@ pub struct TransmittedData {
 
 impl TransmittedData {
     pub const ENCODED_LEN: usize = GameSnapshotNoHeap::ENCODED_LEN + 1;
+    pub const ENCODED_LEN_V2: usize = GameSnapshotNoHeap::ENCODED_LEN_V2 + 1;
+
+    fn encode_flags(&self) -> u8 {
+        ((self.flash as u8) << 1) | self.white_on_right as u8
+    }
 
     pub fn encode(&self) -> Result<[u8; Self::ENCODED_LEN], EncodingError> {
         let mut val = [0u8; Self::ENCODED_LEN];
-        val[0] = ((self.flash as u8) << 1) | self.white_on_right as u8;
+        val[0] = self.encode_flags();
         val[1..].copy_from_slice(&self.snapshot.encode()?);
         Ok(val)
     }
 
+    pub fn encode_v2(&self) -> Result<[u8; Self::ENCODED_LEN_V2], EncodingError> {
+        let mut val = [0u8; Self::ENCODED_LEN_V2];
+        val[0] = self.encode_flags();
+        val[1..].copy_from_slice(&self.snapshot.encode_v2()?);
+        Ok(val)
+    }
+
     pub fn decode(bytes: &[u8; Self::ENCODED_LEN]) -> Result<Self, DecodingError> {
         Ok(Self {
             white_on_right: bytes[0] & 0x01 != 0,
@@ -30,6 +42,18 @@ impl TransmittedData {
             ])?,
         })
     }
+
+    pub fn decode_v2(bytes: &[u8; Self::ENCODED_LEN_V2]) -> Result<Self, DecodingError> {
+        Ok(Self {
+            white_on_right: bytes[0] & 0x01 != 0,
+            flash: bytes[0] & 0x02 != 0,
+            snapshot: GameSnapshotNoHeap::decode_v2(array_ref![
+                bytes,
+                1,
+                GameSnapshotNoHeap::ENCODED_LEN_V2
+            ])?,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -37,7 +61,7 @@ mod test {
     use super::*;
     use arrayvec::ArrayVec;
     use uwh_common::game_snapshot::{
-        GamePeriod, Infraction, PenaltySnapshot, PenaltyTime, TimeoutSnapshot,
+        GamePeriod, Infraction, PenaltySnapshot, PenaltyTime, ShootoutTally, TimeoutSnapshot,
     };
 
     #[test]
@@ -45,12 +69,17 @@ mod test {
         let state = GameSnapshotNoHeap {
             current_period: GamePeriod::BetweenGames,
             secs_in_period: 0,
+            tenths_in_period: None,
             timeout: TimeoutSnapshot::None,
             b_score: 0,
             w_score: 0,
             b_penalties: ArrayVec::new(),
             w_penalties: ArrayVec::new(),
+            b_hidden_penalties: 0,
+            w_hidden_penalties: 0,
             is_old_game: true,
+            b_shootout: ShootoutTally::default(),
+            w_shootout: ShootoutTally::default(),
         };
 
         let mut data = TransmittedData {
@@ -67,6 +96,14 @@ mod test {
                 TransmittedData::ENCODED_LEN
             ])?;
             assert_eq!(data, &mut recreated);
+
+            let serialization = data.encode_v2()?;
+            let mut recreated = TransmittedData::decode_v2(array_ref![
+                serialization,
+                0,
+                TransmittedData::ENCODED_LEN_V2
+            ])?;
+            assert_eq!(data, &mut recreated);
             Ok(())
         };
 
@@ -99,6 +136,24 @@ mod test {
 
         test_data(&mut data)?;
 
+        data.snapshot.secs_in_period = 42;
+        data.snapshot.tenths_in_period = Some(3);
+
+        let serialization = data.encode_v2()?;
+        let recreated = TransmittedData::decode_v2(array_ref![
+            serialization,
+            0,
+            TransmittedData::ENCODED_LEN_V2
+        ])?;
+        assert_eq!(data, recreated);
+
+        // The legacy frame does not carry the tenths of a second
+        let serialization = data.encode()?;
+        let recreated =
+            TransmittedData::decode(array_ref![serialization, 0, TransmittedData::ENCODED_LEN])?;
+        data.snapshot.tenths_in_period = None;
+        assert_eq!(data, recreated);
+
         Ok(())
     }
 }
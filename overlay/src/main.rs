@@ -97,6 +97,24 @@ impl State {
         }
         self.snapshot = recieved_state.snapshot;
     }
+
+    /// The start time to show, pushed back by any delay to the schedule from suspended sessions
+    fn start_time_text(&self) -> String {
+        if self.start_time.is_empty() {
+            return String::new();
+        }
+        let delay_mins = (self.snapshot.schedule_delay_secs + 30) / 60;
+        let start_time = self
+            .start_time
+            .split_once(':')
+            .and_then(|(h, m)| Some((h.parse::<u32>().ok()?, m.parse::<u32>().ok()?)))
+            .map(|(h, m)| {
+                let mins = (h * 60 + m + delay_mins) % (24 * 60);
+                format!("{:02}:{:02}", mins / 60, mins % 60)
+            })
+            .unwrap_or_else(|| self.start_time.clone());
+        format!("START: {start_time}")
+    }
 }
 
 /// processed, non serialisable version of `network::MemberRaw`
@@ -320,7 +338,8 @@ async fn main() {
             | GamePeriod::OvertimeSecondHalf
             | GamePeriod::PreOvertime
             | GamePeriod::PreSuddenDeath
-            | GamePeriod::SuddenDeath => {
+            | GamePeriod::SuddenDeath
+            | GamePeriod::Shootout => {
                 renderer.overtime_and_sudden_death_display(&local_state);
                 flag_renderer.draw();
             }
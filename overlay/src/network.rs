@@ -154,6 +154,7 @@ pub struct StatePacket {
 #[derive(Clone, Serialize, Deserialize)]
 pub struct GameData {
     pub pool: String,
+    /// The published start time, as `HH:MM`
     pub start_time: String,
     pub referees: Vec<MemberRaw>,
     pub black: TeamInfoRaw,
@@ -197,7 +198,7 @@ async fn fetch_game_data(
                 .unwrap_or_default();
             let start_time = data["game"]["start_time"]
                 .as_str()
-                .map(|s| String::from("START: ") + s.split_at(11).1.split_at(5).0)
+                .map(|s| s.split_at(11).1.split_at(5).0.to_string())
                 .unwrap_or_default();
             let sponsor_logo = get_image_from_opt_url(data["game"]["sponsor_logo"].as_str()).await;
             let mut referees = Vec::new();
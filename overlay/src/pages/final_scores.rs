@@ -107,7 +107,7 @@ impl PageRenderer {
         );
         let (x_off, text) = fit_text(
             248f32,
-            &state.start_time,
+            &state.start_time_text(),
             25,
             self.assets.font,
             Justify::Center,
@@ -359,19 +359,23 @@ impl PageRenderer {
         draw_texture_both!(self.assets.time_and_game_state, 367f32, 18f32, WHITE);
         let min = state.snapshot.secs_in_period / 60;
         let secs = state.snapshot.secs_in_period % 60;
-        let text = format!(
-            "{}:{}",
-            if min < 10 {
-                format!("0{min}")
-            } else {
-                format!("{min}")
-            },
-            if secs < 10 {
-                format!("0{secs}")
-            } else {
-                format!("{secs}")
-            }
-        );
+        let text = if let Some(tenths) = state.snapshot.tenths_in_period {
+            format!("{secs}.{tenths}")
+        } else {
+            format!(
+                "{}:{}",
+                if min < 10 {
+                    format!("0{min}")
+                } else {
+                    format!("{min}")
+                },
+                if secs < 10 {
+                    format!("0{secs}")
+                } else {
+                    format!("{secs}")
+                }
+            )
+        };
         let (x_off, text) = fit_text(180f32, &text, 50, self.assets.font, Justify::Center);
         draw_text_ex(
             text.as_str(),
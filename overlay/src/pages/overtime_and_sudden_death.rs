@@ -9,11 +9,13 @@ use crate::pages::Justify;
 use crate::State;
 use coarsetime::Instant;
 use macroquad::prelude::*;
+use uwh_common::game_snapshot::Color as GameColor;
 use uwh_common::game_snapshot::GamePeriod;
+use uwh_common::game_snapshot::ShootoutTally;
 use uwh_common::game_snapshot::TimeoutSnapshot;
 
 impl PageRenderer {
-    /// Display during overtime. Has no animations
+    /// Display during overtime, sudden death and shootouts. Has no animations
     pub fn overtime_and_sudden_death_display(&mut self, state: &State) {
         let mut time = Instant::now()
             .duration_since(self.animation_register2)
@@ -228,19 +230,35 @@ impl PageRenderer {
         draw_texture_both!(self.assets.time_and_game_state, 367f32, 18f32, WHITE);
         let min = state.snapshot.secs_in_period / 60;
         let secs = state.snapshot.secs_in_period % 60;
-        let text = format!(
-            "{}:{}",
-            if min < 10 {
-                format!("0{min}")
-            } else {
-                format!("{min}")
-            },
-            if secs < 10 {
-                format!("0{secs}")
-            } else {
-                format!("{secs}")
-            }
-        );
+        let shootout = state
+            .snapshot
+            .shootout
+            .as_ref()
+            .filter(|_| state.snapshot.current_period == GamePeriod::Shootout);
+        let text = if let Some(shootout) = shootout {
+            // White is on top in the team bar, so it goes first here too
+            format!(
+                "W {} - {} B",
+                shootout.tally(GameColor::White).goals,
+                shootout.tally(GameColor::Black).goals
+            )
+        } else if let Some(tenths) = state.snapshot.tenths_in_period {
+            format!("{secs}.{tenths}")
+        } else {
+            format!(
+                "{}:{}",
+                if min < 10 {
+                    format!("0{min}")
+                } else {
+                    format!("{min}")
+                },
+                if secs < 10 {
+                    format!("0{secs}")
+                } else {
+                    format!("{secs}")
+                }
+            )
+        };
         let (x_off, text) = fit_text(180f32, &text, 50, self.assets.font, Justify::Center);
         draw_text_ex(
             text.as_str(),
@@ -249,8 +267,12 @@ impl PageRenderer {
             TextParams {
                 font: self.assets.font,
                 font_size: 50,
-                color: if [GamePeriod::SuddenDeath, GamePeriod::PreSuddenDeath]
-                    .contains(&state.snapshot.current_period)
+                color: if [
+                    GamePeriod::SuddenDeath,
+                    GamePeriod::PreSuddenDeath,
+                    GamePeriod::Shootout,
+                ]
+                .contains(&state.snapshot.current_period)
                 {
                     GOLD
                 } else {
@@ -260,14 +282,21 @@ impl PageRenderer {
             },
         );
         let ot_text = match state.snapshot.current_period {
-            GamePeriod::OvertimeFirstHalf => "OVERTIME 1ST HALF",
-            GamePeriod::OvertimeSecondHalf => "OVERTIME 2ND HALF",
-            GamePeriod::OvertimeHalfTime => "OVERTIME HALF TIME",
-            GamePeriod::SuddenDeath => "SUDDEN DEATH",
-            GamePeriod::PreSuddenDeath => "PRE SUDDEN DEATH",
-            _ => "PRE OVERTIME",
+            GamePeriod::OvertimeFirstHalf => "OVERTIME 1ST HALF".to_string(),
+            GamePeriod::OvertimeSecondHalf => "OVERTIME 2ND HALF".to_string(),
+            GamePeriod::OvertimeHalfTime => "OVERTIME HALF TIME".to_string(),
+            GamePeriod::SuddenDeath => "SUDDEN DEATH".to_string(),
+            GamePeriod::PreSuddenDeath => "PRE SUDDEN DEATH".to_string(),
+            GamePeriod::Shootout => format!(
+                "SHOOTOUT ROUND {}",
+                shootout.map_or(1, |shootout| ShootoutTally::current_round(
+                    shootout.tally(GameColor::Black),
+                    shootout.tally(GameColor::White)
+                ))
+            ),
+            _ => "PRE OVERTIME".to_string(),
         };
-        let (x_off, text) = fit_text(200f32, ot_text, 20, self.assets.font, Justify::Center);
+        let (x_off, text) = fit_text(200f32, &ot_text, 20, self.assets.font, Justify::Center);
         draw_text_ex(
             text.as_str(),
             420f32 + x_off,
@@ -275,8 +304,12 @@ impl PageRenderer {
             TextParams {
                 font: self.assets.font,
                 font_size: 20,
-                color: if [GamePeriod::SuddenDeath, GamePeriod::PreSuddenDeath]
-                    .contains(&state.snapshot.current_period)
+                color: if [
+                    GamePeriod::SuddenDeath,
+                    GamePeriod::PreSuddenDeath,
+                    GamePeriod::Shootout,
+                ]
+                .contains(&state.snapshot.current_period)
                 {
                     GOLD
                 } else {
@@ -295,7 +295,7 @@ pub fn draw(renderer: &mut PageRenderer, state: &State) {
     );
     let (x_off, text) = fit_text(
         248f32,
-        &state.start_time,
+        &state.start_time_text(),
         25,
         renderer.assets.font,
         Justify::Center,
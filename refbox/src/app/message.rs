@@ -1,4 +1,5 @@
-use crate::tournament_manager::penalty::PenaltyKind;
+use crate::{config::Role, tournament_manager::penalty::PenaltyKind};
+use iced::keyboard::KeyCode;
 use tokio::time::Duration;
 use uwh_common::{
     game_snapshot::{Color as GameColor, GameSnapshot, Infraction},
@@ -9,16 +10,25 @@ use uwh_common::{
 pub enum Message {
     Init,
     NewSnapshot(GameSnapshot),
+    WindowResized {
+        width: u32,
+        height: u32,
+    },
     EditTime,
     ChangeTime {
         increase: bool,
         secs: u64,
         timeout: bool,
     },
+    ChangePeriod {
+        forward: bool,
+    },
     TimeEditComplete {
         canceled: bool,
     },
     StartPlayNow,
+    EndPractice,
+    EndPracticeHalf,
     EditScores,
     AddNewScore(GameColor),
     ChangeScore {
@@ -28,6 +38,13 @@ pub enum Message {
     ScoreEditComplete {
         canceled: bool,
     },
+    GoalTimeline,
+    GoalEditComplete {
+        canceled: bool,
+        deleted: bool,
+    },
+    GoalTimelineComplete,
+    ExportDisciplineReport,
     PenaltyOverview,
     WarningOverview,
     FoulOverview,
@@ -67,7 +84,24 @@ pub enum Message {
     AddScoreComplete {
         canceled: bool,
     },
+    PenaltyShotOutcomeComplete {
+        canceled: bool,
+        scored: bool,
+    },
+    ShootoutShotComplete {
+        canceled: bool,
+        scored: bool,
+    },
     ShowGameDetails,
+    ShowTraining,
+    NextTrainingProgram,
+    StartStopTraining,
+    ResetTraining,
+    TrainingTick,
+    TrainingComplete,
+    SuspendSession,
+    SuspensionTick,
+    ResumeSession,
     ShowWarnings,
     EditGameConfig,
     ChangeConfigPage(ConfigPage),
@@ -91,6 +125,7 @@ pub enum Message {
     PenaltyShot(bool),
     EndTimeout,
     ConfirmScores(GameSnapshot),
+    SuddenDeathLimitReached(GameSnapshot),
     ScoreConfirmation {
         correct: bool,
     },
@@ -100,6 +135,12 @@ pub enum Message {
     RecvGame(GameInfo),
     StopClock,
     StartClock,
+    KeyPressed(KeyCode),
+    KeyReleased(KeyCode),
+    WindowUnfocused,
+    PinEntryComplete {
+        canceled: bool,
+    },
     NoAction, // TODO: Remove once UI is functional
 }
 
@@ -107,7 +148,9 @@ impl Message {
     pub fn is_repeatable(&self) -> bool {
         match self {
             Self::NewSnapshot(_)
+            | Self::WindowResized { .. }
             | Self::ChangeTime { .. }
+            | Self::ChangePeriod { .. }
             | Self::FoulSelectExpanded(_)
             | Self::ChangeScore { .. }
             | Self::Scroll { .. }
@@ -118,15 +161,28 @@ impl Message {
             | Self::RecvTournament(_)
             | Self::RecvGameList(_)
             | Self::RecvGame(_)
+            | Self::KeyPressed(_)
+            | Self::KeyReleased(_)
+            | Self::WindowUnfocused
+            | Self::NextTrainingProgram
+            | Self::StartStopTraining
+            | Self::TrainingTick
+            | Self::SuspensionTick
             | Self::NoAction => true,
 
             Self::Init
             | Self::EditTime
             | Self::TimeEditComplete { .. }
             | Self::StartPlayNow
+            | Self::EndPractice
+            | Self::EndPracticeHalf
             | Self::EditScores
             | Self::AddNewScore(_)
             | Self::ScoreEditComplete { .. }
+            | Self::GoalTimeline
+            | Self::GoalEditComplete { .. }
+            | Self::GoalTimelineComplete
+            | Self::ExportDisciplineReport
             | Self::PenaltyOverview
             | Self::WarningOverview
             | Self::FoulOverview
@@ -141,7 +197,14 @@ impl Message {
             | Self::KeypadPage(_)
             | Self::ChangeColor(_)
             | Self::AddScoreComplete { .. }
+            | Self::PenaltyShotOutcomeComplete { .. }
+            | Self::ShootoutShotComplete { .. }
             | Self::ShowGameDetails
+            | Self::ShowTraining
+            | Self::ResetTraining
+            | Self::TrainingComplete
+            | Self::SuspendSession
+            | Self::ResumeSession
             | Self::ShowWarnings
             | Self::EditGameConfig
             | Self::ChangeConfigPage(_)
@@ -159,9 +222,61 @@ impl Message {
             | Self::PenaltyShot(_)
             | Self::EndTimeout
             | Self::ConfirmScores(_)
+            | Self::SuddenDeathLimitReached(_)
             | Self::ScoreConfirmation { .. }
             | Self::StopClock
-            | Self::StartClock => false,
+            | Self::StartClock
+            | Self::PinEntryComplete { .. } => false,
+        }
+    }
+}
+
+impl Message {
+    /// The action that must be unlocked by the settings lock before this message is handled
+    pub fn protected_action(&self) -> Option<ProtectedAction> {
+        match self {
+            Self::EditGameConfig => Some(ProtectedAction::EditGameConfig),
+            Self::EditScores => Some(ProtectedAction::EditScores),
+            Self::ConfirmationSelected(ConfirmationOption::ChangePeriod) => {
+                Some(ProtectedAction::ChangePeriod)
+            }
+            Self::ConfirmationSelected(ConfirmationOption::EndGameEarly) => {
+                Some(ProtectedAction::EndGameEarly)
+            }
+            Self::SuspendSession => Some(ProtectedAction::SuspendSession),
+            _ => None,
+        }
+    }
+}
+
+/// Actions that can be protected by the settings lock
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProtectedAction {
+    EditGameConfig,
+    EditScores,
+    ChangePeriod,
+    EndGameEarly,
+    SuspendSession,
+}
+
+impl ProtectedAction {
+    pub fn required_role(&self) -> Role {
+        match self {
+            Self::EditGameConfig
+            | Self::EditScores
+            | Self::ChangePeriod
+            | Self::EndGameEarly
+            | Self::SuspendSession => Role::ChiefReferee,
+        }
+    }
+
+    pub fn message(&self) -> Message {
+        match self {
+            Self::EditGameConfig => Message::EditGameConfig,
+            Self::EditScores => Message::EditScores,
+            Self::ChangePeriod => Message::ConfirmationSelected(ConfirmationOption::ChangePeriod),
+            Self::EndGameEarly => Message::ConfirmationSelected(ConfirmationOption::EndGameEarly),
+            Self::SuspendSession => Message::SuspendSession,
         }
     }
 }
@@ -186,6 +301,9 @@ pub enum LengthParameter {
     OvertimeHalf,
     OvertimeHalfTime,
     PreSuddenDeath,
+    SuddenDeathMax,
+    TdTeamShort,
+    StopClock,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -199,6 +317,10 @@ pub enum ListableParameter {
 pub enum BoolGameParameter {
     OvertimeAllowed,
     SuddenDeathAllowed,
+    ShootoutAllowed,
+    StopClockInOvertime,
+    StopClockAtEveryWhistle,
+    PracticeMode,
     WhiteOnRight,
     UsingUwhScores,
     SoundEnabled,
@@ -209,6 +331,8 @@ pub enum BoolGameParameter {
     ScorerCapNum,
     FoulsAndWarnings,
     TeamWarning,
+    SingleKeyStartStop,
+    PenaltyExpiryAlerts,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -218,7 +342,17 @@ pub enum CyclingParameter {
     AlertVolume,
     AboveWaterVol,
     UnderWaterVol,
+    BlackGoalSound,
+    WhiteGoalSound,
+    PenaltyExpirySound,
+    MercyRuleMargin,
+    MercyRulePeriod,
+    ShootoutRounds,
+    SuddenDeathResolution,
     Mode,
+    CatchUpPolicy,
+    UiScale,
+    Language,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -227,11 +361,17 @@ pub enum ScrollOption {
     White,
     Equal,
     GameParameter,
+    Goals,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum KeypadPage {
     AddScore(GameColor),
+    EditGoal {
+        index: usize,
+        color: GameColor,
+        time: Duration,
+    },
     Penalty(
         Option<(GameColor, usize)>,
         GameColor,
@@ -241,6 +381,7 @@ pub enum KeypadPage {
     ),
     GameNumber,
     TeamTimeouts(Duration),
+    Pin(ProtectedAction),
     FoulAdd {
         origin: Option<(Option<GameColor>, usize)>,
         color: Option<GameColor>,
@@ -256,16 +397,25 @@ pub enum KeypadPage {
         team_warning: bool,
         ret_to_overview: bool,
     },
+    PenaltyShotOutcome {
+        color: GameColor,
+        infraction: Infraction,
+        expanded: bool,
+    },
+    ShootoutShot(GameColor),
 }
 
 impl KeypadPage {
     pub fn max_val(&self) -> u16 {
         match self {
             Self::AddScore(_)
+            | Self::EditGoal { .. }
             | Self::Penalty(_, _, _, _, _)
             | Self::FoulAdd { .. }
-            | Self::WarningAdd { .. } => 99,
-            Self::GameNumber => 9999,
+            | Self::WarningAdd { .. }
+            | Self::PenaltyShotOutcome { .. }
+            | Self::ShootoutShot(_) => 99,
+            Self::GameNumber | Self::Pin(_) => 9999,
             Self::TeamTimeouts(_) => 999,
         }
     }
@@ -273,11 +423,14 @@ impl KeypadPage {
     pub fn text(&self) -> &'static str {
         match self {
             Self::AddScore(_)
+            | Self::EditGoal { .. }
             | Self::Penalty(_, _, _, _, _)
             | Self::FoulAdd { .. }
             | Self::WarningAdd { .. } => "PLAYER\nNUMBER:",
+            Self::PenaltyShotOutcome { .. } | Self::ShootoutShot(_) => "SHOOTER\nNUMBER:",
             Self::GameNumber => "GAME\nNUMBER:",
             Self::TeamTimeouts(_) => "NUM T/Os\nPER HALF:",
+            Self::Pin(_) => "PIN:",
         }
     }
 }
@@ -303,4 +456,11 @@ pub enum ConfirmationOption {
     GoBack,
     EndGameAndApply,
     KeepGameAndApply,
+    AddPenalty,
+    Acknowledge,
+    EndGameEarly,
+    DeclareDraw,
+    StartShootout,
+    EndPractice,
+    ChangePeriod,
 }
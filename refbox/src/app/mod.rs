@@ -2,12 +2,22 @@ use self::infraction::InfractionDetails;
 
 use super::APP_NAME;
 use crate::{
-    config::{Config, Mode},
+    config::{Config, KeyAction, Mode, Role},
+    discipline::DisciplineLog,
+    i18n::{self, tr},
+    infractions,
     penalty_editor::*,
     sound_controller::*,
     tournament_manager::{penalty::*, *},
+    training::{IntervalStatus, IntervalTimer},
+};
+use iced::{
+    event, executor,
+    keyboard::{self, KeyCode},
+    subscription,
+    widget::column,
+    Application, Command, Event, Subscription,
 };
-use iced::{executor, widget::column, Application, Command, Subscription};
 use iced_futures::{
     futures::stream::{self, BoxStream},
     subscription::{EventStream, Recipe},
@@ -29,7 +39,7 @@ use tokio::{
 };
 use tokio_serial::SerialPortBuilder;
 use uwh_common::{
-    config::Game as GameConfig,
+    config::{Game as GameConfig, SuddenDeathResolution},
     drawing_support::*,
     game_snapshot::{Color, GamePeriod, GameSnapshot, Infraction, TimeoutSnapshot},
     uwhportal::UwhPortalClient,
@@ -76,9 +86,20 @@ pub struct RefBoxApp {
     current_tid: Option<u32>,
     current_pool: Option<String>,
     sound: SoundController,
-    sim_child: Option<Child>,
+    sim_children: Vec<Child>,
     fullscreen: bool,
     list_all_tournaments: bool,
+    held_keys: Vec<KeyCode>,
+    window_size: (f32, f32),
+    unlocked_role: Option<Role>,
+    discipline: DisciplineLog,
+    training: Option<IntervalTimer>,
+    /// A sudden death goal that is waiting for the final scores to be confirmed
+    pending_golden_goal: Option<(Color, u8)>,
+    /// The page to go back to once the PIN for a protected action has been entered
+    pin_return_state: Option<AppState>,
+    /// A game that ended and was then reopened with a period change
+    reopened_game: Option<u32>,
 }
 
 #[derive(Debug)]
@@ -86,21 +107,24 @@ pub struct RefBoxAppFlags {
     pub config: Config,
     pub serial_ports: Vec<SerialPortBuilder>,
     pub binary_port: u16,
+    pub binary_v2_port: u16,
     pub json_port: u16,
-    pub sim_child: Option<Child>,
+    pub sim_children: Vec<Child>,
     pub require_https: bool,
     pub fullscreen: bool,
     pub list_all_tournaments: bool,
+    pub buttons: ButtonOptions,
 }
 
 #[derive(Debug, Clone)]
 enum AppState {
     MainPage,
-    TimeEdit(bool, Duration, Option<Duration>),
+    TimeEdit(bool, Duration, Option<Duration>, GamePeriod),
     ScoreEdit {
         scores: BlackWhiteBundle<u8>,
         is_confirmation: bool,
     },
+    GoalTimeline(usize),
     PenaltyOverview(BlackWhiteBundle<usize>),
     WarningOverview(BlackWhiteBundle<usize>),
     FoulOverview(OptColorBundle<usize>),
@@ -112,14 +136,34 @@ enum AppState {
     ParameterList(ListableParameter, usize),
     ConfirmationPage(ConfirmationKind),
     ConfirmScores(BlackWhiteBundle<u8>),
+    TrainingPage {
+        program: usize,
+        was_running: bool,
+    },
+    SessionSuspended,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum ConfirmationKind {
     GameNumberChanged,
-    GameConfigChanged(GameConfig),
+    GameConfigChanged(Box<GameConfig>),
     Error(String),
     UwhScoresIncomplete,
+    Escalation(Escalation),
+    SuspendedPlayer {
+        text: String,
+        in_penalty_edit: bool,
+    },
+    ReportExported(String),
+    MercyRule,
+    SuddenDeathLimit,
+    EndPractice,
+    ChangePeriod {
+        was_running: bool,
+        from: GamePeriod,
+        to: GamePeriod,
+        time: Duration,
+    },
 }
 
 impl RefBoxApp {
@@ -128,7 +172,11 @@ impl RefBoxApp {
             if new_snapshot.current_period == GamePeriod::BetweenGames {
                 self.handle_game_end(new_snapshot.game_number, new_snapshot.next_game_number);
             } else if self.snapshot.current_period == GamePeriod::BetweenGames {
-                self.handle_game_start(new_snapshot.game_number);
+                if self.reopened_game == Some(new_snapshot.game_number) {
+                    info!("Game {} was reopened", new_snapshot.game_number);
+                } else {
+                    self.handle_game_start(new_snapshot.game_number);
+                }
             }
         }
         if let Some(tid) = self.current_tid {
@@ -141,6 +189,27 @@ impl RefBoxApp {
         self.snapshot = new_snapshot;
     }
 
+    /// Moves the training timer on, playing the buzzer when a new interval starts, and shows it
+    /// on the panels
+    fn update_training(&mut self, now: Instant) {
+        let timer = self.training.as_mut().unwrap();
+        if timer.update(now) {
+            self.sound.trigger_buzzer();
+        }
+        self.send_training_snapshot();
+    }
+
+    fn send_training_snapshot(&self) {
+        let snapshot = self
+            .training
+            .as_ref()
+            .unwrap()
+            .generate_snapshot(Instant::now());
+        self.update_sender
+            .send_snapshot(snapshot, self.config.hardware.white_on_right)
+            .unwrap();
+    }
+
     fn maybe_play_sound(&self, new_snapshot: &GameSnapshot) {
         let (play_whistle, play_buzzer) = match new_snapshot.timeout {
             TimeoutSnapshot::Black(time) | TimeoutSnapshot::White(time) => {
@@ -154,7 +223,7 @@ impl RefBoxApp {
             }
             TimeoutSnapshot::Ref(_) | TimeoutSnapshot::PenaltyShot(_) => (false, false),
             TimeoutSnapshot::None => {
-                let prereqs = new_snapshot.current_period != GamePeriod::SuddenDeath
+                let prereqs = !new_snapshot.current_period.counts_up(&self.config.game)
                     && new_snapshot.secs_in_period != self.snapshot.secs_in_period;
 
                 let is_whistle_period = match new_snapshot.current_period {
@@ -167,7 +236,8 @@ impl RefBoxApp {
                     | GamePeriod::SecondHalf
                     | GamePeriod::OvertimeFirstHalf
                     | GamePeriod::OvertimeSecondHalf
-                    | GamePeriod::SuddenDeath => false,
+                    | GamePeriod::SuddenDeath
+                    | GamePeriod::Shootout => false,
                 };
 
                 let (end_starts_play, end_stops_play) = match new_snapshot.current_period {
@@ -180,7 +250,9 @@ impl RefBoxApp {
                     | GamePeriod::PreOvertime
                     | GamePeriod::OvertimeHalfTime
                     | GamePeriod::PreSuddenDeath => (true, false),
-                    GamePeriod::SuddenDeath => (false, false),
+                    // Sudden death only ends on time when it has a maximum length
+                    p @ GamePeriod::SuddenDeath => (false, !p.counts_up(&self.config.game)),
+                    GamePeriod::Shootout => (false, false),
                 };
 
                 let is_buzz_period = end_starts_play && self.config.sound.auto_sound_start_play
@@ -200,6 +272,188 @@ impl RefBoxApp {
             info!("Triggering buzzer");
             self.sound.trigger_buzzer();
         }
+
+        // The sound controller holds this until the buzzer is done, so the two don't cut each
+        // other off
+        if let Some(sound) = self.config.sound.penalty_expiry_sound {
+            let new_expiry = new_snapshot
+                .recent_penalty_expiries
+                .iter()
+                .any(|exp| !self.snapshot.recent_penalty_expiries.contains(exp));
+            if new_expiry {
+                info!("Triggering penalty expiry sound");
+                self.sound.trigger_penalty_expiry_sound(sound);
+            }
+        }
+    }
+
+    fn play_goal_sound(&self, color: Color) {
+        let team_name = self
+            .games
+            .as_ref()
+            .and_then(|games| games.get(&self.snapshot.game_number))
+            .map(|game| match color {
+                Color::Black => game.black.as_str(),
+                Color::White => game.white.as_str(),
+            });
+
+        if let Some(sound) = self.config.sound.goal_sound(color, team_name) {
+            info!("Triggering {color} goal sound");
+            self.sound.trigger_goal_sound(sound);
+        }
+    }
+
+    /// Adds a goal, or asks for the scores to be confirmed if the goal ends the game.
+    /// Returns the state the app should move to
+    fn score_goal(&mut self, color: Color, player: u8) -> AppState {
+        let mut tm = self.tm.lock().unwrap();
+        let now = Instant::now();
+
+        let (mut app_state, scored) = if tm.current_period() == GamePeriod::SuddenDeath {
+            tm.stop_clock(now).unwrap();
+            let mut scores = tm.get_scores();
+            scores[color] = scores[color].saturating_add(1);
+            self.pending_golden_goal = Some((color, player));
+
+            (AppState::ConfirmScores(scores), false)
+        } else {
+            tm.add_score(color, player, now);
+            (AppState::MainPage, true)
+        };
+        let snapshot = tm.generate_snapshot(now).unwrap();
+
+        std::mem::drop(tm);
+        self.apply_snapshot(snapshot);
+        if scored {
+            self.play_goal_sound(color);
+            // Once the suspension warning is acknowledged, the mercy rule is checked again
+            if let Some(text) = self.suspension_warning(color, player) {
+                app_state = AppState::ConfirmationPage(ConfirmationKind::SuspendedPlayer {
+                    text,
+                    in_penalty_edit: false,
+                });
+            } else {
+                app_state = self.mercy_rule_state();
+            }
+        }
+
+        app_state
+    }
+
+    /// Asks whether to end the game if the mercy rule has been reached, otherwise returns to the
+    /// main page
+    fn mercy_rule_state(&self) -> AppState {
+        if self.tm.lock().unwrap().mercy_rule_reached() {
+            AppState::ConfirmationPage(ConfirmationKind::MercyRule)
+        } else {
+            AppState::MainPage
+        }
+    }
+
+    /// Applies the confirmed final scores of a sudden death game. If they match the golden goal
+    /// that was waiting for confirmation, the goal is recorded with its scorer. Returns the color
+    /// that scored the golden goal, if there was one
+    fn apply_final_scores(
+        pending_golden_goal: &mut Option<(Color, u8)>,
+        tm: &mut TournamentManager,
+        scores: BlackWhiteBundle<u8>,
+        now: Instant,
+    ) -> Option<Color> {
+        let golden_goal = pending_golden_goal.take().filter(|(color, _)| {
+            let mut expected = tm.get_scores();
+            expected[*color] = expected[*color].saturating_add(1);
+            expected == scores
+        });
+
+        if let Some((color, player)) = golden_goal {
+            tm.add_score(color, player, now);
+        } else {
+            tm.set_scores(scores, now);
+        }
+        golden_goal.map(|(color, _)| color)
+    }
+
+    /// Converts a key press into the message that the equivalent button press would
+    /// send, if that button is currently available
+    fn key_message(&self, key: KeyCode) -> Option<Message> {
+        if let AppState::KeypadPage(page, _) = self.app_state {
+            if matches!(key, KeyCode::Enter | KeyCode::NumpadEnter) {
+                return keypad_submit_message(page);
+            }
+            return keypad_button(key).map(Message::KeypadButtonPress);
+        }
+        if !matches!(self.app_state, AppState::MainPage) {
+            return None;
+        }
+
+        let action = self.config.key_bindings.action(&format!("{key:?}"))?;
+        debug!("Key {key:?} triggered action {action:?}");
+
+        let tm = self.tm.lock().unwrap();
+        let clock_running = tm.clock_is_running();
+
+        match action {
+            KeyAction::StartStopClock => Some(if clock_running {
+                Message::StopClock
+            } else {
+                Message::StartClock
+            }),
+            KeyAction::StartClock => (!clock_running).then_some(Message::StartClock),
+            KeyAction::StopClock => clock_running.then_some(Message::StopClock),
+            KeyAction::BlackGoal | KeyAction::WhiteGoal => {
+                let color = if action == KeyAction::BlackGoal {
+                    Color::Black
+                } else {
+                    Color::White
+                };
+                (self.snapshot.current_period != GamePeriod::BetweenGames)
+                    .then_some(Message::AddNewScore(color))
+            }
+            KeyAction::BlackTimeout | KeyAction::WhiteTimeout => {
+                let color = if action == KeyAction::BlackTimeout {
+                    Color::Black
+                } else {
+                    Color::White
+                };
+                match (self.snapshot.timeout, color) {
+                    (TimeoutSnapshot::None, _) => tm
+                        .can_start_team_timeout(color)
+                        .ok()
+                        .map(|_| Message::TeamTimeout(color, false)),
+                    (TimeoutSnapshot::Black(_), Color::Black)
+                    | (TimeoutSnapshot::White(_), Color::White) => Some(Message::EndTimeout),
+                    _ => tm
+                        .can_switch_to_team_timeout(color)
+                        .ok()
+                        .map(|_| Message::TeamTimeout(color, true)),
+                }
+            }
+            KeyAction::RefTimeout => match self.snapshot.timeout {
+                TimeoutSnapshot::None => tm
+                    .can_start_ref_timeout()
+                    .ok()
+                    .map(|_| Message::RefTimeout(false)),
+                TimeoutSnapshot::Ref(_) => Some(Message::EndTimeout),
+                _ => tm
+                    .can_switch_to_ref_timeout()
+                    .ok()
+                    .map(|_| Message::RefTimeout(true)),
+            },
+            KeyAction::PenaltyShot => match self.snapshot.timeout {
+                TimeoutSnapshot::None => tm
+                    .can_start_penalty_shot()
+                    .ok()
+                    .map(|_| Message::PenaltyShot(false)),
+                TimeoutSnapshot::PenaltyShot(_) => Some(Message::EndTimeout),
+                _ => if self.config.mode == Mode::Rugby {
+                    tm.can_switch_to_rugby_penalty_shot()
+                } else {
+                    tm.can_switch_to_penalty_shot()
+                }
+                .ok()
+                .map(|_| Message::PenaltyShot(true)),
+            },
+        }
     }
 
     fn do_get_request<T, F>(&self, url: String, short_name: String, on_success: F)
@@ -299,6 +553,11 @@ impl RefBoxApp {
     }
 
     fn post_game_score(&self, game: &GameInfo, scores: BlackWhiteBundle<u8>) {
+        if self.config.game.practice_mode {
+            info!("Not posting the score of a practice");
+            return;
+        }
+
         if let Some(client) = &self.client {
             let tid = game.tid;
             let gid = game.gid;
@@ -397,6 +656,11 @@ impl RefBoxApp {
     }
 
     fn post_game_stats(&self, tid: u32, gid: u32, stats: String) {
+        if self.config.game.practice_mode {
+            info!("Not posting the stats of a practice");
+            return;
+        }
+
         if let Some(ref uwhportal_client) = self.uwhportal_client {
             let request = uwhportal_client.post_game_stats(tid, gid, stats);
             tokio::spawn(async move {
@@ -441,8 +705,17 @@ impl RefBoxApp {
         }
     }
 
-    fn handle_game_end(&self, game_number: u32, next_game_num: u32) {
+    fn handle_game_end(&mut self, game_number: u32, next_game_num: u32) {
+        // The score posted when a reopened game ends again replaces the earlier one, and so do
+        // its stats. The next game and any dismissals were already handled the first time
+        let reopened = self.reopened_game.take() == Some(game_number);
+
         if self.using_uwhscores {
+            // Dismissals in a practice don't carry over to real games
+            if !self.config.game.practice_mode && !reopened {
+                self.record_dismissals(game_number);
+            }
+
             let mut stats = self
                 .tm
                 .lock()
@@ -457,7 +730,11 @@ impl RefBoxApp {
             }
 
             if let Some(tid) = self.current_tid {
-                self.request_game_details(tid, next_game_num);
+                if reopened {
+                    info!("Replacing the uploaded results of reopened game {game_number}");
+                } else {
+                    self.request_game_details(tid, next_game_num);
+                }
                 if let Some(stats) = stats.take() {
                     self.post_game_stats(tid, game_number, stats);
                 }
@@ -467,6 +744,56 @@ impl RefBoxApp {
         }
     }
 
+    fn record_dismissals(&mut self, game_number: u32) {
+        let Some(game) = self
+            .games
+            .as_ref()
+            .and_then(|games| games.get(&game_number))
+        else {
+            return;
+        };
+
+        let dismissals = self.tm.lock().unwrap().last_game_dismissals();
+        if dismissals.is_empty() {
+            return;
+        }
+        for (color, player_number) in dismissals {
+            self.discipline.add_dismissal(
+                game,
+                color,
+                player_number,
+                self.config.dismissal_suspension_games,
+            );
+        }
+        if let Err(e) = self.discipline.save() {
+            error!("Failed to save the disciplinary records: {e}");
+        }
+    }
+
+    /// A warning to show if `color` #`player_number` is suspended from the current game
+    fn suspension_warning(&self, color: Color, player_number: u8) -> Option<String> {
+        if !self.using_uwhscores || player_number == 0 {
+            return None;
+        }
+        let games = self.games.as_ref()?;
+        let game = games.get(&self.snapshot.game_number)?;
+        let record = self
+            .discipline
+            .suspension(game, color, player_number, games)?;
+
+        warn!(
+            "{} #{player_number} is suspended, after being dismissed in game {}",
+            record.team_name, record.gid
+        );
+        Some(format!(
+            "{}\n\n{} #{player_number}\n{} {}",
+            tr("This player is suspended for this game."),
+            record.team_name,
+            tr("Dismissed in game"),
+            record.gid
+        ))
+    }
+
     fn apply_settings_change(&mut self) {
         let edited_settings = self.edited_settings.take().unwrap();
 
@@ -483,18 +810,43 @@ impl RefBoxApp {
             config: _config,
             game_number: _game_number,
             track_fouls_and_warnings,
+            single_key_start_stop,
+            penalty_expiry_alerts,
+            ui_scale,
+            language,
+            catch_up_policy,
         } = edited_settings;
 
         self.config.hardware.white_on_right = white_on_right;
+        self.config.hardware.ui_scale = ui_scale;
+        if self.config.language != language {
+            self.config.language = language;
+            i18n::set_language(language);
+        }
         self.using_uwhscores = using_uwhscores;
+        if self.current_tid != current_tid {
+            self.tm.lock().unwrap().clear_schedule_delay();
+        }
         self.current_tid = current_tid;
         self.current_pool = current_pool;
         self.games = games;
         self.config.sound = sound;
         self.sound.update_settings(self.config.sound.clone());
-        self.config.mode = mode;
+        if self.config.mode != mode {
+            self.config.mode = mode;
+            infractions::set_catalog(self.config.infractions.for_mode(mode));
+        }
         self.config.collect_scorer_cap_num = collect_scorer_cap_num;
         self.config.track_fouls_and_warnings = track_fouls_and_warnings;
+        self.config.key_bindings.single_key_start_stop = single_key_start_stop;
+        self.config.penalty_expiry_alerts = penalty_expiry_alerts;
+        if self.config.catch_up.policy != catch_up_policy {
+            self.config.catch_up.policy = catch_up_policy;
+            self.tm
+                .lock()
+                .unwrap()
+                .set_catch_up(self.config.catch_up.clone());
+        }
 
         if self.config.hide_time != hide_time {
             self.config.hide_time = hide_time;
@@ -507,7 +859,7 @@ impl RefBoxApp {
 
 impl Drop for RefBoxApp {
     fn drop(&mut self) {
-        if let Some(mut child) = self.sim_child.take() {
+        for mut child in self.sim_children.drain(..) {
             info!("Waiting for child");
             child.wait().unwrap();
         }
@@ -525,11 +877,13 @@ impl Application for RefBoxApp {
             config,
             serial_ports,
             binary_port,
+            binary_v2_port,
             json_port,
-            sim_child,
+            sim_children,
             require_https,
             fullscreen,
             list_all_tournaments,
+            buttons,
         } = flags;
 
         let (msg_tx, rx) = mpsc::unbounded_channel();
@@ -538,8 +892,17 @@ impl Application for RefBoxApp {
         };
         msg_tx.send(Message::Init).unwrap();
 
+        i18n::set_language(config.language);
+        if config.settings_lock.enabled && config.settings_lock.chief_referee_pin.is_none() {
+            warn!(
+                "The settings lock is enabled, but it stays open until a chief referee PIN is set"
+            );
+        }
+        infractions::set_catalog(config.infractions.for_mode(config.mode));
+
         let mut tm = TournamentManager::new(config.game.clone());
         tm.set_timezone(config.uwhscores.timezone);
+        tm.set_catch_up(config.catch_up.clone());
         tm.start_clock(Instant::now());
 
         let client = match Client::builder()
@@ -571,14 +934,27 @@ impl Application for RefBoxApp {
 
         let tm = Arc::new(Mutex::new(tm));
 
-        let update_sender =
-            UpdateSender::new(serial_ports, binary_port, json_port, config.hide_time);
+        let update_sender = UpdateSender::new(
+            serial_ports,
+            binary_port,
+            binary_v2_port,
+            json_port,
+            config.hide_time,
+        );
 
-        let sound =
-            SoundController::new(config.sound.clone(), update_sender.get_trigger_flash_fn());
+        let sound = SoundController::new(
+            config.sound.clone(),
+            buttons,
+            update_sender.get_trigger_flash_fn(),
+        );
 
         let snapshot = Default::default();
 
+        let window_size = (
+            config.hardware.screen_x as f32,
+            config.hardware.screen_y as f32,
+        );
+
         (
             Self {
                 pen_edit: ListEditor::new(tm.clone()),
@@ -606,9 +982,19 @@ impl Application for RefBoxApp {
                 current_tid: None,
                 current_pool: None,
                 sound,
-                sim_child,
+                sim_children,
                 fullscreen,
                 list_all_tournaments,
+                held_keys: vec![],
+                window_size,
+                unlocked_role: None,
+                discipline: DisciplineLog::default_path()
+                    .map(DisciplineLog::load)
+                    .unwrap_or_default(),
+                training: None,
+                pending_golden_goal: None,
+                pin_return_state: None,
+                reopened_game: None,
             },
             Command::single(command::Action::LoadFont {
                 bytes: Cow::from(&include_bytes!("../../resources/Roboto-Medium.ttf")[..]),
@@ -624,9 +1010,44 @@ impl Application for RefBoxApp {
     }
 
     fn subscription(&self) -> Subscription<Message> {
+        let training_ticks = if self.training.as_ref().is_some_and(|t| t.is_running()) {
+            iced::time::every(Duration::from_millis(100)).map(|_| Message::TrainingTick)
+        } else {
+            Subscription::none()
+        };
+
+        let suspension_ticks = if matches!(self.app_state, AppState::SessionSuspended) {
+            iced::time::every(Duration::from_secs(1)).map(|_| Message::SuspensionTick)
+        } else {
+            Subscription::none()
+        };
+
         Subscription::batch([
+            training_ticks,
+            suspension_ticks,
             Subscription::from_recipe(self.time_updater.clone()),
             Subscription::from_recipe(self.message_listener.clone()),
+            subscription::events_with(|event, status| match (event, status) {
+                (
+                    Event::Keyboard(keyboard::Event::KeyPressed {
+                        key_code,
+                        modifiers,
+                    }),
+                    event::Status::Ignored,
+                ) if !(modifiers.control() || modifiers.alt() || modifiers.logo()) => {
+                    Some(Message::KeyPressed(key_code))
+                }
+                (Event::Keyboard(keyboard::Event::KeyReleased { key_code, .. }), _) => {
+                    Some(Message::KeyReleased(key_code))
+                }
+                (Event::Window(iced_core::window::Event::Resized { width, height }), _) => {
+                    Some(Message::WindowResized { width, height })
+                }
+                (Event::Window(iced_core::window::Event::Unfocused), _) => {
+                    Some(Message::WindowUnfocused)
+                }
+                _ => None,
+            }),
         ])
     }
 
@@ -634,6 +1055,10 @@ impl Application for RefBoxApp {
         "UWH Ref Box".into()
     }
 
+    fn scale_factor(&self) -> f64 {
+        style::scale_factor(self.window_size, self.config.hardware.ui_scale)
+    }
+
     fn update(&mut self, message: Message) -> Command<Message> {
         trace!("Handling message: {message:?}");
 
@@ -653,10 +1078,39 @@ impl Application for RefBoxApp {
             Command::none()
         };
 
+        if let Some(action) = message.protected_action() {
+            let required = action.required_role();
+            let unlocked = self
+                .unlocked_role
+                .take()
+                .is_some_and(|role| role >= required);
+            if !(unlocked || self.config.settings_lock.allows(required)) {
+                info!("{action:?} requires the {required:?} PIN");
+                self.pin_return_state = Some(self.app_state.clone());
+                self.app_state = AppState::KeypadPage(KeypadPage::Pin(action), 0);
+                trace!("AppState changed to {:?}", self.app_state);
+                return command;
+            }
+        }
+
         match message {
             Message::Init => self.request_tournament_list(),
             Message::NewSnapshot(snapshot) => {
-                self.apply_snapshot(snapshot);
+                if self.training.is_some() {
+                    debug!("Not showing the game snapshot while the training timer is in use");
+                } else {
+                    self.apply_snapshot(snapshot);
+                }
+            }
+            Message::WindowResized { width, height } => {
+                // The reported size has already been divided by the current scale factor
+                let scale = self.scale_factor() as f32;
+                self.window_size = (width as f32 * scale, height as f32 * scale);
+                debug!(
+                    "Window resized to {:?}, new scale factor is {}",
+                    self.window_size,
+                    self.scale_factor()
+                );
             }
             Message::EditTime => {
                 let now = Instant::now();
@@ -668,6 +1122,7 @@ impl Application for RefBoxApp {
                     was_running,
                     tm.game_clock_time(now).unwrap(),
                     tm.timeout_clock_time(now),
+                    tm.current_period(),
                 );
                 trace!("AppState changed to {:?}", self.app_state);
             }
@@ -677,7 +1132,7 @@ impl Application for RefBoxApp {
                 timeout,
             } => {
                 let (dur, large_max) = match self.app_state {
-                    AppState::TimeEdit(_, ref mut game_dur, ref mut timeout_dur) => {
+                    AppState::TimeEdit(_, ref mut game_dur, ref mut timeout_dur, _) => {
                         if timeout {
                             (timeout_dur.as_mut().unwrap(), false)
                         } else {
@@ -685,7 +1140,13 @@ impl Application for RefBoxApp {
                         }
                     }
                     AppState::ParameterEditor(_, ref mut dur) => (dur, false),
-                    AppState::KeypadPage(KeypadPage::TeamTimeouts(ref mut dur), _) => (dur, false),
+                    AppState::KeypadPage(KeypadPage::TeamTimeouts(ref mut dur), _)
+                    | AppState::KeypadPage(
+                        KeypadPage::EditGoal {
+                            time: ref mut dur, ..
+                        },
+                        _,
+                    ) => (dur, false),
                     _ => unreachable!(),
                 };
                 if increase {
@@ -705,10 +1166,45 @@ impl Application for RefBoxApp {
                 }
                 trace!("AppState changed to {:?}", self.app_state);
             }
+            Message::ChangePeriod { forward } => {
+                if let AppState::TimeEdit(_, ref mut game_time, _, ref mut period) = self.app_state
+                {
+                    let tm = self.tm.lock().unwrap();
+                    let choices = period_choices(&tm);
+                    let index = choices.iter().position(|p| p == period).unwrap_or(0);
+                    let index = if forward {
+                        min(index + 1, choices.len() - 1)
+                    } else {
+                        index.saturating_sub(1)
+                    };
+                    *period = choices[index];
+                    *game_time = if *period == tm.current_period() {
+                        tm.game_clock_time(Instant::now()).unwrap()
+                    } else {
+                        period.duration(tm.config()).unwrap_or(Duration::ZERO)
+                    };
+                    trace!("AppState changed to {:?}", self.app_state);
+                } else {
+                    unreachable!()
+                }
+            }
             Message::TimeEditComplete { canceled } => {
-                if let AppState::TimeEdit(was_running, game_time, timeout_time) = self.app_state {
+                if let AppState::TimeEdit(was_running, game_time, timeout_time, period) =
+                    self.app_state
+                {
                     let mut tm = self.tm.lock().unwrap();
                     let now = Instant::now();
+                    if !canceled && period != tm.current_period() {
+                        self.app_state =
+                            AppState::ConfirmationPage(ConfirmationKind::ChangePeriod {
+                                was_running,
+                                from: tm.current_period(),
+                                to: period,
+                                time: game_time,
+                            });
+                        trace!("AppState changed to {:?}", self.app_state);
+                        return command;
+                    }
                     if !canceled {
                         tm.set_game_clock_time(game_time).unwrap();
                         if let Some(time) = timeout_time {
@@ -736,6 +1232,20 @@ impl Application for RefBoxApp {
                 std::mem::drop(tm);
                 self.apply_snapshot(snapshot);
             }
+            Message::EndPractice => {
+                self.app_state = AppState::ConfirmationPage(ConfirmationKind::EndPractice);
+                trace!("AppState changed to {:?}", self.app_state);
+            }
+            Message::EndPracticeHalf => {
+                let mut tm = self.tm.lock().unwrap();
+                let now = Instant::now();
+                if let Err(e) = tm.end_practice_half(now) {
+                    error!("Could not end the practice half: {e}");
+                }
+                let snapshot = tm.generate_snapshot(now).unwrap();
+                std::mem::drop(tm);
+                self.apply_snapshot(snapshot);
+            }
             Message::EditScores => {
                 let tm = self.tm.lock().unwrap();
                 self.app_state = AppState::ScoreEdit {
@@ -755,6 +1265,7 @@ impl Application for RefBoxApp {
                     let snapshot = tm.generate_snapshot(now).unwrap(); // TODO: Remove this unwrap
                     std::mem::drop(tm);
                     self.apply_snapshot(snapshot);
+                    self.play_goal_sound(color);
                 }
             }
 
@@ -773,6 +1284,7 @@ impl Application for RefBoxApp {
             Message::ScoreEditComplete { canceled } => {
                 let mut tm = self.tm.lock().unwrap();
                 let mut now = Instant::now();
+                let mut golden_goal = None;
 
                 self.app_state = if let AppState::ScoreEdit {
                     scores,
@@ -788,7 +1300,12 @@ impl Application for RefBoxApp {
                             self.post_game_score(game, scores);
                         }
 
-                        tm.set_scores(scores, now);
+                        golden_goal = Self::apply_final_scores(
+                            &mut self.pending_golden_goal,
+                            &mut tm,
+                            scores,
+                            now,
+                        );
                         tm.start_clock(now);
 
                         // Update `tm` after game ends to get into Between Games
@@ -800,10 +1317,15 @@ impl Application for RefBoxApp {
                             && (scores.black != scores.white)
                         {
                             tm.stop_clock(now).unwrap();
+                            self.pending_golden_goal = None;
                             AppState::ConfirmScores(scores)
                         } else {
                             tm.set_scores(scores, now);
-                            AppState::MainPage
+                            if tm.mercy_rule_reached() {
+                                AppState::ConfirmationPage(ConfirmationKind::MercyRule)
+                            } else {
+                                AppState::MainPage
+                            }
                         }
                     } else {
                         AppState::MainPage
@@ -815,7 +1337,83 @@ impl Application for RefBoxApp {
                 let snapshot = tm.generate_snapshot(now).unwrap(); // `now` is in the past!
                 std::mem::drop(tm);
                 self.apply_snapshot(snapshot);
+                if let Some(color) = golden_goal {
+                    self.play_goal_sound(color);
+                }
+
+                trace!("AppState changed to {:?}", self.app_state);
+            }
+            Message::GoalTimeline => {
+                self.app_state = AppState::GoalTimeline(0);
+                trace!("AppState changed to {:?}", self.app_state);
+            }
+            Message::GoalEditComplete { canceled, deleted } => {
+                let mut next_state = AppState::GoalTimeline(0);
+                if let AppState::KeypadPage(KeypadPage::EditGoal { index, color, time }, player) =
+                    self.app_state
+                {
+                    if !canceled {
+                        let mut tm = self.tm.lock().unwrap();
+                        let now = Instant::now();
+
+                        let result = if deleted {
+                            tm.delete_goal(index, now)
+                        } else {
+                            tm.edit_goal(index, color, player.try_into().unwrap(), time, now)
+                        };
+                        if let Err(e) = result {
+                            error!("Failed to edit goal: {e}");
+                        }
+
+                        // A game that is decided by the next goal only ends once the new scores
+                        // have been confirmed
+                        if tm.scores_would_end_game() {
+                            tm.stop_clock(now).unwrap();
+                            self.pending_golden_goal = None;
+                            next_state = AppState::ConfirmScores(tm.get_scores());
+                        } else if tm.mercy_rule_reached() {
+                            next_state = AppState::ConfirmationPage(ConfirmationKind::MercyRule);
+                        }
+
+                        let snapshot = tm.generate_snapshot(now).unwrap();
+                        std::mem::drop(tm);
+                        self.apply_snapshot(snapshot);
+                    }
+                } else {
+                    unreachable!()
+                }
 
+                self.app_state = next_state;
+                trace!("AppState changed to {:?}", self.app_state);
+            }
+            Message::ExportDisciplineReport => {
+                let tid = self
+                    .edited_settings
+                    .as_ref()
+                    .and_then(|settings| settings.current_tid)
+                    .unwrap();
+                let text = match self.discipline.export_report(tid) {
+                    Ok(path) => {
+                        info!("Saved the disciplinary report to {}", path.display());
+                        format!(
+                            "{}\n\n{}",
+                            tr("The disciplinary report was saved to:"),
+                            path.display()
+                        )
+                    }
+                    Err(e) => {
+                        error!("Failed to save the disciplinary report: {e}");
+                        format!("{}\n\n{e}", tr("Failed to save the disciplinary report:"))
+                    }
+                };
+                self.app_state = AppState::ConfirmationPage(ConfirmationKind::ReportExported(text));
+                trace!("AppState changed to {:?}", self.app_state);
+            }
+            Message::GoalTimelineComplete => {
+                self.app_state = AppState::ScoreEdit {
+                    scores: self.tm.lock().unwrap().get_scores(),
+                    is_confirmation: false,
+                };
                 trace!("AppState changed to {:?}", self.app_state);
             }
             Message::PenaltyOverview => {
@@ -844,7 +1442,11 @@ impl Application for RefBoxApp {
                         let idx = match which {
                             ScrollOption::Black => &mut indices.black,
                             ScrollOption::White => &mut indices.white,
-                            ScrollOption::GameParameter | ScrollOption::Equal => unreachable!(),
+                            ScrollOption::GameParameter
+                            | ScrollOption::Equal
+                            | ScrollOption::Goals => {
+                                unreachable!()
+                            }
                         };
                         if up {
                             *idx = idx.saturating_sub(1);
@@ -857,7 +1459,7 @@ impl Application for RefBoxApp {
                             ScrollOption::Black => &mut indices.black,
                             ScrollOption::Equal => &mut indices.equal,
                             ScrollOption::White => &mut indices.white,
-                            ScrollOption::GameParameter => unreachable!(),
+                            ScrollOption::GameParameter | ScrollOption::Goals => unreachable!(),
                         };
                         if up {
                             *idx = idx.saturating_sub(1);
@@ -865,6 +1467,14 @@ impl Application for RefBoxApp {
                             *idx = idx.saturating_add(1);
                         }
                     }
+                    AppState::GoalTimeline(ref mut idx) => {
+                        debug_assert_eq!(which, ScrollOption::Goals);
+                        if up {
+                            *idx = idx.saturating_sub(1);
+                        } else {
+                            *idx = idx.saturating_add(1);
+                        }
+                    }
                     AppState::ParameterList(_, ref mut idx) => {
                         debug_assert_eq!(which, ScrollOption::GameParameter);
                         if up {
@@ -982,6 +1592,12 @@ impl Application for RefBoxApp {
                             ref mut infraction, ..
                         },
                         _,
+                    )
+                    | AppState::KeypadPage(
+                        KeypadPage::PenaltyShotOutcome {
+                            ref mut infraction, ..
+                        },
+                        _,
                     ) => {
                         *infraction = new_infraction;
                     }
@@ -1005,6 +1621,13 @@ impl Application for RefBoxApp {
                             ..
                         },
                         _,
+                    )
+                    | AppState::KeypadPage(
+                        KeypadPage::PenaltyShotOutcome {
+                            expanded: ref mut old,
+                            ..
+                        },
+                        _,
                     ) => {
                         info!("Foul select expanded set to: {expanded}");
                         *old = expanded;
@@ -1014,6 +1637,7 @@ impl Application for RefBoxApp {
                 trace!("AppState changed to {:?}", self.app_state);
             }
             Message::PenaltyEditComplete { canceled, deleted } => {
+                let mut suspension = None;
                 if !canceled {
                     if let AppState::KeypadPage(
                         KeypadPage::Penalty(origin, color, kind, infraction, _),
@@ -1028,6 +1652,7 @@ impl Application for RefBoxApp {
                             }
                         } else {
                             let player_num = player_num.try_into().unwrap();
+                            suspension = self.suspension_warning(color, player_num);
                             if let Some((old_color, index)) = origin {
                                 self.pen_edit
                                     .edit_item(
@@ -1044,7 +1669,14 @@ impl Application for RefBoxApp {
                         unreachable!();
                     }
                 }
-                self.app_state = AppState::PenaltyOverview(BlackWhiteBundle { black: 0, white: 0 });
+                self.app_state = if let Some(text) = suspension {
+                    AppState::ConfirmationPage(ConfirmationKind::SuspendedPlayer {
+                        text,
+                        in_penalty_edit: true,
+                    })
+                } else {
+                    AppState::PenaltyOverview(BlackWhiteBundle { black: 0, white: 0 })
+                };
                 trace!("AppState changed to {:?}", self.app_state);
             }
             Message::WarningEditComplete {
@@ -1052,6 +1684,7 @@ impl Application for RefBoxApp {
                 deleted,
                 ret_to_overview,
             } => {
+                let mut escalation = None;
                 if !canceled {
                     if let AppState::KeypadPage(
                         KeypadPage::WarningAdd {
@@ -1077,11 +1710,10 @@ impl Application for RefBoxApp {
                                 unreachable!();
                             }
                         } else if !ret_to_overview {
-                            self.tm
-                                .lock()
-                                .unwrap()
-                                .add_warning(color, player_num, infraction, Instant::now())
+                            let mut tm = self.tm.lock().unwrap();
+                            tm.add_warning(color, player_num, infraction, Instant::now())
                                 .unwrap();
+                            escalation = tm.warning_escalation(&self.config.escalation, color);
                         } else if let Some((old_color, index)) = origin {
                             self.warn_edit
                                 .edit_item(old_color, index, color, player_num, (), infraction)
@@ -1095,7 +1727,9 @@ impl Application for RefBoxApp {
                         unreachable!();
                     }
                 }
-                self.app_state = if !ret_to_overview {
+                self.app_state = if let Some(escalation) = escalation {
+                    AppState::ConfirmationPage(ConfirmationKind::Escalation(escalation))
+                } else if !ret_to_overview {
                     AppState::MainPage
                 } else {
                     AppState::WarningOverview(BlackWhiteBundle { black: 0, white: 0 })
@@ -1107,6 +1741,7 @@ impl Application for RefBoxApp {
                 deleted,
                 ret_to_overview,
             } => {
+                let mut escalation = None;
                 if !canceled {
                     if let AppState::KeypadPage(
                         KeypadPage::FoulAdd {
@@ -1131,11 +1766,12 @@ impl Application for RefBoxApp {
                                 unreachable!();
                             }
                         } else if !ret_to_overview {
-                            self.tm
-                                .lock()
-                                .unwrap()
-                                .add_foul(color, player_num, infraction, Instant::now())
+                            let mut tm = self.tm.lock().unwrap();
+                            tm.add_foul(color, player_num, infraction, Instant::now())
                                 .unwrap();
+                            escalation = color.and_then(|color| {
+                                tm.foul_escalation(&self.config.escalation, color)
+                            });
                         } else if let Some((old_color, index)) = origin {
                             self.foul_edit
                                 .edit_item(old_color, index, color, player_num, (), infraction)
@@ -1149,7 +1785,9 @@ impl Application for RefBoxApp {
                         unreachable!();
                     }
                 }
-                self.app_state = if !ret_to_overview {
+                self.app_state = if let Some(escalation) = escalation {
+                    AppState::ConfirmationPage(ConfirmationKind::Escalation(escalation))
+                } else if !ret_to_overview {
                     AppState::MainPage
                 } else {
                     AppState::FoulOverview(OptColorBundle {
@@ -1165,7 +1803,18 @@ impl Application for RefBoxApp {
                     KeypadPage::AddScore(_)
                     | KeypadPage::Penalty(None, _, _, _, _)
                     | KeypadPage::FoulAdd { origin: None, .. }
-                    | KeypadPage::WarningAdd { origin: None, .. } => 0,
+                    | KeypadPage::WarningAdd { origin: None, .. }
+                    | KeypadPage::PenaltyShotOutcome { .. }
+                    | KeypadPage::ShootoutShot(_) => 0,
+                    KeypadPage::EditGoal { index, .. } => self
+                        .tm
+                        .lock()
+                        .unwrap()
+                        .goals()
+                        .get(index)
+                        .and_then(|goal| goal.player_number)
+                        .map(Into::into)
+                        .unwrap_or(0),
                     KeypadPage::Penalty(Some((color, index)), _, _, _, _) => {
                         self.pen_edit.get_item(color, index).unwrap().player_number as u16
                     }
@@ -1190,6 +1839,7 @@ impl Application for RefBoxApp {
                         .map(|n| n.into())
                         .unwrap_or(0),
                     KeypadPage::TeamTimeouts(_) => self.config.game.team_timeouts_per_half,
+                    KeypadPage::Pin(_) => 0,
                     KeypadPage::GameNumber => self
                         .edited_settings
                         .as_ref()
@@ -1227,8 +1877,14 @@ impl Application for RefBoxApp {
             Message::ChangeColor(new_color) => {
                 match self.app_state {
                     AppState::KeypadPage(KeypadPage::AddScore(ref mut color), _)
+                    | AppState::KeypadPage(KeypadPage::EditGoal { ref mut color, .. }, _)
                     | AppState::KeypadPage(KeypadPage::Penalty(_, ref mut color, _, _, _), _)
-                    | AppState::KeypadPage(KeypadPage::WarningAdd { ref mut color, .. }, _) => {
+                    | AppState::KeypadPage(KeypadPage::WarningAdd { ref mut color, .. }, _)
+                    | AppState::KeypadPage(
+                        KeypadPage::PenaltyShotOutcome { ref mut color, .. },
+                        _,
+                    )
+                    | AppState::KeypadPage(KeypadPage::ShootoutShot(ref mut color), _) => {
                         *color = new_color.expect("Invalid color value");
                     }
                     AppState::KeypadPage(KeypadPage::FoulAdd { ref mut color, .. }, _) => {
@@ -1245,25 +1901,37 @@ impl Application for RefBoxApp {
                     if let AppState::KeypadPage(KeypadPage::AddScore(color), player) =
                         self.app_state
                     {
+                        self.score_goal(color, player.try_into().unwrap())
+                    } else {
+                        unreachable!()
+                    }
+                } else {
+                    AppState::MainPage
+                };
+                trace!("AppState changed to {:?}", self.app_state);
+            }
+            Message::PenaltyShotOutcomeComplete { canceled, scored } => {
+                self.app_state = if !canceled {
+                    if let AppState::KeypadPage(
+                        KeypadPage::PenaltyShotOutcome {
+                            color, infraction, ..
+                        },
+                        player,
+                    ) = self.app_state
+                    {
+                        let player = player.try_into().unwrap();
                         let mut tm = self.tm.lock().unwrap();
                         let now = Instant::now();
-
-                        let app_state = if tm.current_period() == GamePeriod::SuddenDeath {
-                            tm.stop_clock(now).unwrap();
-                            let mut scores = tm.get_scores();
-                            scores[color] = scores[color].saturating_add(1);
-
-                            AppState::ConfirmScores(scores)
-                        } else {
-                            tm.add_score(color, player.try_into().unwrap(), now);
-                            AppState::MainPage
-                        };
+                        tm.record_penalty_shot(color, player, infraction, scored, now);
                         let snapshot = tm.generate_snapshot(now).unwrap();
-
                         std::mem::drop(tm);
                         self.apply_snapshot(snapshot);
 
-                        app_state
+                        if scored {
+                            self.score_goal(color, player)
+                        } else {
+                            AppState::MainPage
+                        }
                     } else {
                         unreachable!()
                     }
@@ -1272,10 +1940,142 @@ impl Application for RefBoxApp {
                 };
                 trace!("AppState changed to {:?}", self.app_state);
             }
+            Message::ShootoutShotComplete { canceled, scored } => {
+                if !canceled {
+                    if let AppState::KeypadPage(KeypadPage::ShootoutShot(color), player) =
+                        self.app_state
+                    {
+                        let mut tm = self.tm.lock().unwrap();
+                        let now = Instant::now();
+                        tm.record_shootout_shot(color, player.try_into().unwrap(), scored, now)
+                            .unwrap();
+
+                        if tm.current_period() == GamePeriod::BetweenGames {
+                            if let Some(game) = self
+                                .games
+                                .as_ref()
+                                .and_then(|games| games.get(&tm.game_number()))
+                            {
+                                self.post_game_score(game, tm.get_scores());
+                            }
+                        }
+
+                        let snapshot = tm.generate_snapshot(now).unwrap();
+                        std::mem::drop(tm);
+                        self.apply_snapshot(snapshot);
+                    } else {
+                        unreachable!()
+                    }
+                }
+                self.app_state = AppState::MainPage;
+                trace!("AppState changed to {:?}", self.app_state);
+            }
             Message::ShowGameDetails => {
                 self.app_state = AppState::GameDetailsPage;
                 trace!("AppState changed to {:?}", self.app_state);
             }
+            Message::ShowTraining => {
+                if let Some(program) = self.config.training_programs.first() {
+                    let mut tm = self.tm.lock().unwrap();
+                    let was_running = tm.clock_is_running();
+                    tm.stop_clock(Instant::now()).unwrap();
+                    drop(tm);
+                    self.training = Some(IntervalTimer::new(program.clone()));
+                    self.send_training_snapshot();
+                    self.app_state = AppState::TrainingPage {
+                        program: 0,
+                        was_running,
+                    };
+                    trace!("AppState changed to {:?}", self.app_state);
+                }
+            }
+            Message::NextTrainingProgram => {
+                if let AppState::TrainingPage {
+                    ref mut program, ..
+                } = self.app_state
+                {
+                    *program = (*program + 1) % self.config.training_programs.len();
+                    self.training = Some(IntervalTimer::new(
+                        self.config.training_programs[*program].clone(),
+                    ));
+                    trace!("AppState changed to {:?}", self.app_state);
+                    self.send_training_snapshot();
+                } else {
+                    unreachable!()
+                }
+            }
+            Message::StartStopTraining => {
+                let now = Instant::now();
+                let timer = self.training.as_mut().unwrap();
+                if timer.is_running() {
+                    timer.stop(now);
+                } else {
+                    if timer.status(now) == IntervalStatus::Finished {
+                        timer.reset();
+                    }
+                    timer.start(now);
+                }
+                self.update_training(now);
+            }
+            Message::ResetTraining => {
+                self.training.as_mut().unwrap().reset();
+                self.send_training_snapshot();
+            }
+            Message::TrainingTick => {
+                if self.training.is_some() {
+                    self.update_training(Instant::now());
+                }
+            }
+            Message::TrainingComplete => {
+                if let AppState::TrainingPage { was_running, .. } = self.app_state {
+                    self.training = None;
+                    let mut tm = self.tm.lock().unwrap();
+                    let now = Instant::now();
+                    if was_running {
+                        tm.start_clock(now);
+                        tm.update(now).unwrap();
+                    }
+                    let snapshot = tm.generate_snapshot(now).unwrap();
+                    drop(tm);
+                    self.apply_snapshot(snapshot);
+                    self.app_state = AppState::GameDetailsPage;
+                    trace!("AppState changed to {:?}", self.app_state);
+                } else {
+                    unreachable!()
+                }
+            }
+            Message::SuspendSession => {
+                let mut tm = self.tm.lock().unwrap();
+                let now = Instant::now();
+                match tm.suspend_session(now) {
+                    Ok(()) => {
+                        let snapshot = tm.generate_snapshot(now).unwrap();
+                        drop(tm);
+                        self.apply_snapshot(snapshot);
+                        self.app_state = AppState::SessionSuspended;
+                    }
+                    Err(e) => {
+                        drop(tm);
+                        self.app_state =
+                            AppState::ConfirmationPage(ConfirmationKind::Error(e.to_string()));
+                    }
+                }
+                trace!("AppState changed to {:?}", self.app_state);
+            }
+            Message::SuspensionTick => {}
+            Message::ResumeSession => {
+                let mut tm = self.tm.lock().unwrap();
+                let now = Instant::now();
+                if let Err(e) = tm.resume_session(now) {
+                    error!("Failed to resume the session: {e}");
+                }
+                tm.update(now).unwrap();
+                let snapshot = tm.generate_snapshot(now).unwrap();
+                drop(tm);
+                self.apply_snapshot(snapshot);
+                self.app_state = AppState::MainPage;
+                trace!("AppState changed to {:?}", self.app_state);
+            }
             Message::ShowWarnings => {
                 self.app_state = AppState::WarningsSummaryPage;
                 trace!("AppState changed to {:?}", self.app_state);
@@ -1298,6 +2098,11 @@ impl Application for RefBoxApp {
                     hide_time: self.config.hide_time,
                     collect_scorer_cap_num: self.config.collect_scorer_cap_num,
                     track_fouls_and_warnings: self.config.track_fouls_and_warnings,
+                    single_key_start_stop: self.config.key_bindings.single_key_start_stop,
+                    penalty_expiry_alerts: self.config.penalty_expiry_alerts,
+                    ui_scale: self.config.hardware.ui_scale,
+                    language: self.config.language,
+                    catch_up_policy: self.config.catch_up.policy,
                 };
 
                 self.edited_settings = Some(edited_settings);
@@ -1359,7 +2164,7 @@ impl Application for RefBoxApp {
                     } else if new_config != *tm.config() {
                         if tm.current_period() != GamePeriod::BetweenGames {
                             AppState::ConfirmationPage(ConfirmationKind::GameConfigChanged(
-                                new_config,
+                                Box::new(new_config),
                             ))
                         } else {
                             tm.set_config(new_config.clone()).unwrap();
@@ -1454,6 +2259,11 @@ impl Application for RefBoxApp {
                         LengthParameter::PreSuddenDeath => {
                             self.config.game.pre_sudden_death_duration
                         }
+                        LengthParameter::SuddenDeathMax => {
+                            self.config.game.sudden_death_max_duration
+                        }
+                        LengthParameter::TdTeamShort => self.config.game.td_team_short_duration,
+                        LengthParameter::StopClock => self.config.game.stop_clock_duration,
                     },
                 );
                 trace!("AppState changed to {:?}", self.app_state);
@@ -1528,6 +2338,15 @@ impl Application for RefBoxApp {
                             LengthParameter::PreSuddenDeath => {
                                 edited_settings.config.pre_sudden_death_duration = dur
                             }
+                            LengthParameter::SuddenDeathMax => {
+                                edited_settings.config.sudden_death_max_duration = dur
+                            }
+                            LengthParameter::TdTeamShort => {
+                                edited_settings.config.td_team_short_duration = dur
+                            }
+                            LengthParameter::StopClock => {
+                                edited_settings.config.stop_clock_duration = dur
+                            }
                         },
                         AppState::KeypadPage(KeypadPage::GameNumber, num) => {
                             edited_settings.game_number = num.into();
@@ -1616,6 +2435,18 @@ impl Application for RefBoxApp {
                         BoolGameParameter::SuddenDeathAllowed => {
                             edited_settings.config.sudden_death_allowed ^= true
                         }
+                        BoolGameParameter::ShootoutAllowed => {
+                            edited_settings.config.shootout_allowed ^= true
+                        }
+                        BoolGameParameter::StopClockInOvertime => {
+                            edited_settings.config.stop_clock_in_overtime ^= true
+                        }
+                        BoolGameParameter::StopClockAtEveryWhistle => {
+                            edited_settings.config.stop_clock_at_every_whistle ^= true
+                        }
+                        BoolGameParameter::PracticeMode => {
+                            edited_settings.config.practice_mode ^= true
+                        }
                         BoolGameParameter::WhiteOnRight => edited_settings.white_on_right ^= true,
                         BoolGameParameter::UsingUwhScores => {
                             edited_settings.using_uwhscores ^= true
@@ -1639,6 +2470,12 @@ impl Application for RefBoxApp {
                         BoolGameParameter::FoulsAndWarnings => {
                             edited_settings.track_fouls_and_warnings ^= true
                         }
+                        BoolGameParameter::SingleKeyStartStop => {
+                            edited_settings.single_key_start_stop ^= true
+                        }
+                        BoolGameParameter::PenaltyExpiryAlerts => {
+                            edited_settings.penalty_expiry_alerts ^= true
+                        }
                         BoolGameParameter::TeamWarning => {
                             unreachable!()
                         }
@@ -1655,7 +2492,52 @@ impl Application for RefBoxApp {
                     CyclingParameter::AlertVolume => settings.sound.whistle_vol.cycle(),
                     CyclingParameter::AboveWaterVol => settings.sound.above_water_vol.cycle(),
                     CyclingParameter::UnderWaterVol => settings.sound.under_water_vol.cycle(),
+                    CyclingParameter::BlackGoalSound => settings.sound.black_goal_sound.cycle(),
+                    CyclingParameter::WhiteGoalSound => settings.sound.white_goal_sound.cycle(),
+                    CyclingParameter::PenaltyExpirySound => {
+                        settings.sound.penalty_expiry_sound.cycle()
+                    }
+                    CyclingParameter::MercyRuleMargin => {
+                        settings.config.mercy_rule_margin = match settings.config.mercy_rule_margin
+                        {
+                            0 => 3,
+                            10.. => 0,
+                            margin => margin + 1,
+                        }
+                    }
+                    CyclingParameter::MercyRulePeriod => {
+                        settings.config.mercy_rule_period = match settings.config.mercy_rule_period
+                        {
+                            GamePeriod::FirstHalf => GamePeriod::SecondHalf,
+                            GamePeriod::SecondHalf => GamePeriod::OvertimeFirstHalf,
+                            GamePeriod::OvertimeFirstHalf => GamePeriod::OvertimeSecondHalf,
+                            _ => GamePeriod::FirstHalf,
+                        }
+                    }
+                    CyclingParameter::ShootoutRounds => {
+                        settings.config.shootout_rounds = match settings.config.shootout_rounds {
+                            10.. => 1,
+                            rounds => rounds + 1,
+                        }
+                    }
+                    CyclingParameter::SuddenDeathResolution => {
+                        settings.config.sudden_death_resolution = match settings
+                            .config
+                            .sudden_death_resolution
+                        {
+                            SuddenDeathResolution::Draw => SuddenDeathResolution::Shootout,
+                            SuddenDeathResolution::Shootout => {
+                                SuddenDeathResolution::RefereeDecision
+                            }
+                            SuddenDeathResolution::RefereeDecision => SuddenDeathResolution::Draw,
+                        }
+                    }
                     CyclingParameter::Mode => settings.mode.cycle(),
+                    CyclingParameter::CatchUpPolicy => settings.catch_up_policy.cycle(),
+                    CyclingParameter::Language => settings.language.cycle(),
+                    CyclingParameter::UiScale => {
+                        settings.ui_scale = next_ui_scale(settings.ui_scale)
+                    }
                 }
             }
             Message::RequestRemoteId => {
@@ -1700,14 +2582,162 @@ impl Application for RefBoxApp {
                     ConfirmationKind::GameConfigChanged(ref config),
                 ) = self.app_state
                 {
-                    Some(config.clone())
+                    Some(config.as_ref().clone())
                 } else {
                     None
                 };
 
+                let escalation =
+                    if let AppState::ConfirmationPage(ConfirmationKind::Escalation(escalation)) =
+                        self.app_state
+                    {
+                        Some(escalation)
+                    } else {
+                        None
+                    };
+
+                let period_change =
+                    if let AppState::ConfirmationPage(ConfirmationKind::ChangePeriod {
+                        was_running,
+                        to,
+                        time,
+                        ..
+                    }) = self.app_state
+                    {
+                        Some((was_running, to, time))
+                    } else {
+                        None
+                    };
+
+                let in_penalty_edit = matches!(
+                    self.app_state,
+                    AppState::ConfirmationPage(ConfirmationKind::SuspendedPlayer {
+                        in_penalty_edit: true,
+                        ..
+                    })
+                );
+                let after_goal = matches!(
+                    self.app_state,
+                    AppState::ConfirmationPage(ConfirmationKind::SuspendedPlayer {
+                        in_penalty_edit: false,
+                        ..
+                    })
+                );
+
                 self.app_state = match selection {
                     ConfirmationOption::DiscardChanges => AppState::MainPage,
-                    ConfirmationOption::GoBack => AppState::EditGameConfig(ConfigPage::Main),
+                    ConfirmationOption::Acknowledge => {
+                        if in_penalty_edit {
+                            AppState::PenaltyOverview(BlackWhiteBundle { black: 0, white: 0 })
+                        } else if after_goal {
+                            self.mercy_rule_state()
+                        } else {
+                            AppState::MainPage
+                        }
+                    }
+                    ConfirmationOption::AddPenalty => {
+                        let escalation = escalation.unwrap();
+                        self.pen_edit.start_session().unwrap();
+                        AppState::KeypadPage(
+                            KeypadPage::Penalty(
+                                None,
+                                escalation.color,
+                                PenaltyKind::default(),
+                                escalation.infraction,
+                                false,
+                            ),
+                            escalation.player_number.unwrap_or(0).into(),
+                        )
+                    }
+                    ConfirmationOption::GoBack => {
+                        if let Some((was_running, period, time)) = period_change {
+                            AppState::TimeEdit(was_running, time, None, period)
+                        } else {
+                            AppState::EditGameConfig(ConfigPage::Main)
+                        }
+                    }
+                    ConfirmationOption::ChangePeriod => {
+                        let (was_running, period, time) = period_change.unwrap();
+                        let mut tm = self.tm.lock().unwrap();
+                        let now = Instant::now();
+                        let game_ended = tm.current_period() == GamePeriod::BetweenGames;
+                        // After a period change the clock is left stopped, so that play resumes
+                        // on the referee's signal
+                        let result = tm.change_period(period, time, now);
+                        if game_ended && result.is_ok() {
+                            self.reopened_game = Some(tm.game_number());
+                        }
+                        if was_running && result.is_err() {
+                            tm.start_clock(now);
+                            tm.update(now).unwrap();
+                        }
+                        let snapshot = tm.generate_snapshot(now).unwrap();
+                        std::mem::drop(tm);
+                        self.apply_snapshot(snapshot);
+                        match result {
+                            Ok(()) => self.last_app_state.clone(),
+                            Err(e) => {
+                                error!("Could not change the period: {e}");
+                                AppState::ConfirmationPage(ConfirmationKind::Error(e.to_string()))
+                            }
+                        }
+                    }
+                    ConfirmationOption::EndGameEarly => {
+                        let mut tm = self.tm.lock().unwrap();
+                        let now = Instant::now();
+
+                        if let Some(game) = self
+                            .games
+                            .as_ref()
+                            .and_then(|games| games.get(&tm.game_number()))
+                        {
+                            self.post_game_score(game, tm.get_scores());
+                        }
+
+                        tm.end_game_for_mercy_rule(now).unwrap();
+                        let snapshot = tm.generate_snapshot(now).unwrap();
+                        std::mem::drop(tm);
+                        self.apply_snapshot(snapshot);
+                        AppState::MainPage
+                    }
+                    ConfirmationOption::DeclareDraw => {
+                        let mut tm = self.tm.lock().unwrap();
+                        let now = Instant::now();
+
+                        if let Some(game) = self
+                            .games
+                            .as_ref()
+                            .and_then(|games| games.get(&tm.game_number()))
+                        {
+                            self.post_game_score(game, tm.get_scores());
+                        }
+
+                        tm.decide_sudden_death(SuddenDeathResolution::Draw, now)
+                            .unwrap();
+                        let snapshot = tm.generate_snapshot(now).unwrap();
+                        std::mem::drop(tm);
+                        self.apply_snapshot(snapshot);
+                        AppState::MainPage
+                    }
+                    ConfirmationOption::EndPractice => {
+                        let mut tm = self.tm.lock().unwrap();
+                        let now = Instant::now();
+                        tm.end_practice(now).unwrap();
+                        let snapshot = tm.generate_snapshot(now).unwrap();
+                        std::mem::drop(tm);
+                        self.apply_snapshot(snapshot);
+                        AppState::MainPage
+                    }
+                    ConfirmationOption::StartShootout => {
+                        let mut tm = self.tm.lock().unwrap();
+                        let now = Instant::now();
+                        tm.decide_sudden_death(SuddenDeathResolution::Shootout, now)
+                            .unwrap();
+                        let snapshot = tm.generate_snapshot(now).unwrap();
+                        std::mem::drop(tm);
+                        self.apply_snapshot(snapshot);
+                        AppState::MainPage
+                    }
                     ConfirmationOption::EndGameAndApply => {
                         let edited_settings = self.edited_settings.take().unwrap();
                         let mut tm = self.tm.lock().unwrap();
@@ -1777,6 +2807,7 @@ impl Application for RefBoxApp {
             }
             Message::ConfirmScores(snapshot) => {
                 self.apply_snapshot(snapshot);
+                self.pending_golden_goal = None;
 
                 let scores = BlackWhiteBundle {
                     black: self.snapshot.b_score,
@@ -1786,6 +2817,11 @@ impl Application for RefBoxApp {
                 self.app_state = AppState::ConfirmScores(scores);
                 trace!("AppState changed to {:?}", self.app_state);
             }
+            Message::SuddenDeathLimitReached(snapshot) => {
+                self.apply_snapshot(snapshot);
+                self.app_state = AppState::ConfirmationPage(ConfirmationKind::SuddenDeathLimit);
+                trace!("AppState changed to {:?}", self.app_state);
+            }
             Message::ScoreConfirmation { correct } => {
                 self.app_state = if let AppState::ConfirmScores(scores) = self.app_state {
                     if correct {
@@ -1800,10 +2836,19 @@ impl Application for RefBoxApp {
                             self.post_game_score(game, scores);
                         }
 
-                        tm.set_scores(scores, now);
+                        let golden_goal = Self::apply_final_scores(
+                            &mut self.pending_golden_goal,
+                            &mut tm,
+                            scores,
+                            now,
+                        );
                         tm.start_clock(now);
                         tm.update(now + Duration::from_millis(2)).unwrap(); // Need to update after game ends
+                        std::mem::drop(tm);
 
+                        if let Some(color) = golden_goal {
+                            self.play_goal_sound(color);
+                        }
                         AppState::MainPage
                     } else {
                         AppState::ScoreEdit {
@@ -1825,7 +2870,7 @@ impl Application for RefBoxApp {
                 } else {
                     tm.start_team_timeout(color, now).unwrap();
                 }
-                if let AppState::TimeEdit(_, _, ref mut time) = self.app_state {
+                if let AppState::TimeEdit(_, _, ref mut time, _) = self.app_state {
                     *time = Some(tm.timeout_clock_time(now).unwrap());
                 }
                 let snapshot = tm.generate_snapshot(now).unwrap();
@@ -1840,7 +2885,7 @@ impl Application for RefBoxApp {
                 } else {
                     tm.start_ref_timeout(now).unwrap();
                 }
-                if let AppState::TimeEdit(_, _, ref mut time) = self.app_state {
+                if let AppState::TimeEdit(_, _, ref mut time, _) = self.app_state {
                     *time = Some(tm.timeout_clock_time(now).unwrap());
                 }
                 let snapshot = tm.generate_snapshot(now).unwrap();
@@ -1861,7 +2906,7 @@ impl Application for RefBoxApp {
                 } else {
                     tm.start_penalty_shot(now).unwrap();
                 }
-                if let AppState::TimeEdit(_, _, ref mut time) = self.app_state {
+                if let AppState::TimeEdit(_, _, ref mut time, _) = self.app_state {
                     *time = Some(tm.timeout_clock_time(now).unwrap());
                 }
                 let snapshot = tm.generate_snapshot(now).unwrap();
@@ -1869,6 +2914,8 @@ impl Application for RefBoxApp {
                 self.apply_snapshot(snapshot);
             }
             Message::EndTimeout => {
+                let was_penalty_shot =
+                    matches!(self.snapshot.timeout, TimeoutSnapshot::PenaltyShot(_));
                 let mut tm = self.tm.lock().unwrap();
                 let now = Instant::now();
                 let would_end = tm.timeout_end_would_end_game(now).unwrap();
@@ -1890,9 +2937,18 @@ impl Application for RefBoxApp {
 
                     self.app_state = AppState::ConfirmScores(scores);
                     trace!("AppState changed to {:?}", self.app_state);
+                } else if was_penalty_shot && matches!(self.app_state, AppState::MainPage) {
+                    self.app_state = AppState::KeypadPage(
+                        KeypadPage::PenaltyShotOutcome {
+                            color: Color::Black,
+                            infraction: Infraction::Unknown,
+                            expanded: false,
+                        },
+                        0,
+                    );
                 }
 
-                if let AppState::TimeEdit(_, _, ref mut timeout) = self.app_state {
+                if let AppState::TimeEdit(_, _, ref mut timeout, _) = self.app_state {
                     *timeout = None;
                 }
                 trace!("AppState changed to {:?}", self.app_state);
@@ -1957,6 +3013,45 @@ impl Application for RefBoxApp {
             }
             Message::StartClock => self.tm.lock().unwrap().start_clock(Instant::now()),
             Message::StopClock => self.tm.lock().unwrap().stop_clock(Instant::now()).unwrap(),
+            Message::KeyPressed(key) => {
+                // Ignore the repeated presses generated while a key is held down
+                if !self.held_keys.contains(&key) {
+                    self.held_keys.push(key);
+                    if let Some(msg) = self.key_message(key) {
+                        return Command::batch([command, self.update(msg)]);
+                    }
+                }
+            }
+            Message::KeyReleased(key) => self.held_keys.retain(|k| *k != key),
+            Message::WindowUnfocused => {
+                // The key releases are not reported while the window is unfocused
+                self.held_keys.clear();
+            }
+            Message::PinEntryComplete { canceled } => {
+                if let AppState::KeypadPage(KeypadPage::Pin(action), pin) = self.app_state {
+                    let return_state = self.pin_return_state.take().unwrap_or(AppState::MainPage);
+                    if canceled {
+                        self.app_state = return_state;
+                    } else {
+                        let role = self.config.settings_lock.role_for_pin(pin);
+                        if role >= action.required_role() {
+                            info!("Unlocked {action:?} with the {role:?} PIN");
+                            self.unlocked_role = Some(role);
+                            // The action may depend on the page it was started from
+                            self.app_state = return_state;
+                            return Command::batch([command, self.update(action.message())]);
+                        } else {
+                            warn!("Incorrect PIN entered for {action:?}");
+                            self.app_state = AppState::ConfirmationPage(ConfirmationKind::Error(
+                                "The PIN entered is incorrect.".to_string(),
+                            ));
+                        }
+                    }
+                } else {
+                    unreachable!()
+                }
+                trace!("AppState changed to {:?}", self.app_state);
+            }
             Message::NoAction => {}
         };
 
@@ -1965,7 +3060,7 @@ impl Application for RefBoxApp {
 
     fn view(&self) -> Element<Message> {
         let clock_running = self.tm.lock().unwrap().clock_is_running();
-        let mut main_view = column![match self.app_state {
+        let page = match self.app_state {
             AppState::MainPage => {
                 let new_config = if self.snapshot.current_period == GamePeriod::BetweenGames {
                     self.tm
@@ -1992,10 +3087,12 @@ impl Application for RefBoxApp {
                     clock_running,
                 )
             }
-            AppState::TimeEdit(_, time, timeout_time) => build_time_edit_view(
+            AppState::TimeEdit(_, time, timeout_time, period) => build_time_edit_view(
                 &self.snapshot,
                 time,
                 timeout_time,
+                period,
+                &period_choices(&self.tm.lock().unwrap()),
                 self.config.mode,
                 clock_running,
             ),
@@ -2009,6 +3106,13 @@ impl Application for RefBoxApp {
                 self.config.mode,
                 clock_running,
             ),
+            AppState::GoalTimeline(index) => build_goal_timeline_page(
+                &self.snapshot,
+                self.tm.lock().unwrap().goals(),
+                index,
+                self.config.mode,
+                clock_running,
+            ),
             AppState::PenaltyOverview(indices) => build_penalty_overview_page(
                 &self.snapshot,
                 self.pen_edit.get_printable_lists(Instant::now()).unwrap(),
@@ -2037,16 +3141,37 @@ impl Application for RefBoxApp {
                 &self.config,
                 clock_running,
             ),
-            AppState::GameDetailsPage => build_game_info_page(
-                &self.snapshot,
-                &self.config.game,
-                self.using_uwhscores,
-                &self.games,
-                self.config.mode,
-                clock_running,
+            AppState::GameDetailsPage => {
+                let tm = self.tm.lock().unwrap();
+                build_game_info_page(
+                    &self.snapshot,
+                    &self.config.game,
+                    self.using_uwhscores,
+                    &self.games,
+                    self.config.mode,
+                    clock_running,
+                    !self.config.training_programs.is_empty(),
+                    tm.schedule_drift(Instant::now()),
+                    tm.schedule_delay(),
+                )
+            }
+            AppState::TrainingPage { .. } => build_training_page(
+                self.training.as_ref().unwrap(),
+                self.config.training_programs.len(),
+                Instant::now(),
             ),
-            AppState::WarningsSummaryPage =>
-                build_warnings_summary_page(&self.snapshot, self.config.mode, clock_running,),
+            AppState::SessionSuspended => {
+                let tm = self.tm.lock().unwrap();
+                let now = Instant::now();
+                let suspended_for = tm
+                    .session_suspended_at()
+                    .map(|at| now.saturating_duration_since(at))
+                    .unwrap_or(Duration::ZERO);
+                build_session_suspended_page(&self.snapshot, suspended_for, tm.schedule_delay())
+            }
+            AppState::WarningsSummaryPage => {
+                build_warnings_summary_page(&self.snapshot, self.config.mode, clock_running)
+            }
             AppState::EditGameConfig(page) => build_game_config_edit_page(
                 &self.snapshot,
                 self.edited_settings.as_ref().unwrap(),
@@ -2080,9 +3205,13 @@ impl Application for RefBoxApp {
                 self.config.mode,
                 clock_running,
             ),
-        }]
-        .spacing(SPACING)
-        .padding(PADDING);
+        };
+
+        let mut main_view = column![].spacing(SPACING).padding(PADDING);
+        if self.config.penalty_expiry_alerts && !self.snapshot.recent_penalty_expiries.is_empty() {
+            main_view = main_view.push(build_penalty_expiry_banner(&self.snapshot));
+        }
+        main_view = main_view.push(page);
 
         match self.app_state {
             AppState::ScoreEdit {
@@ -2102,6 +3231,65 @@ impl Application for RefBoxApp {
     }
 }
 
+fn keypad_button(key: KeyCode) -> Option<KeypadButton> {
+    match key {
+        KeyCode::Key0 | KeyCode::Numpad0 => Some(KeypadButton::Zero),
+        KeyCode::Key1 | KeyCode::Numpad1 => Some(KeypadButton::One),
+        KeyCode::Key2 | KeyCode::Numpad2 => Some(KeypadButton::Two),
+        KeyCode::Key3 | KeyCode::Numpad3 => Some(KeypadButton::Three),
+        KeyCode::Key4 | KeyCode::Numpad4 => Some(KeypadButton::Four),
+        KeyCode::Key5 | KeyCode::Numpad5 => Some(KeypadButton::Five),
+        KeyCode::Key6 | KeyCode::Numpad6 => Some(KeypadButton::Six),
+        KeyCode::Key7 | KeyCode::Numpad7 => Some(KeypadButton::Seven),
+        KeyCode::Key8 | KeyCode::Numpad8 => Some(KeypadButton::Eight),
+        KeyCode::Key9 | KeyCode::Numpad9 => Some(KeypadButton::Nine),
+        KeyCode::Backspace | KeyCode::Delete => Some(KeypadButton::Delete),
+        _ => None,
+    }
+}
+
+/// The message sent by the button that completes `page`. The outcome pages don't have a single
+/// button that completes them, so they must be completed on screen
+fn keypad_submit_message(page: KeypadPage) -> Option<Message> {
+    match page {
+        KeypadPage::AddScore(_) => Some(Message::AddScoreComplete { canceled: false }),
+        KeypadPage::EditGoal { .. } => Some(Message::GoalEditComplete {
+            canceled: false,
+            deleted: false,
+        }),
+        KeypadPage::Penalty(_, _, _, _, _) => Some(Message::PenaltyEditComplete {
+            canceled: false,
+            deleted: false,
+        }),
+        KeypadPage::GameNumber | KeypadPage::TeamTimeouts(_) => {
+            Some(Message::ParameterEditComplete { canceled: false })
+        }
+        KeypadPage::Pin(_) => Some(Message::PinEntryComplete { canceled: false }),
+        KeypadPage::FoulAdd {
+            ret_to_overview, ..
+        } => Some(Message::FoulEditComplete {
+            canceled: false,
+            deleted: false,
+            ret_to_overview,
+        }),
+        KeypadPage::WarningAdd {
+            ret_to_overview, ..
+        } => Some(Message::WarningEditComplete {
+            canceled: false,
+            deleted: false,
+            ret_to_overview,
+        }),
+        KeypadPage::PenaltyShotOutcome { .. } | KeypadPage::ShootoutShot(_) => None,
+    }
+}
+
+/// The periods that the time edit page can move the game to, in order, including the current one
+fn period_choices(tm: &TournamentManager) -> Vec<GamePeriod> {
+    std::iter::successors(Some(GamePeriod::BetweenGames), |p| p.next_period())
+        .filter(|&p| p == tm.current_period() || tm.can_change_period(p).is_ok())
+        .collect()
+}
+
 #[derive(Clone, Debug)]
 struct TimeUpdater {
     tm: Arc<Mutex<TournamentManager>>,
@@ -2176,7 +3364,12 @@ impl Recipe for TimeUpdater {
                 Message::ConfirmScores
             } else {
                 tm.update(now).unwrap();
-                Message::NewSnapshot
+                if tm.awaiting_sudden_death_decision() {
+                    clock_running = false;
+                    Message::SuddenDeathLimitReached
+                } else {
+                    Message::NewSnapshot
+                }
             };
 
             let snapshot = match tm.generate_snapshot(now) {
@@ -20,6 +20,27 @@ pub const LARGE_TEXT: f32 = 66.0;
 
 pub const LINE_HEIGHT: LineHeight = LineHeight::Relative(1.15);
 
+/// The window size that all the sizes above were chosen for. The whole UI is scaled so that this
+/// size fits in the actual window.
+pub const DESIGN_WIDTH: f32 = 945.0;
+pub const DESIGN_HEIGHT: f32 = 691.0;
+
+pub const MIN_SCALE_FACTOR: f64 = 0.5;
+pub const MAX_SCALE_FACTOR: f64 = 4.0;
+
+/// The smallest window the UI can be squeezed into
+pub const MIN_WINDOW_SIZE: (u32, u32) = (
+    (DESIGN_WIDTH * MIN_SCALE_FACTOR as f32) as u32,
+    (DESIGN_HEIGHT * MIN_SCALE_FACTOR as f32) as u32,
+);
+
+/// Calculates the factor to scale the UI by so that it fits in a window of `window_size`
+/// (unscaled pixels), with an extra `ui_scale` given in percent
+pub fn scale_factor(window_size: (f32, f32), ui_scale: u16) -> f64 {
+    let fit = (window_size.0 / DESIGN_WIDTH).min(window_size.1 / DESIGN_HEIGHT) as f64;
+    (fit * ui_scale as f64 / 100.0).clamp(MIN_SCALE_FACTOR, MAX_SCALE_FACTOR)
+}
+
 // See https://stackoverflow.com/a/727339 for color mixing math. For darkening colors with pure
 // black, the math simplifies to new_r = orig_r * (1 - black_alpha), so we will multiply by the
 // value of (1 - black_alpha)
@@ -204,6 +225,7 @@ pub enum ContainerStyle {
     Black,
     White,
     Blue,
+    Yellow,
     ScrollBar,
     Disabled,
     Transparent,
@@ -219,6 +241,7 @@ impl container::StyleSheet for ApplicationTheme {
             ContainerStyle::Black => cont_style(BLACK, WHITE),
             ContainerStyle::White => cont_style(WHITE, BLACK),
             ContainerStyle::Blue => cont_style(BLUE, WHITE),
+            ContainerStyle::Yellow => cont_style(YELLOW, BLACK),
             ContainerStyle::ScrollBar => cont_style(WINDOW_BACKGROUND, BLACK),
             ContainerStyle::Disabled => container::Appearance {
                 text_color: Some(DISABLED_COLOR),
@@ -339,3 +362,34 @@ impl application::StyleSheet for ApplicationTheme {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_scale_factor() {
+        assert_eq!(scale_factor((DESIGN_WIDTH, DESIGN_HEIGHT), 100), 1.0);
+        assert_eq!(
+            scale_factor((DESIGN_WIDTH * 2.0, DESIGN_HEIGHT * 2.0), 100),
+            2.0
+        );
+        // The smaller dimension limits the scale
+        assert_eq!(
+            scale_factor((DESIGN_WIDTH * 3.0, DESIGN_HEIGHT * 2.0), 100),
+            2.0
+        );
+        assert_eq!(scale_factor((DESIGN_WIDTH * 2.0, DESIGN_HEIGHT), 100), 1.0);
+        assert_eq!(scale_factor((DESIGN_WIDTH, DESIGN_HEIGHT), 150), 1.5);
+        assert_eq!(
+            scale_factor((DESIGN_WIDTH * 2.0, DESIGN_HEIGHT * 2.0), 75),
+            1.5
+        );
+        // The scale is limited
+        assert_eq!(scale_factor((10.0, 10.0), 100), MIN_SCALE_FACTOR);
+        assert_eq!(
+            scale_factor((DESIGN_WIDTH * 10.0, DESIGN_HEIGHT * 10.0), 100),
+            MAX_SCALE_FACTOR
+        );
+    }
+}
@@ -26,6 +26,7 @@ const SERIAL_SEND_SPACING: Duration = Duration::from_millis(100);
 const WORKER_CHANNEL_LEN: usize = 4;
 const FLASH_DURATION: Duration = Duration::from_millis(300); // Used by the simulator
 const FLASH_LENGTH: u8 = 3; // Number of transmit cycles to flash for (each cycle is 100ms)
+const PENALTY_PAGE_DURATION: Duration = Duration::from_secs(4);
 
 #[derive(Debug)]
 pub struct UpdateSender {
@@ -38,6 +39,7 @@ impl UpdateSender {
     pub fn new(
         initial: Vec<SerialPortBuilder>,
         binary_port: u16,
+        binary_v2_port: u16,
         json_port: u16,
         hide_time: bool,
     ) -> Self {
@@ -50,7 +52,12 @@ impl UpdateSender {
 
         let server_join = task::spawn(Server::new(rx, initial, hide_time).run_loop());
 
-        let listener_join = task::spawn(listener_loop(tx.clone(), binary_port, json_port));
+        let listener_join = task::spawn(listener_loop(
+            tx.clone(),
+            binary_port,
+            binary_v2_port,
+            json_port,
+        ));
 
         Self {
             tx,
@@ -211,12 +218,14 @@ async fn serial_worker_loop(
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum SendType {
     Binary,
+    BinaryV2,
     Json,
 }
 
 #[derive(Debug)]
 enum WorkerTx {
     Binary(mpsc::Sender<Vec<u8>>),
+    BinaryV2(mpsc::Sender<Vec<u8>>),
     Json(mpsc::Sender<Vec<u8>>),
     Serial(mpsc::Sender<SerialWorkerMessage>),
 }
@@ -235,6 +244,13 @@ impl WorkerHandle {
         }
     }
 
+    fn new_binary_v2(tx: mpsc::Sender<Vec<u8>>, join: JoinHandle<Result<(), WorkerError>>) -> Self {
+        WorkerHandle {
+            tx: WorkerTx::BinaryV2(tx),
+            join,
+        }
+    }
+
     fn new_json(tx: mpsc::Sender<Vec<u8>>, join: JoinHandle<Result<(), WorkerError>>) -> Self {
         WorkerHandle {
             tx: WorkerTx::Json(tx),
@@ -256,6 +272,10 @@ impl WorkerHandle {
         matches!(self.tx, WorkerTx::Binary(_))
     }
 
+    fn is_binary_v2(&self) -> bool {
+        matches!(self.tx, WorkerTx::BinaryV2(_))
+    }
+
     fn is_json(&self) -> bool {
         matches!(self.tx, WorkerTx::Json(_))
     }
@@ -267,12 +287,16 @@ impl WorkerHandle {
     fn send(
         &self,
         binary: &[u8],
+        binary_v2: &[u8],
         json: &[u8],
         snapshot: &GameSnapshotNoHeap,
         white_on_right: bool,
     ) -> Result<(), TrySendError<String>> {
         match self.tx {
             WorkerTx::Binary(ref tx) => tx.try_send(Vec::from(binary)).map_err(error_formatter),
+            WorkerTx::BinaryV2(ref tx) => {
+                tx.try_send(Vec::from(binary_v2)).map_err(error_formatter)
+            }
             WorkerTx::Json(ref tx) => tx.try_send(Vec::from(json)).map_err(error_formatter),
             WorkerTx::Serial(ref tx) => tx
                 .try_send(SerialWorkerMessage::NewSnapshot(
@@ -306,11 +330,17 @@ struct Server {
     senders: HashMap<usize, WorkerHandle>,
     rx: mpsc::Receiver<ServerMessage>,
     has_binary: bool,
+    has_binary_v2: bool,
     has_json: bool,
     snapshot: GameSnapshotNoHeap,
+    /// The same page for the legacy frame, which has no room for the hidden penalty indicator
+    legacy_snapshot: GameSnapshotNoHeap,
+    full_snapshot: GameSnapshot,
+    penalty_page: usize,
     white_on_right: bool,
     flash: bool,
     binary: Vec<u8>,
+    binary_v2: Vec<u8>,
     json: Vec<u8>,
     hide_time: bool,
 }
@@ -326,11 +356,16 @@ impl Server {
             senders: HashMap::new(),
             rx,
             has_binary: false,
+            has_binary_v2: false,
             has_json: false,
             snapshot: Default::default(),
+            legacy_snapshot: Default::default(),
+            full_snapshot: Default::default(),
+            penalty_page: 0,
             white_on_right: false,
             flash: false,
             binary: Vec::new(),
+            binary_v2: Vec::new(),
             json: Vec::new(),
             hide_time,
         };
@@ -354,6 +389,7 @@ impl Server {
             self.next_id,
             match send_type {
                 SendType::Binary => WorkerHandle::new_binary(tx, join),
+                SendType::BinaryV2 => WorkerHandle::new_binary_v2(tx, join),
                 SendType::Json => WorkerHandle::new_json(tx, join),
             },
         );
@@ -361,6 +397,7 @@ impl Server {
 
         match send_type {
             SendType::Binary => self.has_binary = true,
+            SendType::BinaryV2 => self.has_binary_v2 = true,
             SendType::Json => self.has_json = true,
         };
     }
@@ -378,77 +415,92 @@ impl Server {
 
     fn check_types(&mut self) {
         self.has_binary = self.senders.iter().any(|(_, handle)| handle.is_binary());
+        self.has_binary_v2 = self.senders.iter().any(|(_, handle)| handle.is_binary_v2());
         self.has_json = self.senders.iter().any(|(_, handle)| handle.is_json());
     }
 
-    fn encode(&mut self, new_snapshot: GameSnapshot) {
+    fn encode(&mut self, mut new_snapshot: GameSnapshot) {
         self.json = if self.has_json {
             (serde_json::to_string(&new_snapshot).unwrap() + "\n").into_bytes()
         } else {
             Vec::new()
         };
 
-        let next_time = new_snapshot.next_period_len_secs.unwrap_or(0) as u16;
-
-        self.snapshot = new_snapshot.into();
-
         if self.hide_time {
-            match self.snapshot.current_period {
+            match new_snapshot.current_period {
                 GamePeriod::BetweenGames
                 | GamePeriod::HalfTime
                 | GamePeriod::OvertimeHalfTime
-                | GamePeriod::PreOvertime => {
-                    if self.snapshot.secs_in_period < 15 {
-                        self.snapshot.secs_in_period = next_time;
+                | GamePeriod::PreOvertime
+                | GamePeriod::PreSuddenDeath => {
+                    // Sudden death starts at 0:00 unless it has a maximum length
+                    if new_snapshot.secs_in_period < 15 {
+                        new_snapshot.secs_in_period =
+                            new_snapshot.next_period_len_secs.unwrap_or(0);
                     };
                 }
-                GamePeriod::PreSuddenDeath => {
-                    if self.snapshot.secs_in_period < 15 {
-                        self.snapshot.secs_in_period = 0;
-                    }
-                }
                 GamePeriod::FirstHalf
                 | GamePeriod::OvertimeFirstHalf
                 | GamePeriod::OvertimeSecondHalf
                 | GamePeriod::SecondHalf
-                | GamePeriod::SuddenDeath => {}
+                | GamePeriod::SuddenDeath
+                | GamePeriod::Shootout => {}
             }
         }
 
+        self.full_snapshot = new_snapshot;
+        self.page_snapshots();
+        self.encode_flash();
+    }
+
+    fn page_snapshots(&mut self) {
+        self.snapshot =
+            GameSnapshotNoHeap::from_page(self.full_snapshot.clone(), self.penalty_page);
+        self.legacy_snapshot =
+            GameSnapshotNoHeap::from_legacy_page(self.full_snapshot.clone(), self.penalty_page);
+    }
+
+    fn has_hidden_penalties(&self) -> bool {
+        self.snapshot.b_hidden_penalties > 0 || self.snapshot.w_hidden_penalties > 0
+    }
+
+    fn next_penalty_page(&mut self) {
+        self.penalty_page = self.penalty_page.wrapping_add(1);
+        self.page_snapshots();
         self.encode_flash();
     }
 
     fn encode_flash(&mut self) {
+        let legacy_data = TransmittedData {
+            white_on_right: self.white_on_right,
+            flash: self.flash,
+            snapshot: self.legacy_snapshot.clone(),
+        };
+        let data = TransmittedData {
+            white_on_right: self.white_on_right,
+            flash: self.flash,
+            snapshot: self.snapshot.clone(),
+        };
         self.binary = if self.has_binary {
-            Vec::from(
-                TransmittedData {
-                    white_on_right: self.white_on_right,
-                    flash: self.flash,
-                    snapshot: self.snapshot.clone(),
-                }
-                .encode()
-                .unwrap(),
-            )
+            Vec::from(legacy_data.encode().unwrap())
         } else {
             Vec::new()
         };
-    }
-
-    fn send_to_workers(&mut self, only_binary: bool) {
-        let filter = |(_, handle): &(_, &WorkerHandle)| {
-            if only_binary {
-                handle.is_binary()
-            } else {
-                true
-            }
+        self.binary_v2 = if self.has_binary_v2 {
+            Vec::from(data.encode_v2().unwrap())
+        } else {
+            Vec::new()
         };
+    }
 
+    fn send_to_workers(&mut self, filter: impl Fn(&WorkerHandle) -> bool) {
         let mut to_drop = vec![];
-        for (id, handle) in self.senders.iter().filter(filter) {
+        for (id, handle) in self.senders.iter().filter(|(_, handle)| filter(handle)) {
             if let Err(e) = handle.send(
                 &self.binary,
+                &self.binary_v2,
                 &self.json,
-                &self.snapshot,
+                &self.legacy_snapshot,
                 self.white_on_right,
             ) {
                 if matches!(e, TrySendError::Closed(_)) {
@@ -466,6 +518,7 @@ impl Server {
 
     pub async fn run_loop(mut self) {
         let mut flash_ends = None;
+        let mut next_page = None;
 
         loop {
             let flash_end = if let Some(time) = flash_ends {
@@ -473,11 +526,21 @@ impl Server {
             } else {
                 FlashEnd::Never(core::future::pending())
             };
+            let page_change = if let Some(time) = next_page {
+                FlashEnd::Time(Box::pin(sleep_until(time)))
+            } else {
+                FlashEnd::Never(core::future::pending())
+            };
 
             select! {
                 _ = flash_end => {
                     self.flash = false;
                 }
+                _ = page_change => {
+                    self.next_penalty_page();
+                    self.send_to_workers(|handle| !handle.is_json()); // Only the panels need the new page
+                    next_page = Some(Instant::now() + PENALTY_PAGE_DURATION);
+                }
                 msg = self.rx.recv() => {
                     match msg {
                         Some(ServerMessage::NewConnection(send_type, stream)) => {
@@ -487,13 +550,19 @@ impl Server {
                         Some(ServerMessage::NewSnapshot(snapshot, white_on_right)) => {
                             self.white_on_right = white_on_right;
                             self.encode(*snapshot);
-                            self.send_to_workers(false);
+                            self.send_to_workers(|_| true);
+                            if !self.has_hidden_penalties() {
+                                next_page = None;
+                                self.penalty_page = 0;
+                            } else if next_page.is_none() {
+                                next_page = Some(Instant::now() + PENALTY_PAGE_DURATION);
+                            }
                         }
                         Some(ServerMessage::TriggerFlash) => {
                             self.flash = true;
                             flash_ends = Some(Instant::now() + FLASH_DURATION);
                             self.encode_flash();
-                            self.send_to_workers(true);  // Send to the binary listeners
+                            self.send_to_workers(|handle| handle.is_binary() || handle.is_binary_v2());  // Send to the binary listeners
                             for (_, handle) in self.senders.iter().filter(|(_, handle)| handle.is_serial()) {
                                 if let WorkerTx::Serial(tx) = &handle.tx {
                                     if let Err(e) = tx.try_send(SerialWorkerMessage::TriggerFlash) {
@@ -526,14 +595,21 @@ impl Drop for Server {
     }
 }
 
-async fn listener_loop(tx: mpsc::Sender<ServerMessage>, binary_port: u16, json_port: u16) {
-    info!("Starting Listeners for JSON (port {json_port}) and binary (port {binary_port})");
+async fn listener_loop(
+    tx: mpsc::Sender<ServerMessage>,
+    binary_port: u16,
+    binary_v2_port: u16,
+    json_port: u16,
+) {
+    info!("Starting Listeners for JSON (port {json_port}), binary (port {binary_port}) and binary v2 (port {binary_v2_port})");
     let binary_listener_v6 = TcpListener::bind(("::", binary_port)).await.unwrap();
+    let binary_v2_listener_v6 = TcpListener::bind(("::", binary_v2_port)).await.unwrap();
     let json_listener_v6 = TcpListener::bind(("::", json_port)).await.unwrap();
 
     // On some OSs, we must separately listen on IPv4, but on other OSs that
     // that isn't allowed, so we just try to listen on IPv4
     let binary_listener_v4 = TcpListener::bind(("0.0.0.0", binary_port)).await.ok();
+    let binary_v2_listener_v4 = TcpListener::bind(("0.0.0.0", binary_v2_port)).await.ok();
     let json_listener_v4 = TcpListener::bind(("0.0.0.0", json_port)).await.ok();
 
     info!("Listeners started");
@@ -546,6 +622,12 @@ async fn listener_loop(tx: mpsc::Sender<ServerMessage>, binary_port: u16, json_p
             } else {
                 Box::pin(iced::futures::future::pending())
             };
+        let binary_v2_v4_future: Pin<Box<dyn Future<Output = ListenResult> + Send>> =
+            if let Some(listener) = binary_v2_listener_v4.as_ref() {
+                Box::pin(listener.accept())
+            } else {
+                Box::pin(iced::futures::future::pending())
+            };
         let json_v4_future: Pin<Box<dyn Future<Output = ListenResult> + Send>> =
             if let Some(listener) = json_listener_v4.as_ref() {
                 Box::pin(listener.accept())
@@ -565,6 +647,17 @@ async fn listener_loop(tx: mpsc::Sender<ServerMessage>, binary_port: u16, json_p
                     Err(addr) => error!("New binary connection to {addr:?} failed"),
                 }
             }
+            conn = binary_v2_v4_future => {
+                match conn {
+                    Ok((stream, addr)) => {
+                        info!("New Binary v2 connection from {addr:?}");
+                        tx.send(ServerMessage::NewConnection(SendType::BinaryV2, stream))
+                            .await
+                            .unwrap();
+                    }
+                    Err(addr) => error!("New binary v2 connection to {addr:?} failed"),
+                }
+            }
             conn = json_v4_future => {
                 match conn {
                     Ok((stream, addr)) => {
@@ -587,6 +680,17 @@ async fn listener_loop(tx: mpsc::Sender<ServerMessage>, binary_port: u16, json_p
                     Err(addr) => error!("New binary connection to {addr:?} failed"),
                 }
             }
+            conn = binary_v2_listener_v6.accept() => {
+                match conn {
+                    Ok((stream, addr)) => {
+                        info!("New Binary v2 connection from {addr:?}");
+                        tx.send(ServerMessage::NewConnection(SendType::BinaryV2, stream))
+                            .await
+                            .unwrap();
+                    }
+                    Err(addr) => error!("New binary v2 connection to {addr:?} failed"),
+                }
+            }
             conn = json_listener_v6.accept() => {
                 match conn {
                     Ok((stream, addr)) => {
@@ -625,16 +729,67 @@ mod test {
     use std::io::ErrorKind;
     use tokio::io::AsyncReadExt;
     use uwh_common::game_snapshot::{
-        GamePeriod, Infraction, InfractionSnapshot, PenaltySnapshot, PenaltyTime, TimeoutSnapshot,
+        Color, GamePeriod, Infraction, InfractionSnapshot, PenaltySnapshot, PenaltyTime,
+        TimeoutSnapshot,
     };
 
     const BINARY_PORT: u16 = 12345;
     const JSON_PORT: u16 = 12346;
+    const BINARY_V2_PORT: u16 = 12347;
     const MAX_CONN_FAILS: usize = 20;
 
+    #[test]
+    fn test_legacy_penalty_paging() {
+        let (_tx, rx) = mpsc::channel(1);
+        let mut server = Server::new(rx, vec![], false);
+        server.has_binary = true;
+        server.has_binary_v2 = true;
+
+        let pen = |player_number| PenaltySnapshot {
+            player_number,
+            time: PenaltyTime::Seconds(60),
+            infraction: Infraction::Unknown,
+        };
+        server.encode(GameSnapshot {
+            b_penalties: (1..=4).map(pen).collect(),
+            ..Default::default()
+        });
+        assert!(server.has_hidden_penalties());
+
+        let nums = |snapshot: &GameSnapshotNoHeap| {
+            snapshot
+                .b_penalties
+                .iter()
+                .map(|pen| pen.player_number)
+                .collect::<Vec<_>>()
+        };
+        let legacy = |server: &Server| {
+            TransmittedData::decode(server.binary.as_slice().try_into().unwrap())
+                .unwrap()
+                .snapshot
+        };
+        let v2 = |server: &Server| {
+            TransmittedData::decode_v2(server.binary_v2.as_slice().try_into().unwrap())
+                .unwrap()
+                .snapshot
+        };
+
+        // The legacy frame uses every slot, the v2 frame leaves one for the indicator
+        assert_eq!(nums(&legacy(&server)), vec![1, 2, 3]);
+        assert_eq!(nums(&v2(&server)), vec![1, 2]);
+        assert_eq!(v2(&server).b_hidden_penalties, 2);
+        assert_eq!(nums(&server.legacy_snapshot), vec![1, 2, 3]);
+
+        server.next_penalty_page();
+        assert_eq!(nums(&legacy(&server)), vec![4]);
+        assert_eq!(nums(&v2(&server)), vec![3, 4]);
+        assert_eq!(nums(&server.legacy_snapshot), vec![4]);
+    }
+
     #[tokio::test]
     async fn test_update_sender() {
-        let update_sender = UpdateSender::new(vec![], BINARY_PORT, JSON_PORT, false);
+        let update_sender =
+            UpdateSender::new(vec![], BINARY_PORT, BINARY_V2_PORT, JSON_PORT, false);
 
         let mut binary_conn;
         let mut fail_count = 0;
@@ -674,7 +829,26 @@ mod test {
             };
         }
 
-        // Make a third connection to the binary port to ensure that the server has processed the first two
+        let mut binary_v2_conn;
+        let mut fail_count = 0;
+        loop {
+            match TcpStream::connect(("localhost", BINARY_V2_PORT)).await {
+                Ok(stream) => {
+                    binary_v2_conn = stream;
+                    break;
+                }
+                Err(e) => {
+                    if e.kind() == ErrorKind::ConnectionRefused {
+                        assert_le!(fail_count, MAX_CONN_FAILS);
+                        fail_count += 1;
+                    } else {
+                        panic!("Unexpected connection error: {e:?}");
+                    }
+                }
+            };
+        }
+
+        // Make a fourth connection to the binary port to ensure that the server has processed the first three
         println!("Connecting to server on binary port");
         let mut fail_count = 0;
         loop {
@@ -698,6 +872,7 @@ mod test {
         let snapshot = GameSnapshot {
             current_period: GamePeriod::FirstHalf,
             secs_in_period: 897,
+            tenths_in_period: None,
             timeout: TimeoutSnapshot::None,
             b_score: 2,
             w_score: 3,
@@ -780,20 +955,22 @@ mod test {
             next_game_number: 28,
             tournament_id: 1,
             recent_goal: None,
+            recent_penalty_expiries: vec![(Color::White, 7)],
+            shootout: None,
+            stop_clock_time: false,
+            schedule_delay_secs: 0,
             next_period_len_secs: Some(180),
         };
 
         let json_expected = serde_json::to_string(&snapshot).unwrap().into_bytes();
 
-        let binary_expected = Vec::from(
-            TransmittedData {
-                white_on_right,
-                flash,
-                snapshot: snapshot.clone().into(),
-            }
-            .encode()
-            .unwrap(),
-        );
+        let data = TransmittedData {
+            white_on_right,
+            flash,
+            snapshot: snapshot.clone().into(),
+        };
+        let binary_expected = Vec::from(data.encode().unwrap());
+        let binary_v2_expected = Vec::from(data.encode_v2().unwrap());
 
         update_sender
             .send_snapshot(snapshot, white_on_right)
@@ -803,15 +980,25 @@ mod test {
         let mut binary_result = vec![0u8; expected_binary_bytes];
         let mut binary_read_so_far = 0;
 
+        let expected_binary_v2_bytes = binary_v2_expected.len();
+        let mut binary_v2_result = vec![0u8; expected_binary_v2_bytes];
+        let mut binary_v2_read_so_far = 0;
+
         let expected_json_bytes = json_expected.len();
         let mut json_result = vec![0u8; expected_json_bytes];
         let mut json_read_so_far = 0;
 
-        while json_read_so_far < expected_json_bytes || binary_read_so_far < expected_binary_bytes {
+        while json_read_so_far < expected_json_bytes
+            || binary_read_so_far < expected_binary_bytes
+            || binary_v2_read_so_far < expected_binary_v2_bytes
+        {
             select! {
                 bytes = binary_conn.read(&mut binary_result[binary_read_so_far..]) => {
                     binary_read_so_far += bytes.unwrap();
                 }
+                bytes = binary_v2_conn.read(&mut binary_v2_result[binary_v2_read_so_far..]) => {
+                    binary_v2_read_so_far += bytes.unwrap();
+                }
                 bytes = json_conn.read(&mut json_result[json_read_so_far..]) => {
                     json_read_so_far += bytes.unwrap();
                 }
@@ -823,5 +1010,8 @@ mod test {
 
         assert_eq!(expected_binary_bytes, binary_read_so_far);
         assert_eq!(binary_expected, binary_result);
+
+        assert_eq!(expected_binary_v2_bytes, binary_v2_read_so_far);
+        assert_eq!(binary_v2_expected, binary_v2_result);
     }
 }
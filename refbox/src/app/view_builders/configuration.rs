@@ -6,8 +6,11 @@ use super::{
         SMALL_TEXT, SPACING,
     },
 };
-use crate::config::Mode;
 use crate::sound_controller::*;
+use crate::{
+    config::{CatchUpPolicy, Mode},
+    i18n::{tr, Language},
+};
 use collect_array::CollectArrayResult;
 use iced::{
     alignment::{Horizontal, Vertical},
@@ -35,6 +38,22 @@ pub(in super::super) struct EditableSettings {
     pub hide_time: bool,
     pub collect_scorer_cap_num: bool,
     pub track_fouls_and_warnings: bool,
+    pub single_key_start_stop: bool,
+    pub penalty_expiry_alerts: bool,
+    pub ui_scale: u16,
+    pub language: Language,
+    pub catch_up_policy: CatchUpPolicy,
+}
+
+const UI_SCALES: [u16; 8] = [75, 90, 100, 110, 125, 150, 175, 200];
+
+/// Returns the next UI scale option after `scale`, wrapping around after the largest
+pub(in super::super) fn next_ui_scale(scale: u16) -> u16 {
+    UI_SCALES
+        .iter()
+        .copied()
+        .find(|s| *s > scale)
+        .unwrap_or(UI_SCALES[0])
 }
 
 pub(in super::super) trait Cyclable
@@ -85,6 +104,17 @@ impl Cyclable for Volume {
     }
 }
 
+impl Cyclable for Language {
+    fn next(&self) -> Self {
+        match self {
+            Self::English => Self::French,
+            Self::French => Self::Spanish,
+            Self::Spanish => Self::German,
+            Self::German => Self::English,
+        }
+    }
+}
+
 impl Cyclable for Mode {
     fn next(&self) -> Self {
         match self {
@@ -95,6 +125,26 @@ impl Cyclable for Mode {
     }
 }
 
+impl Cyclable for CatchUpPolicy {
+    fn next(&self) -> Self {
+        match self {
+            Self::Off => Self::ShortenHalfTime,
+            Self::ShortenHalfTime => Self::ShortenBreaks,
+            Self::ShortenBreaks => Self::Both,
+            Self::Both => Self::Off,
+        }
+    }
+}
+
+fn catch_up_policy_string(policy: CatchUpPolicy) -> &'static str {
+    match policy {
+        CatchUpPolicy::Off => "OFF",
+        CatchUpPolicy::ShortenHalfTime => "HALF TIME",
+        CatchUpPolicy::ShortenBreaks => "BREAKS",
+        CatchUpPolicy::Both => "BOTH",
+    }
+}
+
 pub(in super::super) fn build_game_config_edit_page<'a>(
     snapshot: &GameSnapshot,
     settings: &EditableSettings,
@@ -236,12 +286,13 @@ fn make_tournament_config_page<'a>(
         using_uwhscores,
         current_tid,
         current_pool,
+        catch_up_policy,
         ..
     } = settings;
 
     let using_uwhscores = *using_uwhscores;
 
-    let rows: [Element<Message>; 4] = if using_uwhscores {
+    let rows: Vec<Element<Message>> = if using_uwhscores {
         let tournament_label = if let Some(ref tournaments) = tournaments {
             if let Some(tid) = current_tid {
                 match tournaments.get(tid) {
@@ -283,7 +334,7 @@ fn make_tournament_config_page<'a>(
             .and_then(|tourns| tourns.get(&(*current_tid)?)?.pools.as_ref())
             .map(|_| Message::SelectParameter(ListableParameter::Pool));
 
-        [
+        vec![
             make_value_button(
                 "TOURNAMENT:",
                 tournament_label,
@@ -295,7 +346,13 @@ fn make_tournament_config_page<'a>(
             make_value_button("COURT:", pool_label, (true, true), pool_btn_msg)
                 .height(Length::Fill)
                 .into(),
-            vertical_space(Length::Fill).into(),
+            make_message_button(
+                "EXPORT DISCIPLINE REPORT",
+                current_tid.map(|_| Message::ExportDisciplineReport),
+            )
+            .style(ButtonStyle::LightGray)
+            .height(Length::Fill)
+            .into(),
             row![
                 horizontal_space(Length::Fill),
                 horizontal_space(Length::Fill),
@@ -309,7 +366,7 @@ fn make_tournament_config_page<'a>(
             .into(),
         ]
     } else {
-        [
+        vec![
             row![
                 make_value_button(
                     "HALF LENGTH:",
@@ -414,6 +471,129 @@ fn make_tournament_config_page<'a>(
                         None
                     },
                 ),
+                make_value_button(
+                    "TD TEAM\nSHORT LENGTH:",
+                    if config.td_team_short_duration.is_zero() {
+                        tr("WHOLE GAME").to_string()
+                    } else {
+                        time_string(config.td_team_short_duration)
+                    },
+                    (false, true),
+                    Some(Message::EditParameter(LengthParameter::TdTeamShort)),
+                ),
+            ]
+            .spacing(SPACING)
+            .height(Length::Fill)
+            .into(),
+            row![
+                make_value_button(
+                    "MERCY RULE\nMARGIN:",
+                    if config.mercy_rule_margin == 0 {
+                        tr("OFF").to_string()
+                    } else {
+                        config.mercy_rule_margin.to_string()
+                    },
+                    (false, true),
+                    Some(Message::CycleParameter(CyclingParameter::MercyRuleMargin)),
+                ),
+                make_value_button(
+                    "MERCY RULE\nFROM:",
+                    period_string(config.mercy_rule_period),
+                    (false, true),
+                    if config.mercy_rule_margin != 0 {
+                        Some(Message::CycleParameter(CyclingParameter::MercyRulePeriod))
+                    } else {
+                        None
+                    },
+                ),
+                make_value_button(
+                    "SHOOTOUT\nALLOWED:",
+                    bool_string(config.shootout_allowed),
+                    (false, true),
+                    Some(Message::ToggleBoolParameter(
+                        BoolGameParameter::ShootoutAllowed,
+                    )),
+                ),
+            ]
+            .spacing(SPACING)
+            .height(Length::Fill)
+            .into(),
+            row![
+                make_value_button(
+                    "SHOOTOUT\nROUNDS:",
+                    config.shootout_rounds.to_string(),
+                    (false, true),
+                    if config.shootout_allowed {
+                        Some(Message::CycleParameter(CyclingParameter::ShootoutRounds))
+                    } else {
+                        None
+                    },
+                ),
+                make_value_button(
+                    "SD MAX\nLENGTH:",
+                    if config.sudden_death_max_duration.is_zero() {
+                        tr("NO LIMIT").to_string()
+                    } else {
+                        time_string(config.sudden_death_max_duration)
+                    },
+                    (false, true),
+                    if config.sudden_death_allowed {
+                        Some(Message::EditParameter(LengthParameter::SuddenDeathMax))
+                    } else {
+                        None
+                    },
+                ),
+                make_value_button(
+                    "AT SD\nLIMIT:",
+                    sudden_death_resolution_string(config.sudden_death_resolution),
+                    (false, true),
+                    if config.sudden_death_allowed && !config.sudden_death_max_duration.is_zero() {
+                        Some(Message::CycleParameter(
+                            CyclingParameter::SuddenDeathResolution,
+                        ))
+                    } else {
+                        None
+                    },
+                ),
+            ]
+            .spacing(SPACING)
+            .height(Length::Fill)
+            .into(),
+            row![
+                make_value_button(
+                    "STOP CLOCK\nLENGTH:",
+                    if config.stop_clock_duration.is_zero() {
+                        tr("OFF").to_string()
+                    } else {
+                        time_string(config.stop_clock_duration)
+                    },
+                    (false, true),
+                    Some(Message::EditParameter(LengthParameter::StopClock)),
+                ),
+                make_value_button(
+                    "STOP CLOCK\nIN OVERTIME:",
+                    bool_string(config.stop_clock_in_overtime),
+                    (false, true),
+                    if config.overtime_allowed && !config.stop_clock_duration.is_zero() {
+                        Some(Message::ToggleBoolParameter(
+                            BoolGameParameter::StopClockInOvertime,
+                        ))
+                    } else {
+                        None
+                    },
+                ),
+                make_value_button(
+                    "STOP CLOCK AT\nEVERY WHISTLE:",
+                    bool_string(config.stop_clock_at_every_whistle),
+                    (false, true),
+                    if !config.stop_clock_duration.is_zero() {
+                        Some(Message::ToggleBoolParameter(
+                            BoolGameParameter::StopClockAtEveryWhistle,
+                        ))
+                    } else {
+                        None
+                    },
+                ),
                 make_button("DONE")
                     .style(ButtonStyle::Green)
                     .width(Length::Fill)
@@ -427,14 +607,35 @@ fn make_tournament_config_page<'a>(
 
     let mut col = column![
         make_game_time_button(snapshot, false, false, mode, clock_running),
-        make_value_button(
-            "USING UWHPORTAL:",
-            bool_string(using_uwhscores),
-            (true, true),
-            Some(Message::ToggleBoolParameter(
-                BoolGameParameter::UsingUwhScores,
-            )),
-        )
+        row![
+            make_value_button(
+                "USING UWHPORTAL:",
+                bool_string(using_uwhscores),
+                (true, true),
+                Some(Message::ToggleBoolParameter(
+                    BoolGameParameter::UsingUwhScores,
+                )),
+            ),
+            make_value_button(
+                "PRACTICE MODE:",
+                bool_string(config.practice_mode),
+                (true, true),
+                if using_uwhscores {
+                    None
+                } else {
+                    Some(Message::ToggleBoolParameter(
+                        BoolGameParameter::PracticeMode,
+                    ))
+                },
+            ),
+            make_value_button(
+                "SCHEDULE\nCATCH UP:",
+                catch_up_policy_string(*catch_up_policy),
+                (false, true),
+                Some(Message::CycleParameter(CyclingParameter::CatchUpPolicy)),
+            ),
+        ]
+        .spacing(SPACING)
         .height(Length::Fill),
     ]
     .spacing(SPACING)
@@ -456,6 +657,9 @@ fn make_app_config_page<'a>(
     let EditableSettings {
         collect_scorer_cap_num,
         track_fouls_and_warnings,
+        single_key_start_stop,
+        penalty_expiry_alerts,
+        sound,
         ..
     } = settings;
 
@@ -488,12 +692,52 @@ fn make_app_config_page<'a>(
                     BoolGameParameter::FoulsAndWarnings,
                 )),
             ),
+            make_value_button(
+                "SINGLE KEY\nSTART/STOP",
+                bool_string(*single_key_start_stop),
+                (false, true),
+                Some(Message::ToggleBoolParameter(
+                    BoolGameParameter::SingleKeyStartStop,
+                )),
+            ),
+        ]
+        .spacing(SPACING)
+        .height(Length::Fill),
+        row![
+            make_value_button(
+                "LANGUAGE",
+                settings.language.native_name(),
+                (false, true),
+                Some(Message::CycleParameter(CyclingParameter::Language)),
+            ),
+            make_value_button(
+                "PENALTY END\nBANNER",
+                bool_string(*penalty_expiry_alerts),
+                (false, true),
+                Some(Message::ToggleBoolParameter(
+                    BoolGameParameter::PenaltyExpiryAlerts,
+                )),
+            ),
+        ]
+        .spacing(SPACING)
+        .height(Length::Fill),
+        row![
+            make_value_button(
+                "PENALTY END\nSOUND",
+                goal_sound_string(sound.penalty_expiry_sound),
+                (false, true),
+                if sound.sound_enabled {
+                    Some(Message::CycleParameter(
+                        CyclingParameter::PenaltyExpirySound,
+                    ))
+                } else {
+                    None
+                },
+            ),
             horizontal_space(Length::Fill),
         ]
         .spacing(SPACING)
         .height(Length::Fill),
-        vertical_space(Length::Fill),
-        vertical_space(Length::Fill),
         row![
             horizontal_space(Length::Fill),
             horizontal_space(Length::Fill),
@@ -519,23 +763,24 @@ fn make_display_config_page<'a>(
     let EditableSettings {
         white_on_right,
         hide_time,
+        ui_scale,
         ..
     } = settings;
 
-    let white = container("WHITE")
+    let white = container(tr("WHITE"))
         .center_x()
         .center_y()
         .width(Length::FillPortion(2))
         .height(Length::Fill)
         .style(ContainerStyle::White);
-    let black = container("BLACK")
+    let black = container(tr("BLACK"))
         .center_x()
         .center_y()
         .width(Length::FillPortion(2))
         .height(Length::Fill)
         .style(ContainerStyle::Black);
 
-    let center = text("STARTING SIDES")
+    let center = text(tr("STARTING SIDES"))
         .size(MEDIUM_TEXT)
         .line_height(LINE_HEIGHT)
         .vertical_alignment(Vertical::Center)
@@ -564,12 +809,20 @@ fn make_display_config_page<'a>(
     column![
         make_game_time_button(snapshot, false, false, mode, clock_running),
         row![sides_btn].spacing(SPACING).height(Length::Fill),
-        row![make_value_button(
-            "HIDE TIME FOR\nLAST 15 SECONDS",
-            bool_string(*hide_time),
-            (false, true),
-            Some(Message::ToggleBoolParameter(BoolGameParameter::HideTime))
-        )]
+        row![
+            make_value_button(
+                "HIDE TIME FOR\nLAST 15 SECONDS",
+                bool_string(*hide_time),
+                (false, true),
+                Some(Message::ToggleBoolParameter(BoolGameParameter::HideTime))
+            ),
+            make_value_button(
+                "UI SCALE",
+                format!("{ui_scale}%"),
+                (false, true),
+                Some(Message::CycleParameter(CyclingParameter::UiScale))
+            ),
+        ]
         .spacing(SPACING)
         .height(Length::Fill),
         vertical_space(Length::Fill),
@@ -702,8 +955,26 @@ fn make_sound_config_page<'a>(
         .spacing(SPACING)
         .height(Length::Fill),
         row![
-            horizontal_space(Length::Fill),
-            horizontal_space(Length::Fill),
+            make_value_button(
+                "BLACK GOAL\nSOUND:",
+                goal_sound_string(sound.black_goal_sound),
+                (false, true),
+                if sound.sound_enabled {
+                    Some(Message::CycleParameter(CyclingParameter::BlackGoalSound))
+                } else {
+                    None
+                },
+            ),
+            make_value_button(
+                "WHITE GOAL\nSOUND:",
+                goal_sound_string(sound.white_goal_sound),
+                (false, true),
+                if sound.sound_enabled {
+                    Some(Message::CycleParameter(CyclingParameter::WhiteGoalSound))
+                } else {
+                    None
+                },
+            ),
             make_button("DONE")
                 .style(ButtonStyle::Green)
                 .width(Length::Fill)
@@ -717,6 +988,13 @@ fn make_sound_config_page<'a>(
     .into()
 }
 
+fn goal_sound_string(sound: Option<BuzzerSound>) -> String {
+    match sound {
+        Some(sound) => sound.to_string().to_uppercase(),
+        None => "OFF".to_string(),
+    }
+}
+
 fn make_remote_config_page<'a>(
     snapshot: &GameSnapshot,
     settings: &EditableSettings,
@@ -727,7 +1005,7 @@ fn make_remote_config_page<'a>(
 ) -> Element<'a, Message> {
     const REMOTES_LIST_LEN: usize = 4;
 
-    let title = text("REMOTES")
+    let title = text(tr("REMOTES"))
         .line_height(LINE_HEIGHT)
         .height(Length::Fill)
         .width(Length::Fill)
@@ -750,7 +1028,7 @@ fn make_remote_config_page<'a>(
                 } else {
                     "DEFAULT".to_owned()
                 };
-                let sound_text = format!("SOUND: {}", sound_text);
+                let sound_text = format!("{}: {}", tr("SOUND"), tr(&sound_text));
 
                 container(
                     row![
@@ -869,6 +1147,23 @@ pub(in super::super) fn build_game_parameter_editor<'a>(
             "PRE SD BREAK",
             "The length of the break between the preceeding play period and Sudden Death",
         ),
+        LengthParameter::SuddenDeathMax => (
+            "SD MAX LEN",
+            "The longest Sudden Death can last. When it is reached the game is decided as \
+            chosen by the At SD Limit setting. A length of 0:00 means Sudden Death has no limit.",
+        ),
+        LengthParameter::TdTeamShort => (
+            "TD SHORT",
+            "After a Total Dismissal, the team plays a player down for this long before a \
+            substitute may enter. The dismissal is shown counting down like a penalty. A length \
+            of 0:00 means the team plays short for the rest of the game.",
+        ),
+        LengthParameter::StopClock => (
+            "STOP CLOCK",
+            "During this long at the end of the Second Half the game clock stops after every \
+            goal, and must be restarted by the referees. A length of 0:00 disables the stop-clock \
+            rules.",
+        ),
     };
 
     column![
@@ -876,7 +1171,7 @@ pub(in super::super) fn build_game_parameter_editor<'a>(
         vertical_space(Length::Fill),
         make_time_editor(title, length, false),
         vertical_space(Length::Fill),
-        text(String::from("Help: ") + hint)
+        text(format!("{}: {}", tr("Help"), tr(hint)))
             .size(SMALL_TEXT)
             .line_height(LINE_HEIGHT)
             .horizontal_alignment(Horizontal::Center),
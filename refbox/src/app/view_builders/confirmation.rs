@@ -18,10 +18,26 @@ pub(in super::super) fn build_confirmation_page<'a>(
     clock_running: bool,
 ) -> Element<'a, Message> {
     let header_text = match kind {
-        ConfirmationKind::GameConfigChanged(_) => "The game configuration can not be changed while a game is in progress.\n\nWhat would you like to do?",
-        ConfirmationKind::GameNumberChanged => "How would you like to apply this game number change?",
-        ConfirmationKind::Error(string) => string,
-        ConfirmationKind::UwhScoresIncomplete => "When UWHScores is enabled, all fields must be filled out."
+        ConfirmationKind::GameConfigChanged(_) => tr("The game configuration can not be changed while a game is in progress.\n\nWhat would you like to do?").to_string(),
+        ConfirmationKind::GameNumberChanged => tr("How would you like to apply this game number change?").to_string(),
+        ConfirmationKind::Error(string) => tr(string).to_string(),
+        ConfirmationKind::UwhScoresIncomplete => tr("When UWHScores is enabled, all fields must be filled out.").to_string(),
+        ConfirmationKind::Escalation(escalation) => escalation_string(escalation),
+        ConfirmationKind::SuspendedPlayer { text, .. } | ConfirmationKind::ReportExported(text) => {
+            text.clone()
+        }
+        ConfirmationKind::MercyRule => tr("The mercy rule margin has been reached.\n\nWould you like to end the game now?").to_string(),
+        ConfirmationKind::EndPractice => tr("Would you like to end the practice now?").to_string(),
+        ConfirmationKind::ChangePeriod { from, to, time, .. } => format!(
+            "{}\n\n{} {}\n{} {} ({})",
+            tr("Are you sure you want to change the period?"),
+            tr("FROM:"),
+            period_string(*from),
+            tr("TO:"),
+            period_string(*to),
+            time_string(*time),
+        ),
+        ConfirmationKind::SuddenDeathLimit => tr("Sudden death has reached its maximum length.\n\nHow should the game be decided?").to_string(),
             };
 
     let buttons = match kind {
@@ -79,6 +95,77 @@ pub(in super::super) fn build_confirmation_page<'a>(
                 ConfirmationOption::DiscardChanges,
             ),
         ],
+        ConfirmationKind::Escalation(_) => vec![
+            (
+                "ADD PENALTY",
+                ButtonStyle::Orange,
+                ConfirmationOption::AddPenalty,
+            ),
+            (
+                "IGNORE",
+                ButtonStyle::Green,
+                ConfirmationOption::DiscardChanges,
+            ),
+        ],
+        ConfirmationKind::SuspendedPlayer { .. } => {
+            vec![("OK", ButtonStyle::Green, ConfirmationOption::Acknowledge)]
+        }
+        ConfirmationKind::ReportExported(_) => {
+            vec![("OK", ButtonStyle::Green, ConfirmationOption::GoBack)]
+        }
+        ConfirmationKind::MercyRule => vec![
+            (
+                "END GAME",
+                ButtonStyle::Red,
+                ConfirmationOption::EndGameEarly,
+            ),
+            (
+                "CONTINUE PLAYING",
+                ButtonStyle::Green,
+                ConfirmationOption::DiscardChanges,
+            ),
+        ],
+        ConfirmationKind::EndPractice => vec![
+            (
+                "END PRACTICE",
+                ButtonStyle::Red,
+                ConfirmationOption::EndPractice,
+            ),
+            (
+                "CONTINUE PLAYING",
+                ButtonStyle::Green,
+                ConfirmationOption::DiscardChanges,
+            ),
+        ],
+        ConfirmationKind::ChangePeriod { .. } => vec![
+            (
+                "CHANGE PERIOD",
+                ButtonStyle::Red,
+                ConfirmationOption::ChangePeriod,
+            ),
+            (
+                "GO BACK TO EDITOR",
+                ButtonStyle::Green,
+                ConfirmationOption::GoBack,
+            ),
+        ],
+        ConfirmationKind::SuddenDeathLimit => vec![
+            (
+                "DECLARE A DRAW",
+                ButtonStyle::Red,
+                ConfirmationOption::DeclareDraw,
+            ),
+            (
+                "START A SHOOTOUT",
+                ButtonStyle::Orange,
+                ConfirmationOption::StartShootout,
+            ),
+            (
+                "AWARD A GOAL",
+                ButtonStyle::Green,
+                ConfirmationOption::DiscardChanges,
+            ),
+        ],
     };
 
     let buttons = buttons.into_iter().map(|(text, style, option)| {
@@ -122,6 +209,28 @@ pub(in super::super) fn build_confirmation_page<'a>(
     .into()
 }
 
+fn escalation_string(escalation: &Escalation) -> String {
+    let team = tr(&escalation.color.to_string().to_uppercase()).to_string();
+    let reason = match escalation.reason {
+        EscalationReason::PlayerWarnings(num) => {
+            format!("{}: {team} #{num}", tr("Warnings for player"))
+        }
+        EscalationReason::InfractionWarnings(infraction) => format!(
+            "{}: {team} - {}",
+            tr("Warnings for infraction"),
+            tr(&infractions::catalog().short_name(infraction))
+        ),
+        EscalationReason::TeamFouls => format!("{}: {team}", tr("Team fouls")),
+    };
+    let question = if escalation.player_number.is_some() {
+        tr("Add a penalty?")
+    } else {
+        tr("Add a team penalty?")
+    };
+
+    format!("{reason} ({})\n\n{question}", escalation.count)
+}
+
 pub(in super::super) fn build_score_confirmation_page<'a>(
     snapshot: &GameSnapshot,
     scores: BlackWhiteBundle<u8>,
@@ -129,8 +238,13 @@ pub(in super::super) fn build_score_confirmation_page<'a>(
     clock_running: bool,
 ) -> Element<'a, Message> {
     let header = text(format!(
-        "Is this score correct?\nConfirm with cheif referee.\n\nBlack: {}        White: {}\n",
-        scores.black, scores.white
+        "{}\n{}\n\n{}: {}        {}: {}\n",
+        tr("Is this score correct?"),
+        tr("Confirm with cheif referee."),
+        tr("Black"),
+        scores.black,
+        tr("White"),
+        scores.white
     ))
     .line_height(LINE_HEIGHT)
     .horizontal_alignment(Horizontal::Center);
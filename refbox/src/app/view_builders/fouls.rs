@@ -82,7 +82,7 @@ fn make_foul_list<'a>(
         None => "EQUAL".to_string(),
     };
 
-    let title = text(title)
+    let title = text(tr(&title))
         .line_height(LINE_HEIGHT)
         .height(Length::Fill)
         .width(Length::Fill)
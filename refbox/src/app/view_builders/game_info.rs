@@ -4,7 +4,7 @@ use super::{
 };
 use iced::{
     alignment::{Horizontal, Vertical},
-    widget::{column, horizontal_space, row, text},
+    widget::{column, row, text},
     Length,
 };
 
@@ -12,6 +12,7 @@ use std::fmt::Write;
 
 use uwh_common::game_snapshot::GameSnapshot;
 
+#[allow(clippy::too_many_arguments)]
 pub(in super::super) fn build_game_info_page<'a>(
     snapshot: &GameSnapshot,
     config: &GameConfig,
@@ -19,8 +20,18 @@ pub(in super::super) fn build_game_info_page<'a>(
     games: &Option<BTreeMap<u32, GameInfo>>,
     mode: Mode,
     clock_running: bool,
+    training_available: bool,
+    schedule_drift: Option<Duration>,
+    schedule_delay: Duration,
 ) -> Element<'a, Message> {
-    let (left_details, right_details) = details_strings(snapshot, config, using_uwhscores, games);
+    let (left_details, right_details) = details_strings(
+        snapshot,
+        config,
+        using_uwhscores,
+        games,
+        schedule_drift,
+        schedule_delay,
+    );
     column![
         make_game_time_button(snapshot, false, false, mode, clock_running,),
         row![
@@ -43,7 +54,20 @@ pub(in super::super) fn build_game_info_page<'a>(
                 .style(ButtonStyle::Red)
                 .width(Length::Fill)
                 .on_press(Message::ConfigEditComplete { canceled: true }),
-            horizontal_space(Length::Fill),
+            make_message_button(
+                "TRAINING TIMER",
+                (training_available && snapshot.current_period == GamePeriod::BetweenGames)
+                    .then_some(Message::ShowTraining),
+            )
+            .style(ButtonStyle::Blue)
+            .width(Length::Fill),
+            make_message_button(
+                "SUSPEND SESSION",
+                (snapshot.current_period == GamePeriod::BetweenGames)
+                    .then_some(Message::SuspendSession),
+            )
+            .style(ButtonStyle::Orange)
+            .width(Length::Fill),
             make_button("SETTINGS")
                 .style(ButtonStyle::Gray)
                 .width(Length::Fill)
@@ -62,6 +86,8 @@ fn details_strings(
     config: &GameConfig,
     using_uwhscores: bool,
     games: &Option<BTreeMap<u32, GameInfo>>,
+    schedule_drift: Option<Duration>,
+    schedule_delay: Duration,
 ) -> (String, String) {
     const TEAM_NAME_LEN_LIMIT: usize = 40;
     let mut left_string = String::new();
@@ -73,24 +99,24 @@ fn details_strings(
             if let Some(games) = games {
                 prev_game = match games.get(&snapshot.game_number) {
                     Some(game) => game_string_short(game),
-                    None if snapshot.game_number == 0 => "None".to_string(),
-                    None => format!("Error ({})", snapshot.game_number),
+                    None if snapshot.game_number == 0 => tr("None").to_string(),
+                    None => format!("{} ({})", tr("Error"), snapshot.game_number),
                 };
                 next_game = match games.get(&snapshot.next_game_number) {
                     Some(game) => game_string_short(game),
-                    None => format!("Error ({})", snapshot.next_game_number),
+                    None => format!("{} ({})", tr("Error"), snapshot.next_game_number),
                 };
             } else {
                 prev_game = if snapshot.game_number == 0 {
-                    "None".to_string()
+                    tr("None").to_string()
                 } else {
-                    format!("Error ({})", snapshot.game_number)
+                    format!("{} ({})", tr("Error"), snapshot.game_number)
                 };
-                next_game = format!("Error ({})", snapshot.next_game_number);
+                next_game = format!("{} ({})", tr("Error"), snapshot.next_game_number);
             }
         } else {
             prev_game = if snapshot.game_number == 0 {
-                "None".to_string()
+                tr("None").to_string()
             } else {
                 snapshot.game_number.to_string()
             };
@@ -99,8 +125,11 @@ fn details_strings(
 
         write!(
             &mut left_string,
-            "Last Game: {}, \nNext Game: {}\n",
-            prev_game, next_game
+            "{}: {}, \n{}: {}\n",
+            tr("Last Game"),
+            prev_game,
+            tr("Next Game"),
+            next_game
         )
         .unwrap();
         snapshot.next_game_number
@@ -110,15 +139,15 @@ fn details_strings(
             if let Some(games) = games {
                 game = match games.get(&snapshot.game_number) {
                     Some(game) => game_string_short(game),
-                    None => format!("Error ({})", snapshot.game_number),
+                    None => format!("{} ({})", tr("Error"), snapshot.game_number),
                 };
             } else {
-                game = format!("Error ({})", snapshot.game_number);
+                game = format!("{} ({})", tr("Error"), snapshot.game_number);
             }
         } else {
             game = snapshot.game_number.to_string();
         }
-        writeln!(&mut left_string, "Game: {}", game).unwrap();
+        writeln!(&mut left_string, "{}: {}", tr("Game"), game).unwrap();
         snapshot.game_number
     };
 
@@ -127,8 +156,10 @@ fn details_strings(
             if let Some(game) = games.get(&game_number) {
                 write!(
                     &mut left_string,
-                    "Black Team: {}\nWhite Team: {}\n",
+                    "{}: {}\n{}: {}\n",
+                    tr("Black Team"),
                     limit_team_name_len(&game.black, TEAM_NAME_LEN_LIMIT),
+                    tr("White Team"),
                     limit_team_name_len(&game.white, TEAM_NAME_LEN_LIMIT)
                 )
                 .unwrap()
@@ -136,31 +167,67 @@ fn details_strings(
         }
     }
 
+    if let Some(drift) = schedule_drift {
+        if drift.as_secs() == 0 {
+            writeln!(&mut left_string, "{}: {}", tr("Schedule"), tr("On Time")).unwrap();
+        } else {
+            writeln!(
+                &mut left_string,
+                "{}: {} {}",
+                tr("Schedule"),
+                time_string(drift),
+                tr("Behind")
+            )
+            .unwrap();
+        }
+    }
+    if !schedule_delay.is_zero() {
+        writeln!(
+            &mut left_string,
+            "{}: {}",
+            tr("Schedule Delayed By"),
+            time_string(schedule_delay)
+        )
+        .unwrap();
+    }
+
+    if config.practice_mode {
+        writeln!(
+            &mut left_string,
+            "{}",
+            tr("Practice: the clock counts up and results are not uploaded")
+        )
+        .unwrap();
+    }
+
     write!(
         &mut left_string,
-        "Half Length: {}\n\
-         Half Time Length: {}\n\
-         Overtime Allowed: {}\n",
+        "{}: {}\n{}: {}\n{}: {}\n",
+        tr("Half Length"),
         time_string(config.half_play_duration),
+        tr("Half Time Length"),
         time_string(config.half_time_duration),
+        tr("Overtime Allowed"),
         bool_string(config.overtime_allowed),
     )
     .unwrap();
     if config.overtime_allowed {
         write!(
             &mut left_string,
-            "Pre-Overtime Break Length: {}\n\
-             Overtime Half Length: {}\n\
-             Overtime Half Time Length: {}\n",
+            "{}: {}\n{}: {}\n{}: {}\n",
+            tr("Pre-Overtime Break Length"),
             time_string(config.pre_overtime_break),
+            tr("Overtime Half Length"),
             time_string(config.ot_half_play_duration),
+            tr("Overtime Half Time Length"),
             time_string(config.ot_half_time_duration),
         )
         .unwrap()
     };
     writeln!(
         &mut left_string,
-        "Sudden Death Allowed: {}",
+        "{}: {}",
+        tr("Sudden Death Allowed"),
         bool_string(config.sudden_death_allowed)
     )
     .unwrap();
@@ -168,21 +235,71 @@ fn details_strings(
     if config.sudden_death_allowed {
         writeln!(
             &mut left_string,
-            "Pre-Sudden-Death Break Length: {}",
+            "{}: {}",
+            tr("Pre-Sudden-Death Break Length"),
             time_string(config.pre_sudden_death_duration)
         )
+        .unwrap();
+        if !config.sudden_death_max_duration.is_zero() {
+            writeln!(
+                &mut left_string,
+                "{}: {} ({})",
+                tr("Sudden Death Max Length"),
+                time_string(config.sudden_death_max_duration),
+                sudden_death_resolution_string(config.sudden_death_resolution)
+            )
+            .unwrap()
+        }
+    };
+    if !config.stop_clock_duration.is_zero() {
+        let mut notes = vec![];
+        if config.overtime_allowed && config.stop_clock_in_overtime {
+            notes.push(tr("Including Overtime"));
+        }
+        if config.stop_clock_at_every_whistle {
+            notes.push(tr("At Every Whistle"));
+        }
+        writeln!(
+            &mut left_string,
+            "{}: {}{}",
+            tr("Stop Clock Length"),
+            time_string(config.stop_clock_duration),
+            if notes.is_empty() {
+                String::new()
+            } else {
+                format!(" ({})", notes.join(", "))
+            }
+        )
+        .unwrap()
+    };
+    writeln!(
+        &mut left_string,
+        "{}: {}",
+        tr("Shootout Allowed"),
+        bool_string(config.shootout_allowed)
+    )
+    .unwrap();
+    if config.shootout_allowed {
+        writeln!(
+            &mut left_string,
+            "{}: {}",
+            tr("Shootout Rounds"),
+            config.shootout_rounds
+        )
         .unwrap()
     };
     writeln!(
         &mut left_string,
-        "Team Timeouts Allowed Per Half: {}",
+        "{}: {}",
+        tr("Team Timeouts Allowed Per Half"),
         config.team_timeouts_per_half
     )
     .unwrap();
     if config.team_timeouts_per_half != 0 {
         writeln!(
             &mut left_string,
-            "Team Timeout Duration: {}",
+            "{}: {}",
+            tr("Team Timeout Duration"),
             time_string(config.team_timeout_duration)
         )
         .unwrap()
@@ -190,27 +307,56 @@ fn details_strings(
     if !using_uwhscores {
         writeln!(
             &mut left_string,
-            "Nominal Time Between Games: {}",
+            "{}: {}",
+            tr("Nominal Time Between Games"),
             time_string(config.nominal_break),
         )
         .unwrap();
     }
     writeln!(
         &mut left_string,
-        "Minimum Time Between Games: {}",
+        "{}: {}",
+        tr("Minimum Time Between Games"),
         time_string(config.minimum_break),
     )
     .unwrap();
+    if !config.td_team_short_duration.is_zero() {
+        writeln!(
+            &mut left_string,
+            "{}: {}",
+            tr("Team Short After Dismissal"),
+            time_string(config.td_team_short_duration),
+        )
+        .unwrap();
+    }
+    if config.mercy_rule_margin != 0 {
+        writeln!(
+            &mut left_string,
+            "{}: {} ({})",
+            tr("Mercy Rule Margin"),
+            config.mercy_rule_margin,
+            period_string(config.mercy_rule_period),
+        )
+        .unwrap();
+    }
 
-    writeln!(&mut left_string, "Stop clock in last 2 minutes: UNKNOWN").unwrap();
+    writeln!(
+        &mut left_string,
+        "{}: {}",
+        tr("Stop clock in last 2 minutes"),
+        tr("UNKNOWN")
+    )
+    .unwrap();
 
     write!(
         &mut right_string,
-        "Cheif ref: UNKNOWN\n\
-        Timer: UNKNOWN\n\
-        Water ref 1: UNKNOWN\n\
-        Water ref 2: UNKNOWN\n\
-        Water ref 3: UNKNOWN",
+        "{}: {unknown}\n{}: {unknown}\n{} 1: {unknown}\n{} 2: {unknown}\n{} 3: {unknown}",
+        tr("Cheif ref"),
+        tr("Timer"),
+        tr("Water ref"),
+        tr("Water ref"),
+        tr("Water ref"),
+        unknown = tr("UNKNOWN"),
     )
     .unwrap();
 
@@ -0,0 +1,113 @@
+use super::{
+    style::{ButtonStyle, ContainerStyle, Element, LINE_HEIGHT, MIN_BUTTON_SIZE, PADDING, SPACING},
+    *,
+};
+use crate::tournament_manager::Goal;
+use collect_array::CollectArrayResult;
+use iced::{
+    alignment::{Horizontal, Vertical},
+    widget::{button, column, horizontal_space, row, text},
+    Length,
+};
+
+use uwh_common::game_snapshot::{Color as GameColor, GameSnapshot};
+
+pub(in super::super) fn build_goal_timeline_page<'a>(
+    snapshot: &GameSnapshot,
+    goals: Vec<Goal>,
+    index: usize,
+    mode: Mode,
+    clock_running: bool,
+) -> Element<'a, Message> {
+    const GOAL_LIST_LEN: usize = 4;
+
+    let title = text(tr("GOALS"))
+        .line_height(LINE_HEIGHT)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .horizontal_alignment(Horizontal::Center)
+        .vertical_alignment(Vertical::Center);
+
+    let num_goals = goals.len();
+
+    let buttons: CollectArrayResult<_, GOAL_LIST_LEN> = goals
+        .into_iter()
+        .enumerate()
+        .skip(index)
+        .map(Some)
+        .chain([None].into_iter().cycle())
+        .take(GOAL_LIST_LEN)
+        .map(|goal| {
+            if let Some((i, goal)) = goal {
+                let style = match goal.color {
+                    GameColor::Black => ButtonStyle::Black,
+                    GameColor::White => ButtonStyle::White,
+                };
+
+                button(
+                    text(goal_string(i, &goal))
+                        .line_height(LINE_HEIGHT)
+                        .vertical_alignment(Vertical::Center)
+                        .horizontal_alignment(Horizontal::Left)
+                        .width(Length::Fill),
+                )
+                .padding(PADDING)
+                .height(Length::Fixed(MIN_BUTTON_SIZE))
+                .width(Length::Fill)
+                .style(style)
+                .on_press(Message::KeypadPage(KeypadPage::EditGoal {
+                    index: i,
+                    color: goal.color,
+                    time: goal.time,
+                }))
+                .into()
+            } else {
+                button(horizontal_space(Length::Shrink))
+                    .height(Length::Fixed(MIN_BUTTON_SIZE))
+                    .width(Length::Fill)
+                    .style(ButtonStyle::Gray)
+                    .into()
+            }
+        })
+        .collect();
+
+    column![
+        make_game_time_button(snapshot, false, false, mode, clock_running),
+        make_scroll_list(
+            buttons.unwrap(),
+            num_goals,
+            index,
+            title,
+            ScrollOption::Goals,
+            ContainerStyle::LightGray,
+        )
+        .height(Length::Fill),
+        row![
+            horizontal_space(Length::Fill),
+            horizontal_space(Length::Fill),
+            make_button("DONE")
+                .style(ButtonStyle::Green)
+                .width(Length::Fill)
+                .on_press(Message::GoalTimelineComplete),
+        ]
+        .spacing(SPACING),
+    ]
+    .spacing(SPACING)
+    .height(Length::Fill)
+    .into()
+}
+
+fn goal_string(index: usize, goal: &Goal) -> String {
+    let player = match goal.player_number {
+        Some(num) if num != 0 => num.to_string(),
+        _ => "?".to_string(),
+    };
+
+    format!(
+        "{}. {} #{player} - {} {}",
+        index + 1,
+        tr(&goal.color.to_string().to_uppercase()),
+        period_string(goal.period),
+        time_string(goal.time),
+    )
+}
@@ -0,0 +1,61 @@
+use super::{style::Element, *};
+use iced::{
+    widget::{column, horizontal_space, row, vertical_space},
+    Length,
+};
+
+use std::time::Duration;
+use uwh_common::game_snapshot::Color as GameColor;
+
+pub(super) fn make_goal_edit_page<'a>(color: GameColor, time: Duration) -> Element<'a, Message> {
+    let (black_style, white_style) = match color {
+        GameColor::Black => (ButtonStyle::BlackSelected, ButtonStyle::White),
+        GameColor::White => (ButtonStyle::Black, ButtonStyle::WhiteSelected),
+    };
+
+    column![
+        vertical_space(Length::Fill),
+        row![
+            make_button("BLACK")
+                .style(black_style)
+                .on_press(Message::ChangeColor(Some(GameColor::Black))),
+            make_button("WHITE")
+                .style(white_style)
+                .on_press(Message::ChangeColor(Some(GameColor::White))),
+        ]
+        .spacing(SPACING),
+        vertical_space(Length::Fill),
+        row![
+            horizontal_space(Length::Fill),
+            make_time_editor("GOAL TIME", time, false),
+            horizontal_space(Length::Fill)
+        ],
+        vertical_space(Length::Fill),
+        row![
+            make_button("CANCEL")
+                .style(ButtonStyle::Red)
+                .width(Length::Fill)
+                .on_press(Message::GoalEditComplete {
+                    canceled: true,
+                    deleted: false,
+                }),
+            make_button("DELETE")
+                .style(ButtonStyle::Orange)
+                .width(Length::Fill)
+                .on_press(Message::GoalEditComplete {
+                    canceled: false,
+                    deleted: true,
+                }),
+            make_button("DONE")
+                .style(ButtonStyle::Green)
+                .width(Length::Fill)
+                .on_press(Message::GoalEditComplete {
+                    canceled: false,
+                    deleted: false,
+                }),
+        ]
+        .spacing(SPACING),
+    ]
+    .spacing(SPACING)
+    .into()
+}
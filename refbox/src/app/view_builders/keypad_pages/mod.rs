@@ -37,6 +37,18 @@ use foul_add::*;
 mod warning_add;
 use warning_add::*;
 
+mod goal_edit;
+use goal_edit::*;
+
+mod pin_entry;
+use pin_entry::*;
+
+mod penalty_shot_outcome;
+use penalty_shot_outcome::*;
+
+mod shootout_shot;
+use shootout_shot::*;
+
 pub(in super::super) fn build_keypad_page<'a>(
     snapshot: &GameSnapshot,
     page: KeypadPage,
@@ -66,16 +78,23 @@ pub(in super::super) fn build_keypad_page<'a>(
             container(
                 column![
                     row![
-                        text(page.text())
+                        text(tr(page.text()))
                             .line_height(LINE_HEIGHT)
                             .horizontal_alignment(Horizontal::Left)
                             .vertical_alignment(Vertical::Center),
-                        text(player_num.to_string())
-                            .size(LARGE_TEXT)
-                            .line_height(LINE_HEIGHT)
-                            .width(Length::Fill)
-                            .horizontal_alignment(Horizontal::Right)
-                            .vertical_alignment(Vertical::Center),
+                        text(match page {
+                            // Don't show the PIN to anyone looking over the user's shoulder
+                            KeypadPage::Pin(_) if player_num != 0 => {
+                                "*".repeat(player_num.to_string().len())
+                            }
+                            KeypadPage::Pin(_) => String::new(),
+                            _ => player_num.to_string(),
+                        })
+                        .size(LARGE_TEXT)
+                        .line_height(LINE_HEIGHT)
+                        .width(Length::Fill)
+                        .horizontal_alignment(Horizontal::Right)
+                        .vertical_alignment(Vertical::Center),
                     ]
                     .align_items(Alignment::Center)
                     .height(Length::Fill)
@@ -166,11 +185,13 @@ pub(in super::super) fn build_keypad_page<'a>(
             .padding(PADDING),
             match page {
                 KeypadPage::AddScore(color) => make_score_add_page(color),
+                KeypadPage::EditGoal { color, time, .. } => make_goal_edit_page(color, time),
                 KeypadPage::Penalty(origin, color, kind, foul, expanded) => {
                     make_penalty_edit_page(origin, color, kind, config, foul, expanded)
                 }
                 KeypadPage::GameNumber => make_game_number_edit_page(),
                 KeypadPage::TeamTimeouts(dur) => make_team_timeout_edit_page(dur),
+                KeypadPage::Pin(_) => make_pin_entry_page(),
                 KeypadPage::FoulAdd {
                     origin,
                     color,
@@ -193,6 +214,12 @@ pub(in super::super) fn build_keypad_page<'a>(
                     team_warning,
                     ret_to_overview
                 ),
+                KeypadPage::PenaltyShotOutcome {
+                    color,
+                    infraction,
+                    expanded,
+                } => make_penalty_shot_outcome_page(color, infraction, expanded),
+                KeypadPage::ShootoutShot(color) => make_shootout_shot_page(color),
             }
         ]
         .spacing(SPACING)
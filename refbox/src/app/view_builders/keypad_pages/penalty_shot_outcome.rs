@@ -0,0 +1,58 @@
+use super::{style::Element, *};
+use iced::{
+    widget::{column, row, vertical_space},
+    Length,
+};
+
+use uwh_common::game_snapshot::Color as GameColor;
+
+pub(super) fn make_penalty_shot_outcome_page<'a>(
+    color: GameColor,
+    infraction: Infraction,
+    expanded: bool,
+) -> Element<'a, Message> {
+    let (black_style, white_style) = match color {
+        GameColor::Black => (ButtonStyle::BlackSelected, ButtonStyle::White),
+        GameColor::White => (ButtonStyle::Black, ButtonStyle::WhiteSelected),
+    };
+
+    column![
+        row![
+            make_button("BLACK")
+                .style(black_style)
+                .on_press(Message::ChangeColor(Some(GameColor::Black))),
+            make_button("WHITE")
+                .style(white_style)
+                .on_press(Message::ChangeColor(Some(GameColor::White))),
+        ]
+        .spacing(SPACING),
+        vertical_space(Length::Fixed(SPACING)),
+        make_penalty_dropdown(infraction, expanded),
+        vertical_space(Length::Fill),
+        row![
+            make_button("SKIP")
+                .style(ButtonStyle::Red)
+                .width(Length::Fill)
+                .on_press(Message::PenaltyShotOutcomeComplete {
+                    canceled: true,
+                    scored: false,
+                }),
+            make_button("NO GOAL")
+                .style(ButtonStyle::Orange)
+                .width(Length::Fill)
+                .on_press(Message::PenaltyShotOutcomeComplete {
+                    canceled: false,
+                    scored: false,
+                }),
+            make_button("GOAL")
+                .style(ButtonStyle::Green)
+                .width(Length::Fill)
+                .on_press(Message::PenaltyShotOutcomeComplete {
+                    canceled: false,
+                    scored: true,
+                }),
+        ]
+        .spacing(SPACING),
+    ]
+    .into()
+}
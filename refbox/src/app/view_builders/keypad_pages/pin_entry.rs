@@ -0,0 +1,30 @@
+use super::{style::Element, *};
+use iced::{
+    alignment::Horizontal,
+    widget::{column, row, text, vertical_space},
+    Length,
+};
+
+pub(super) fn make_pin_entry_page<'a>() -> Element<'a, Message> {
+    column![
+        vertical_space(Length::Fill),
+        text(tr("ENTER THE CHIEF REFEREE PIN"))
+            .line_height(LINE_HEIGHT)
+            .horizontal_alignment(Horizontal::Center)
+            .width(Length::Fill),
+        vertical_space(Length::Fill),
+        row![
+            make_button("CANCEL")
+                .style(ButtonStyle::Red)
+                .width(Length::Fill)
+                .on_press(Message::PinEntryComplete { canceled: true }),
+            make_button("DONE")
+                .style(ButtonStyle::Green)
+                .width(Length::Fill)
+                .on_press(Message::PinEntryComplete { canceled: false }),
+        ]
+        .spacing(SPACING),
+    ]
+    .spacing(SPACING)
+    .into()
+}
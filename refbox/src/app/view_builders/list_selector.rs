@@ -29,7 +29,7 @@ pub(in super::super) fn build_list_selector_page<'a>(
         ListableParameter::Game => "SELECT GAME",
     };
 
-    let title = text(title)
+    let title = text(tr(title))
         .line_height(LINE_HEIGHT)
         .height(Length::Fill)
         .width(Length::Fill)
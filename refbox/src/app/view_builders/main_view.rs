@@ -14,7 +14,10 @@ use iced::{
 };
 use uwh_common::{
     config::Game as GameConfig,
-    game_snapshot::{Color as GameColor, GamePeriod, GameSnapshot, PenaltyTime, TimeoutSnapshot},
+    game_snapshot::{
+        Color as GameColor, GamePeriod, GameSnapshot, PenaltyTime, ShootoutSnapshot,
+        TimeoutSnapshot,
+    },
 };
 
 pub(in super::super) fn build_main_view<'a>(
@@ -29,6 +32,15 @@ pub(in super::super) fn build_main_view<'a>(
 
     let mut center_col = column![time_button].spacing(SPACING).width(Length::Fill);
 
+    if snapshot.stop_clock_time {
+        center_col = center_col.push(
+            make_button("STOP CLOCK TIME")
+                .style(ButtonStyle::Red)
+                .width(Length::Fill)
+                .on_press(Message::NoAction),
+        );
+    }
+
     let make_warn_button = || {
         make_button("ADD WARNING")
             .style(ButtonStyle::Blue)
@@ -95,16 +107,60 @@ pub(in super::super) fn build_main_view<'a>(
                 | GamePeriod::SecondHalf
                 | GamePeriod::OvertimeFirstHalf
                 | GamePeriod::OvertimeSecondHalf
-                | GamePeriod::SuddenDeath => {
+                | GamePeriod::SuddenDeath
+                | GamePeriod::Shootout => {
                     if config.track_fouls_and_warnings {
                         center_col = center_col
                             .push(row![make_foul_button(), make_warn_button()].spacing(SPACING))
                     }
+                    if game_config.practice_mode {
+                        let end_button = make_button("END PRACTICE")
+                            .style(ButtonStyle::Red)
+                            .width(Length::Fill)
+                            .on_press(Message::EndPractice);
+                        match snapshot.current_period {
+                            GamePeriod::FirstHalf => {
+                                center_col = center_col.push(
+                                    row![
+                                        make_button("HALF TIME")
+                                            .style(ButtonStyle::Orange)
+                                            .width(Length::Fill)
+                                            .on_press(Message::EndPracticeHalf),
+                                        end_button
+                                    ]
+                                    .spacing(SPACING),
+                                )
+                            }
+                            GamePeriod::SecondHalf => center_col = center_col.push(end_button),
+                            _ => {}
+                        }
+                    }
                 }
             };
         }
     };
 
+    if let Some(shootout) = snapshot
+        .shootout
+        .as_ref()
+        .filter(|_| snapshot.current_period == GamePeriod::Shootout)
+    {
+        center_col = center_col.push(
+            button(
+                text(shootout_string(shootout))
+                    .size(SMALL_TEXT)
+                    .line_height(LINE_HEIGHT)
+                    .vertical_alignment(Vertical::Center)
+                    .horizontal_alignment(Horizontal::Left),
+            )
+            .padding(PADDING)
+            .style(ButtonStyle::LightGray)
+            .width(Length::Fill)
+            .height(Length::FillPortion(2))
+            .on_press(Message::NoAction),
+        );
+    }
+
     center_col = center_col.push(
         button(
             text(config_string(
@@ -130,7 +186,7 @@ pub(in super::super) fn build_main_view<'a>(
         center_col = center_col.push(
             button(
                 column![
-                    text("WARNINGS")
+                    text(tr("WARNINGS"))
                         .line_height(LINE_HEIGHT)
                         .vertical_alignment(Vertical::Top)
                         .horizontal_alignment(Horizontal::Center)
@@ -219,7 +275,7 @@ pub(in super::super) fn build_main_view<'a>(
 
         button(
             column![
-                text("PENALTIES")
+                text(tr("PENALTIES"))
                     .line_height(LINE_HEIGHT)
                     .vertical_alignment(Vertical::Center)
                     .horizontal_alignment(Horizontal::Center)
@@ -244,7 +300,7 @@ pub(in super::super) fn build_main_view<'a>(
 
     let mut black_score_btn = button(
         column![
-            text("BLACK").line_height(LINE_HEIGHT),
+            text(tr("BLACK")).line_height(LINE_HEIGHT),
             text(snapshot.b_score.to_string())
                 .size(LARGE_TEXT)
                 .line_height(LINE_HEIGHT),
@@ -262,7 +318,7 @@ pub(in super::super) fn build_main_view<'a>(
 
     let mut white_score_btn = button(
         column![
-            text("WHITE").line_height(LINE_HEIGHT),
+            text(tr("WHITE")).line_height(LINE_HEIGHT),
             text(snapshot.w_score.to_string())
                 .size(LARGE_TEXT)
                 .line_height(LINE_HEIGHT),
@@ -285,6 +341,19 @@ pub(in super::super) fn build_main_view<'a>(
         white_new_score_btn = white_new_score_btn.on_press(Message::AddNewScore(GameColor::White));
     }
 
+    if snapshot.current_period == GamePeriod::Shootout {
+        black_new_score_btn = make_multi_label_button(("SHOOTOUT", "SHOT"))
+            .style(ButtonStyle::Black)
+            .on_press(Message::KeypadPage(KeypadPage::ShootoutShot(
+                GameColor::Black,
+            )));
+        white_new_score_btn = make_multi_label_button(("SHOOTOUT", "SHOT"))
+            .style(ButtonStyle::White)
+            .on_press(Message::KeypadPage(KeypadPage::ShootoutShot(
+                GameColor::White,
+            )));
+    }
+
     let black_col = column![
         black_score_btn,
         black_new_score_btn,
@@ -328,3 +397,31 @@ pub(in super::super) fn build_main_view<'a>(
     .height(Length::Fill)
     .into()
 }
+
+fn shootout_string(shootout: &ShootoutSnapshot) -> String {
+    let mut string = tr("SHOOTOUT").to_string();
+
+    for (color, name) in [(GameColor::Black, "BLACK"), (GameColor::White, "WHITE")] {
+        let tally = shootout.tally(color);
+        let shots = shootout
+            .shots(color)
+            .iter()
+            .map(|shot| {
+                format!(
+                    "#{} {}",
+                    shot.player_number,
+                    tr(if shot.scored { "GOAL" } else { "NO GOAL" })
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        string.push_str(&format!(
+            "\n{}: {}/{}\n{shots}",
+            tr(name),
+            tally.goals,
+            tally.shots
+        ));
+    }
+
+    string
+}
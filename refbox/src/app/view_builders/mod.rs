@@ -9,9 +9,18 @@ pub(super) use configuration::*;
 pub mod confirmation;
 pub(super) use confirmation::*;
 
+pub mod goals;
+pub(super) use goals::*;
+
 pub mod game_info;
 pub(super) use game_info::*;
 
+pub mod training;
+pub(super) use training::*;
+
+pub mod session_suspended;
+pub(super) use session_suspended::*;
+
 pub mod warnings_fouls_summary;
 pub(super) use warnings_fouls_summary::*;
 
@@ -81,12 +81,15 @@ fn make_penalty_list<'a>(
 ) -> Container<'a, Message> {
     const PENALTY_LIST_LEN: usize = 3;
 
-    let title = text(format!("{} PENALTIES", color.to_string().to_uppercase()))
-        .line_height(LINE_HEIGHT)
-        .height(Length::Fill)
-        .width(Length::Fill)
-        .horizontal_alignment(Horizontal::Center)
-        .vertical_alignment(Vertical::Center);
+    let title = text(tr(&format!(
+        "{} PENALTIES",
+        color.to_string().to_uppercase()
+    )))
+    .line_height(LINE_HEIGHT)
+    .height(Length::Fill)
+    .width(Length::Fill)
+    .horizontal_alignment(Horizontal::Center)
+    .vertical_alignment(Vertical::Center);
 
     let num_pens = penalties.len();
 
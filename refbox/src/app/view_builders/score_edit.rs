@@ -24,6 +24,15 @@ pub(in super::super) fn build_score_edit_view<'a>(
         Some(Message::ScoreEditComplete { canceled: true })
     };
 
+    // The goal timeline shows the recorded goals, so it can't be opened with unsaved changes
+    let goals_btn_msg =
+        if !is_confirmation && scores.black == snapshot.b_score && scores.white == snapshot.w_score
+        {
+            Some(Message::GoalTimeline)
+        } else {
+            None
+        };
+
     let black_edit = container(
         row![
             column![
@@ -42,7 +51,7 @@ pub(in super::super) fn build_score_edit_view<'a>(
             ]
             .spacing(SPACING),
             column![
-                "BLACK",
+                tr("BLACK"),
                 text(scores.black.to_string())
                     .size(LARGE_TEXT)
                     .line_height(LINE_HEIGHT)
@@ -61,7 +70,7 @@ pub(in super::super) fn build_score_edit_view<'a>(
     let white_edit = container(
         row![
             column![
-                "WHITE",
+                tr("WHITE"),
                 text(scores.white.to_string())
                     .size(LARGE_TEXT)
                     .line_height(LINE_HEIGHT)
@@ -102,7 +111,7 @@ pub(in super::super) fn build_score_edit_view<'a>(
     if is_confirmation {
         main_col = main_col
             .push(
-                text("Please enter the final score")
+                text(tr("Please enter the final score"))
                     .line_height(LINE_HEIGHT)
                     .horizontal_alignment(Horizontal::Center)
                     .width(Length::Fill),
@@ -126,6 +135,8 @@ pub(in super::super) fn build_score_edit_view<'a>(
             row![
                 make_message_button("CANCEL", cancel_btn_msg).style(ButtonStyle::Red),
                 horizontal_space(Length::Fill),
+                make_message_button("GOALS", goals_btn_msg).style(ButtonStyle::Blue),
+                horizontal_space(Length::Fill),
                 make_button("DONE")
                     .style(ButtonStyle::Green)
                     .on_press(Message::ScoreEditComplete { canceled: false }),
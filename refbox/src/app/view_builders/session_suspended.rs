@@ -0,0 +1,68 @@
+use super::{
+    style::{
+        ButtonStyle, ContainerStyle, Element, TextStyle, LARGE_TEXT, LINE_HEIGHT, MEDIUM_TEXT,
+        PADDING, SPACING,
+    },
+    *,
+};
+use iced::{
+    widget::{column, container, row, text},
+    Alignment, Length,
+};
+use uwh_common::game_snapshot::GameSnapshot;
+
+pub(in super::super) fn build_session_suspended_page<'a>(
+    snapshot: &GameSnapshot,
+    suspended_for: Duration,
+    schedule_delay: Duration,
+) -> Element<'a, Message> {
+    let mut details = column![
+        text(tr("SESSION SUSPENDED"))
+            .size(MEDIUM_TEXT)
+            .line_height(LINE_HEIGHT)
+            .style(TextStyle::Yellow),
+        text(time_string(suspended_for))
+            .size(LARGE_TEXT)
+            .line_height(LINE_HEIGHT),
+        text(format!(
+            "{} {}",
+            tr("NEXT GAME IN"),
+            time_string(Duration::from_secs(snapshot.secs_in_period.into()))
+        ))
+        .size(MEDIUM_TEXT)
+        .line_height(LINE_HEIGHT),
+    ]
+    .spacing(SPACING)
+    .width(Length::Fill)
+    .align_items(Alignment::Center);
+
+    if !schedule_delay.is_zero() {
+        details = details.push(
+            text(format!(
+                "{} {}",
+                tr("SCHEDULE ALREADY DELAYED BY"),
+                time_string(schedule_delay)
+            ))
+            .size(MEDIUM_TEXT)
+            .line_height(LINE_HEIGHT),
+        );
+    }
+
+    column![
+        container(details)
+            .padding(PADDING)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_y()
+            .style(ContainerStyle::LightGray),
+        row![make_button("RESUME SESSION")
+            .style(ButtonStyle::Green)
+            .width(Length::Fill)
+            .on_press(Message::ResumeSession)]
+        .spacing(SPACING)
+        .width(Length::Fill),
+    ]
+    .spacing(SPACING)
+    .height(Length::Fill)
+    .into()
+}
@@ -6,8 +6,12 @@ use super::{
     },
     Element,
 };
-use crate::{config::Mode, tournament_manager::TournamentManager};
-use enum_iterator::all;
+use crate::{
+    config::Mode,
+    i18n::tr,
+    infractions::{self, Catalog},
+    tournament_manager::TournamentManager,
+};
 use iced::{
     alignment::{Horizontal, Vertical},
     widget::{
@@ -17,7 +21,7 @@ use iced::{
     },
     Alignment, Length,
 };
-use matrix_drawing::{secs_to_long_time_string, secs_to_time_string};
+use matrix_drawing::{secs_to_long_time_string, secs_to_tenths_time_string, secs_to_time_string};
 use std::{
     borrow::Cow,
     collections::BTreeMap,
@@ -26,7 +30,7 @@ use std::{
     time::Duration,
 };
 use uwh_common::{
-    config::Game as GameConfig,
+    config::{Game as GameConfig, SuddenDeathResolution},
     drawing_support::*,
     game_snapshot::{
         Color as GameColor, GamePeriod, GameSnapshot, Infraction, InfractionSnapshot,
@@ -293,6 +297,35 @@ pub(in super::super) fn build_timeout_ribbon<'a>(
     row![black, referee, penalty, white].spacing(SPACING)
 }
 
+pub(in super::super) fn build_penalty_expiry_banner<'a>(
+    snapshot: &GameSnapshot,
+) -> Container<'a, Message> {
+    let players: Vec<String> = snapshot
+        .recent_penalty_expiries
+        .iter()
+        .map(|(color, num)| {
+            format!(
+                "{} #{num} {}",
+                tr(&color.to_string().to_uppercase()),
+                tr("MAY RETURN")
+            )
+        })
+        .collect();
+
+    container(
+        text(players.join("    "))
+            .size(MEDIUM_TEXT)
+            .line_height(LINE_HEIGHT)
+            .horizontal_alignment(Horizontal::Center)
+            .vertical_alignment(Vertical::Center),
+    )
+    .center_x()
+    .center_y()
+    .width(Length::Fill)
+    .height(Length::Fixed(MIN_BUTTON_SIZE))
+    .style(ContainerStyle::Yellow)
+}
+
 pub(super) fn make_game_time_button<'a>(
     snapshot: &GameSnapshot,
     tall: bool,
@@ -319,7 +352,8 @@ pub(super) fn make_game_time_button<'a>(
                     | GamePeriod::SecondHalf
                     | GamePeriod::OvertimeFirstHalf
                     | GamePeriod::OvertimeSecondHalf
-                    | GamePeriod::SuddenDeath => false,
+                    | GamePeriod::SuddenDeath
+                    | GamePeriod::Shootout => false,
                 };
 
                 snapshot.current_period != GamePeriod::SuddenDeath
@@ -343,6 +377,7 @@ pub(super) fn make_game_time_button<'a>(
             GamePeriod::OvertimeSecondHalf => ("OVERTIME SECOND HALF", TextStyle::Green),
             GamePeriod::PreSuddenDeath => ("PRE SUDDEN DEATH BREAK", TextStyle::Yellow),
             GamePeriod::SuddenDeath => ("SUDDEN DEATH", TextStyle::Green),
+            GamePeriod::Shootout => ("SHOOTOUT", TextStyle::Green),
         };
 
         if make_red {
@@ -374,7 +409,7 @@ pub(super) fn make_game_time_button<'a>(
     }
 
     let make_time_view_row = |period_text, time_text, style| {
-        let per = text(period_text)
+        let per = text(tr(period_text))
             .line_height(LINE_HEIGHT)
             .style(style)
             .width(Length::Fill)
@@ -392,7 +427,7 @@ pub(super) fn make_game_time_button<'a>(
     };
 
     let make_time_view_col = |period_text, time_text, style| {
-        let per = text(period_text).line_height(LINE_HEIGHT).style(style);
+        let per = text(tr(period_text)).line_height(LINE_HEIGHT).style(style);
         let time = text(time_text)
             .line_height(LINE_HEIGHT)
             .style(style)
@@ -425,8 +460,15 @@ pub(super) fn make_game_time_button<'a>(
         TimeoutSnapshot::None => None,
     };
 
-    let time_text = secs_to_long_time_string(snapshot.secs_in_period);
-    let time_text = time_text.trim();
+    let time_text = match snapshot.tenths_in_period {
+        Some(tenths) => secs_to_tenths_time_string(snapshot.secs_in_period, tenths)
+            .trim()
+            .to_string(),
+        None => secs_to_long_time_string(snapshot.secs_in_period)
+            .trim()
+            .to_string(),
+    };
+    let time_text = time_text.as_str();
 
     if tall {
         content = content.push(make_time_view_col(period_text, time_text, period_color));
@@ -591,11 +633,37 @@ pub(super) fn timeout_time_string(snapshot: &GameSnapshot) -> String {
 
 pub(super) fn bool_string(val: bool) -> String {
     match val {
-        true => "YES".to_string(),
-        false => "NO".to_string(),
+        true => tr("YES").to_string(),
+        false => tr("NO").to_string(),
     }
 }
 
+pub(super) fn period_string(period: GamePeriod) -> String {
+    tr(match period {
+        GamePeriod::BetweenGames => "NEXT GAME",
+        GamePeriod::FirstHalf => "FIRST HALF",
+        GamePeriod::HalfTime => "HALF TIME",
+        GamePeriod::SecondHalf => "SECOND HALF",
+        GamePeriod::PreOvertime => "PRE OVERTIME BREAK",
+        GamePeriod::OvertimeFirstHalf => "OVERTIME FIRST HALF",
+        GamePeriod::OvertimeHalfTime => "OVERTIME HALF TIME",
+        GamePeriod::OvertimeSecondHalf => "OVERTIME SECOND HALF",
+        GamePeriod::PreSuddenDeath => "PRE SUDDEN DEATH BREAK",
+        GamePeriod::SuddenDeath => "SUDDEN DEATH",
+        GamePeriod::Shootout => "SHOOTOUT",
+    })
+    .to_string()
+}
+
+pub(super) fn sudden_death_resolution_string(resolution: SuddenDeathResolution) -> String {
+    tr(match resolution {
+        SuddenDeathResolution::Draw => "DRAW",
+        SuddenDeathResolution::Shootout => "SHOOTOUT",
+        SuddenDeathResolution::RefereeDecision => "REF DECISION",
+    })
+    .to_string()
+}
+
 pub(super) fn penalty_string(penalties: &[PenaltySnapshot]) -> String {
     let mut string = String::new();
 
@@ -606,10 +674,14 @@ pub(super) fn penalty_string(penalties: &[PenaltySnapshot]) -> String {
                 if secs != 0 {
                     writeln!(&mut string, "{}:{:02}", secs / 60, secs % 60).unwrap();
                 } else {
-                    string.push_str("Served\n");
+                    string.push_str(tr("Served"));
+                    string.push('\n');
                 }
             }
-            PenaltyTime::TotalDismissal => string.push_str("DSMS\n"),
+            PenaltyTime::TotalDismissal => {
+                string.push_str(tr("DSMS"));
+                string.push('\n');
+            }
         }
     }
     // if the string is not empty, the last char is a '\n' that we don't want
@@ -654,24 +726,24 @@ pub(super) fn config_string(
             if let Some(games) = games {
                 prev_game = match games.get(&snapshot.game_number) {
                     Some(game) => game_string_short(game),
-                    None if snapshot.game_number == 0 => "None".to_string(),
-                    None => format!("Error ({})", snapshot.game_number),
+                    None if snapshot.game_number == 0 => tr("None").to_string(),
+                    None => format!("{} ({})", tr("Error"), snapshot.game_number),
                 };
                 next_game = match games.get(&snapshot.next_game_number) {
                     Some(game) => game_string_short(game),
-                    None => format!("Error ({})", snapshot.next_game_number),
+                    None => format!("{} ({})", tr("Error"), snapshot.next_game_number),
                 };
             } else {
                 prev_game = if snapshot.game_number == 0 {
-                    "None".to_string()
+                    tr("None").to_string()
                 } else {
-                    format!("Error ({})", snapshot.game_number)
+                    format!("{} ({})", tr("Error"), snapshot.game_number)
                 };
-                next_game = format!("Error ({})", snapshot.next_game_number);
+                next_game = format!("{} ({})", tr("Error"), snapshot.next_game_number);
             }
         } else {
             prev_game = if snapshot.game_number == 0 {
-                "None".to_string()
+                tr("None").to_string()
             } else {
                 snapshot.game_number.to_string()
             };
@@ -680,8 +752,11 @@ pub(super) fn config_string(
 
         write!(
             &mut result,
-            "Last Game: {},  Next Game: {}\n\n",
-            prev_game, next_game
+            "{}: {},  {}: {}\n\n",
+            tr("Last Game"),
+            prev_game,
+            tr("Next Game"),
+            next_game
         )
         .unwrap();
         snapshot.next_game_number
@@ -691,15 +766,15 @@ pub(super) fn config_string(
             if let Some(games) = games {
                 game = match games.get(&snapshot.game_number) {
                     Some(game) => game_string_short(game),
-                    None => format!("Error ({})", snapshot.game_number),
+                    None => format!("{} ({})", tr("Error"), snapshot.game_number),
                 };
             } else {
-                game = format!("Error ({})", snapshot.game_number);
+                game = format!("{} ({})", tr("Error"), snapshot.game_number);
             }
         } else {
             game = snapshot.game_number.to_string();
         }
-        write!(&mut result, "Game: {}\n\n", game).unwrap();
+        write!(&mut result, "{}: {}\n\n", tr("Game"), game).unwrap();
         snapshot.game_number
     };
 
@@ -708,8 +783,10 @@ pub(super) fn config_string(
             if let Some(game) = games.get(&game_number) {
                 write!(
                     &mut result,
-                    "Black Team: {}\nWhite Team: {}\n",
+                    "{}: {}\n{}: {}\n",
+                    tr("Black Team"),
                     limit_team_name_len(&game.black, TEAM_NAME_LEN_LIMIT),
+                    tr("White Team"),
                     limit_team_name_len(&game.white, TEAM_NAME_LEN_LIMIT)
                 )
                 .unwrap()
@@ -719,39 +796,43 @@ pub(super) fn config_string(
 
     writeln!(
         &mut result,
-        "Half Length: {},  \
-         Half Time Length: {}",
+        "{}: {},  {}: {}",
+        tr("Half Length"),
         time_string(config.half_play_duration),
+        tr("Half Time Length"),
         time_string(config.half_time_duration),
     )
     .unwrap();
 
     writeln!(
         &mut result,
-        "Sudden Death Allowed: {},  \
-         Overtime Allowed: {}",
+        "{}: {},  {}: {}",
+        tr("Sudden Death Allowed"),
         bool_string(config.sudden_death_allowed),
+        tr("Overtime Allowed"),
         bool_string(config.overtime_allowed),
     )
     .unwrap();
 
     writeln!(
         &mut result,
-        "Team Timeouts Allowed Per Half: {}",
+        "{}: {}",
+        tr("Team Timeouts Allowed Per Half"),
         config.team_timeouts_per_half
     )
     .unwrap();
 
-    writeln!(&mut result, "Stop clock in last 2 minutes: ").unwrap();
+    writeln!(&mut result, "{}: ", tr("Stop clock in last 2 minutes")).unwrap();
 
     if !fouls_and_warnings {
         write!(
             &mut result,
-            "Cheif ref: \n\
-            Timer: \n\
-            Water ref 1: \n\
-            Water ref 2: \n\
-            Water ref 3: ",
+            "{}: \n{}: \n{} 1: \n{} 2: \n{} 3: ",
+            tr("Cheif ref"),
+            tr("Timer"),
+            tr("Water ref"),
+            tr("Water ref"),
+            tr("Water ref"),
         )
         .unwrap();
     }
@@ -780,7 +861,7 @@ pub(super) fn make_multi_label_button<'a, Message: 'a + Clone, T: ToString>(
 }
 
 pub fn centered_text<'a, T: ToString>(label: T) -> Text<'a> {
-    text(label)
+    text(tr(&label.to_string()))
         .line_height(LINE_HEIGHT)
         .vertical_alignment(Vertical::Center)
         .horizontal_alignment(Horizontal::Center)
@@ -827,7 +908,7 @@ pub(super) fn make_value_button<'a, Message: 'a + Clone, T: ToString, U: ToStrin
 ) -> Button<'a, Message> {
     let mut button = button(
         row![
-            text(first_label)
+            text(tr(&first_label.to_string()))
                 .size(if large_text.0 {
                     MEDIUM_TEXT
                 } else {
@@ -836,7 +917,7 @@ pub(super) fn make_value_button<'a, Message: 'a + Clone, T: ToString, U: ToStrin
                 .line_height(LINE_HEIGHT)
                 .vertical_alignment(Vertical::Center),
             horizontal_space(Length::Fill),
-            text(second_label)
+            text(tr(&second_label.to_string()))
                 .size(if large_text.1 {
                     MEDIUM_TEXT
                 } else {
@@ -865,26 +946,26 @@ pub(super) fn make_penalty_dropdown<'a>(
 ) -> Element<'a, Message> {
     const ROW_LEN: usize = 6;
 
+    let catalog = infractions::catalog();
+
     let svg_file = if expanded {
         &include_bytes!("../../../resources/expand_more.svg")[..]
     } else {
         &include_bytes!("../../../resources/expand_less.svg")[..]
     };
     let closed_button_content = row![
-        text("INFRACTION")
+        text(tr("INFRACTION"))
             .size(MEDIUM_TEXT)
             .vertical_alignment(Vertical::Center)
             .horizontal_alignment(Horizontal::Left)
             .height(Length::Fill)
             .line_height(LINE_HEIGHT),
         horizontal_space(Length::Fill),
-        container(
-            Svg::new(svg::Handle::from_memory(infraction.svg_fouls())).style(SvgStyle::Black)
-        )
-        .padding(PADDING)
-        .style(ContainerStyle::LightGray)
-        .height(Length::Fixed(MIN_BUTTON_SIZE))
-        .width(80),
+        container(make_infraction_icon(&catalog, infraction))
+            .padding(PADDING)
+            .style(ContainerStyle::LightGray)
+            .height(Length::Fixed(MIN_BUTTON_SIZE))
+            .width(80),
         horizontal_space(Length::Fixed(SPACING)),
         container(
             Svg::new(svg::Handle::from_memory(svg_file,))
@@ -902,13 +983,10 @@ pub(super) fn make_penalty_dropdown<'a>(
         .style(ButtonStyle::Blue);
 
     if expanded {
-        let foul_buttons = all::<Infraction>().map(|button_infraction| {
+        let foul_buttons = catalog.available().into_iter().map(|button_infraction| {
             button(
-                container(
-                    Svg::new(svg::Handle::from_memory(button_infraction.svg_fouls()))
-                        .style(SvgStyle::Black),
-                )
-                .style(ContainerStyle::LightGray),
+                container(make_infraction_icon(&catalog, button_infraction))
+                    .style(ContainerStyle::LightGray),
             )
             .padding(0)
             .height(Length::Fixed(MIN_BUTTON_SIZE))
@@ -921,27 +999,23 @@ pub(super) fn make_penalty_dropdown<'a>(
             .on_press(Message::ChangeInfraction(button_infraction))
         });
 
-        let mut first_row = row![].spacing(SPACING);
-        for button in foul_buttons.clone().take(ROW_LEN) {
-            first_row = first_row.push(button);
-        }
-        let mut second_row = row![].spacing(SPACING);
-        for button in foul_buttons.skip(ROW_LEN).take(ROW_LEN) {
-            second_row = second_row.push(button);
-        }
-
-        let open_button_content = column![
-            foul_dropdown
-                .padding(0)
-                .height(Length::Fixed(MIN_BUTTON_SIZE - (2.0 * PADDING)))
-                .on_press(Message::FoulSelectExpanded(false)),
-            vertical_space(Length::Fixed(SPACING)),
-            first_row,
-            vertical_space(Length::Fixed(SPACING)),
-            second_row,
-        ]
+        let mut open_button_content = column![foul_dropdown
+            .padding(0)
+            .height(Length::Fixed(MIN_BUTTON_SIZE - (2.0 * PADDING)))
+            .on_press(Message::FoulSelectExpanded(false))]
         .padding(0);
 
+        let mut foul_buttons = foul_buttons.peekable();
+        while foul_buttons.peek().is_some() {
+            let mut foul_row = row![].spacing(SPACING);
+            for button in foul_buttons.by_ref().take(ROW_LEN) {
+                foul_row = foul_row.push(button);
+            }
+            open_button_content = open_button_content
+                .push(vertical_space(Length::Fixed(SPACING)))
+                .push(foul_row);
+        }
+
         container(open_button_content)
             .padding(PADDING)
             .width(Length::Fill)
@@ -956,6 +1030,22 @@ pub(super) fn make_penalty_dropdown<'a>(
     }
 }
 
+/// Shows the icon for `infraction`, or its short name if it doesn't have one
+fn make_infraction_icon<'a>(catalog: &Catalog, infraction: Infraction) -> Element<'a, Message> {
+    match catalog.icon(infraction) {
+        Some(icon) => Svg::new(icon).style(SvgStyle::Black).into(),
+        None => text(tr(&catalog.short_name(infraction)).to_string())
+            .size(SMALL_TEXT)
+            .line_height(LINE_HEIGHT)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .horizontal_alignment(Horizontal::Center)
+            .vertical_alignment(Vertical::Center)
+            .style(TextStyle::Black)
+            .into(),
+    }
+}
+
 pub fn make_warning_container<'a>(
     warning: &InfractionSnapshot,
     color: Option<GameColor>,
@@ -969,10 +1059,12 @@ pub fn make_warning_container<'a>(
         "T".to_string()
     };
 
+    let short_name = infractions::catalog().short_name(warning.infraction);
+
     container(if color.is_some() {
         row![
             horizontal_space(PADDING),
-            text(warning.infraction.short_name()).size(SMALL_TEXT),
+            text(tr(&short_name)).size(SMALL_TEXT),
             horizontal_space(Length::Fill),
             text(who).size(SMALL_TEXT),
             horizontal_space(PADDING),
@@ -980,7 +1072,7 @@ pub fn make_warning_container<'a>(
     } else {
         row![
             horizontal_space(Length::Fill),
-            text(warning.infraction.short_name()).size(SMALL_TEXT),
+            text(tr(&short_name)).size(SMALL_TEXT),
             horizontal_space(Length::Fill),
         ]
     })
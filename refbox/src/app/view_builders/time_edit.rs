@@ -1,5 +1,5 @@
 use super::{
-    style::{ButtonStyle, LINE_HEIGHT, SMALL_TEXT, SPACING},
+    style::{ButtonStyle, LINE_HEIGHT, MEDIUM_TEXT, SMALL_TEXT, SPACING},
     *,
 };
 use iced::{
@@ -8,12 +8,14 @@ use iced::{
     Alignment, Length,
 };
 use std::time::Duration;
-use uwh_common::game_snapshot::{GameSnapshot, TimeoutSnapshot};
+use uwh_common::game_snapshot::{GamePeriod, GameSnapshot, TimeoutSnapshot};
 
 pub(in super::super) fn build_time_edit_view<'a>(
     snapshot: &GameSnapshot,
     time: Duration,
     timeout_time: Option<Duration>,
+    period: GamePeriod,
+    period_choices: &[GamePeriod],
     mode: Mode,
     clock_running: bool,
 ) -> Element<'a, Message> {
@@ -32,31 +34,65 @@ pub(in super::super) fn build_time_edit_view<'a>(
             .push(horizontal_space(Length::Fill));
     }
 
-    column![
+    let mut main_col = column![
         make_game_time_button(snapshot, false, true, mode, clock_running),
         vertical_space(Length::Fill),
-        text("Note: Game time is paused while on this screen")
+        text(tr("Note: Game time is paused while on this screen"))
             .size(SMALL_TEXT)
             .line_height(LINE_HEIGHT)
             .width(Length::Fill)
             .horizontal_alignment(Horizontal::Center),
         vertical_space(Length::Fill),
-        edit_row,
-        vertical_space(Length::Fill),
-        row![
-            make_button("CANCEL")
-                .style(ButtonStyle::Red)
-                .width(Length::Fill)
-                .on_press(Message::TimeEditComplete { canceled: true }),
-            horizontal_space(Length::Fill),
-            make_button("DONE")
-                .style(ButtonStyle::Green)
-                .width(Length::Fill)
-                .on_press(Message::TimeEditComplete { canceled: false }),
-        ]
-        .spacing(SPACING),
     ]
     .spacing(SPACING)
-    .height(Length::Fill)
-    .into()
+    .height(Length::Fill);
+
+    // Moving to another period is only offered when there is somewhere to go
+    if period_choices.len() > 1 {
+        let index = period_choices.iter().position(|p| *p == period);
+        let mut back = make_small_button("<", MEDIUM_TEXT).style(ButtonStyle::Blue);
+        if index.is_some_and(|i| i > 0) {
+            back = back.on_press(Message::ChangePeriod { forward: false });
+        }
+        let mut forward = make_small_button(">", MEDIUM_TEXT).style(ButtonStyle::Blue);
+        if index.is_some_and(|i| i + 1 < period_choices.len()) {
+            forward = forward.on_press(Message::ChangePeriod { forward: true });
+        }
+        main_col = main_col
+            .push(
+                row![
+                    horizontal_space(Length::Fill),
+                    back,
+                    text(period_string(period))
+                        .size(MEDIUM_TEXT)
+                        .line_height(LINE_HEIGHT)
+                        .width(Length::FillPortion(3))
+                        .horizontal_alignment(Horizontal::Center),
+                    forward,
+                    horizontal_space(Length::Fill),
+                ]
+                .spacing(SPACING)
+                .align_items(Alignment::Center),
+            )
+            .push(vertical_space(Length::Fill));
+    }
+
+    main_col
+        .push(edit_row)
+        .push(vertical_space(Length::Fill))
+        .push(
+            row![
+                make_button("CANCEL")
+                    .style(ButtonStyle::Red)
+                    .width(Length::Fill)
+                    .on_press(Message::TimeEditComplete { canceled: true }),
+                horizontal_space(Length::Fill),
+                make_button("DONE")
+                    .style(ButtonStyle::Green)
+                    .width(Length::Fill)
+                    .on_press(Message::TimeEditComplete { canceled: false }),
+            ]
+            .spacing(SPACING),
+        )
+        .into()
 }
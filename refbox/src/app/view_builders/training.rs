@@ -0,0 +1,105 @@
+use super::{
+    style::{
+        ButtonStyle, ContainerStyle, Element, TextStyle, LARGE_TEXT, LINE_HEIGHT, MEDIUM_TEXT,
+        MIN_BUTTON_SIZE, PADDING, SPACING,
+    },
+    *,
+};
+use crate::training::{IntervalPhase, IntervalStatus, IntervalTimer};
+use iced::{
+    widget::{column, container, row, text},
+    Alignment, Length,
+};
+use tokio::time::Instant;
+
+pub(in super::super) fn build_training_page<'a>(
+    timer: &IntervalTimer,
+    program_count: usize,
+    now: Instant,
+) -> Element<'a, Message> {
+    let program = timer.program();
+    let running = timer.is_running();
+    let status = timer.status(now);
+
+    let (phase_text, phase_style, remaining) = match status {
+        IntervalStatus::NotStarted => ("READY", TextStyle::Yellow, program.duration()),
+        IntervalStatus::Interval {
+            phase: IntervalPhase::Work,
+            remaining,
+            ..
+        } => ("WORK", TextStyle::Green, remaining),
+        IntervalStatus::Interval {
+            phase: IntervalPhase::Rest,
+            remaining,
+            ..
+        } => ("REST", TextStyle::Yellow, remaining),
+        IntervalStatus::Finished => ("FINISHED", TextStyle::Yellow, Duration::ZERO),
+    };
+
+    let mut details = column![
+        text(tr(phase_text))
+            .size(MEDIUM_TEXT)
+            .line_height(LINE_HEIGHT)
+            .style(phase_style),
+        text(time_string(remaining))
+            .size(LARGE_TEXT)
+            .line_height(LINE_HEIGHT),
+    ]
+    .spacing(SPACING)
+    .width(Length::Fill)
+    .align_items(Alignment::Center);
+
+    if let IntervalStatus::Interval { block, repeat, .. } = status {
+        let block = &program.blocks[block];
+        details = details
+            .push(text(&block.name).size(MEDIUM_TEXT).line_height(LINE_HEIGHT))
+            .push(
+                text(format!("{} {repeat}/{}", tr("REP"), block.repeats))
+                    .size(MEDIUM_TEXT)
+                    .line_height(LINE_HEIGHT),
+            );
+    }
+
+    let (start_text, start_style) = if running {
+        ("PAUSE", ButtonStyle::Yellow)
+    } else {
+        ("START", ButtonStyle::Green)
+    };
+
+    column![
+        row![make_value_button(
+            "PROGRAM:",
+            &program.name,
+            (false, true),
+            (!running && program_count > 1).then_some(Message::NextTrainingProgram),
+        )]
+        .height(Length::Fixed(MIN_BUTTON_SIZE)),
+        container(details)
+            .padding(PADDING)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_y()
+            .style(ContainerStyle::LightGray),
+        row![
+            make_button("DONE")
+                .style(ButtonStyle::Red)
+                .width(Length::Fill)
+                .on_press(Message::TrainingComplete),
+            make_message_button(
+                "RESET",
+                (status != IntervalStatus::NotStarted).then_some(Message::ResetTraining),
+            )
+            .style(ButtonStyle::Orange)
+            .width(Length::Fill),
+            make_button(start_text)
+                .style(start_style)
+                .width(Length::Fill)
+                .on_press(Message::StartStopTraining),
+        ]
+        .spacing(SPACING)
+        .width(Length::Fill),
+    ]
+    .spacing(SPACING)
+    .height(Length::Fill)
+    .into()
+}
@@ -73,12 +73,15 @@ fn make_warning_list<'a>(
 ) -> Container<'a, Message> {
     const WARNING_LIST_LEN: usize = 3;
 
-    let title = text(format!("{} WARNINGS", color.to_string().to_uppercase()))
-        .line_height(LINE_HEIGHT)
-        .height(Length::Fill)
-        .width(Length::Fill)
-        .horizontal_alignment(Horizontal::Center)
-        .vertical_alignment(Vertical::Center);
+    let title = text(tr(&format!(
+        "{} WARNINGS",
+        color.to_string().to_uppercase()
+    )))
+    .line_height(LINE_HEIGHT)
+    .height(Length::Fill)
+    .width(Length::Fill)
+    .horizontal_alignment(Horizontal::Center)
+    .vertical_alignment(Vertical::Center);
 
     let num_pens = warnings.len();
 
@@ -17,7 +17,7 @@ pub(in super::super) fn build_warnings_summary_page<'a>(
     clock_running: bool,
 ) -> Element<'a, Message> {
     let warnings_container = container(column![
-        text("WARNINGS")
+        text(tr("WARNINGS"))
             .size(SMALL_PLUS_TEXT)
             .vertical_alignment(Vertical::Top)
             .horizontal_alignment(Horizontal::Center)
@@ -61,7 +61,7 @@ pub(in super::super) fn build_warnings_summary_page<'a>(
     .height(Length::Fill);
 
     let fouls_container = container(column![
-        text("FOULS")
+        text(tr("FOULS"))
             .size(SMALL_PLUS_TEXT)
             .vertical_alignment(Vertical::Top)
             .horizontal_alignment(Horizontal::Center)
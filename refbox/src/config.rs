@@ -1,4 +1,9 @@
-use crate::sound_controller::SoundSettings;
+use crate::{
+    i18n::Language,
+    infractions::InfractionLists,
+    sound_controller::SoundSettings,
+    training::{self, IntervalProgram},
+};
 use derivative::Derivative;
 use enum_derive_2018::{EnumDisplay, EnumFromStr};
 use macro_attr_2018::macro_attr;
@@ -13,6 +18,8 @@ pub struct Hardware {
     pub screen_x: i32,
     pub screen_y: i32,
     pub white_on_right: bool,
+    /// Extra scaling applied to the UI on top of fitting it to the window, in percent
+    pub ui_scale: u16,
 }
 
 impl Default for Hardware {
@@ -21,6 +28,7 @@ impl Default for Hardware {
             screen_x: 945,
             screen_y: 691,
             white_on_right: false,
+            ui_scale: 100,
         }
     }
 }
@@ -31,16 +39,19 @@ impl Hardware {
             mut screen_x,
             mut screen_y,
             mut white_on_right,
+            mut ui_scale,
         } = Default::default();
 
         get_integer_value(old, "screen_x", &mut screen_x);
         get_integer_value(old, "screen_y", &mut screen_y);
         get_boolean_value(old, "white_on_right", &mut white_on_right);
+        get_integer_value(old, "ui_scale", &mut ui_scale);
 
         Self {
             screen_x,
             screen_y,
             white_on_right,
+            ui_scale,
         }
     }
 }
@@ -110,34 +121,329 @@ impl UwhPortal {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyAction {
+    StartStopClock,
+    StartClock,
+    StopClock,
+    BlackGoal,
+    WhiteGoal,
+    BlackTimeout,
+    WhiteTimeout,
+    RefTimeout,
+    PenaltyShot,
+}
+
+/// Keyboard shortcuts for the main page. Keys are named as in `iced::keyboard::KeyCode`
+/// (e.g. `"Space"`, `"F1"`, `"NumpadAdd"`).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Derivative)]
+#[derivative(Default)]
+pub struct KeyBindings {
+    #[derivative(Default(value = "true"))]
+    pub enabled: bool,
+    /// When enabled, only the `start_stop_clock` keys are used, so that a foot pedal
+    /// that can only send a single key can't trigger any other action
+    pub single_key_start_stop: bool,
+    #[derivative(Default(value = "vec![\"Space\".to_string(), \"Pause\".to_string()]"))]
+    pub start_stop_clock: Vec<String>,
+    #[derivative(Default(value = "vec![\"NumpadAdd\".to_string()]"))]
+    pub start_clock: Vec<String>,
+    #[derivative(Default(value = "vec![\"NumpadSubtract\".to_string()]"))]
+    pub stop_clock: Vec<String>,
+    #[derivative(Default(value = "vec![\"B\".to_string(), \"NumpadDivide\".to_string()]"))]
+    pub black_goal: Vec<String>,
+    #[derivative(Default(value = "vec![\"W\".to_string(), \"NumpadMultiply\".to_string()]"))]
+    pub white_goal: Vec<String>,
+    #[derivative(Default(value = "vec![\"F1\".to_string()]"))]
+    pub black_timeout: Vec<String>,
+    #[derivative(Default(value = "vec![\"F4\".to_string()]"))]
+    pub white_timeout: Vec<String>,
+    #[derivative(Default(value = "vec![\"F2\".to_string()]"))]
+    pub ref_timeout: Vec<String>,
+    #[derivative(Default(value = "vec![\"F3\".to_string()]"))]
+    pub penalty_shot: Vec<String>,
+}
+
+impl KeyBindings {
+    pub fn migrate(old: &Table) -> Self {
+        let Self {
+            mut enabled,
+            mut single_key_start_stop,
+            mut start_stop_clock,
+            mut start_clock,
+            mut stop_clock,
+            mut black_goal,
+            mut white_goal,
+            mut black_timeout,
+            mut white_timeout,
+            mut ref_timeout,
+            mut penalty_shot,
+        } = Default::default();
+
+        get_boolean_value(old, "enabled", &mut enabled);
+        get_boolean_value(old, "single_key_start_stop", &mut single_key_start_stop);
+        get_string_list_value(old, "start_stop_clock", &mut start_stop_clock);
+        get_string_list_value(old, "start_clock", &mut start_clock);
+        get_string_list_value(old, "stop_clock", &mut stop_clock);
+        get_string_list_value(old, "black_goal", &mut black_goal);
+        get_string_list_value(old, "white_goal", &mut white_goal);
+        get_string_list_value(old, "black_timeout", &mut black_timeout);
+        get_string_list_value(old, "white_timeout", &mut white_timeout);
+        get_string_list_value(old, "ref_timeout", &mut ref_timeout);
+        get_string_list_value(old, "penalty_shot", &mut penalty_shot);
+
+        Self {
+            enabled,
+            single_key_start_stop,
+            start_stop_clock,
+            start_clock,
+            stop_clock,
+            black_goal,
+            white_goal,
+            black_timeout,
+            white_timeout,
+            ref_timeout,
+            penalty_shot,
+        }
+    }
+
+    /// Returns the action bound to `key`, if there is one. Each press of a `start_stop_clock`
+    /// key toggles the clock. In single key mode, the other keys are ignored.
+    pub fn action(&self, key: &str) -> Option<KeyAction> {
+        if !self.enabled {
+            return None;
+        }
+
+        if self.start_stop_clock.iter().any(|k| k == key) {
+            return Some(KeyAction::StartStopClock);
+        }
+        if self.single_key_start_stop {
+            return None;
+        }
+
+        let bindings = [
+            (&self.start_clock, KeyAction::StartClock),
+            (&self.stop_clock, KeyAction::StopClock),
+            (&self.black_goal, KeyAction::BlackGoal),
+            (&self.white_goal, KeyAction::WhiteGoal),
+            (&self.black_timeout, KeyAction::BlackTimeout),
+            (&self.white_timeout, KeyAction::WhiteTimeout),
+            (&self.ref_timeout, KeyAction::RefTimeout),
+            (&self.penalty_shot, KeyAction::PenaltyShot),
+        ];
+
+        bindings
+            .into_iter()
+            .find(|(keys, _)| keys.iter().any(|k| k == key))
+            .map(|(_, action)| action)
+    }
+}
+
+/// The roles that can operate the refbox. Each role can do everything the roles before it can.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Role {
+    Timekeeper,
+    ChiefReferee,
+}
+
+/// Optional PIN protection for the settings and destructive actions. When enabled, the refbox runs
+/// with the `Timekeeper` role and the chief referee's PIN (up to 4 digits) must be entered before
+/// each action that needs the `ChiefReferee` role. The lock stays open until a PIN has been set.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SettingsLock {
+    pub enabled: bool,
+    pub chief_referee_pin: Option<u16>,
+}
+
+impl SettingsLock {
+    pub fn migrate(old: &Table) -> Self {
+        let Self {
+            mut enabled,
+            mut chief_referee_pin,
+        } = Default::default();
+
+        get_boolean_value(old, "enabled", &mut enabled);
+        if let Some(pin) = old
+            .get("chief_referee_pin")
+            .and_then(|pin| pin.as_integer())
+            .and_then(|pin| pin.try_into().ok())
+        {
+            chief_referee_pin = Some(pin);
+        }
+
+        Self {
+            enabled,
+            chief_referee_pin,
+        }
+    }
+
+    /// Whether the lock is protecting anything. It is only active once a PIN has been set
+    pub fn is_active(&self) -> bool {
+        self.enabled && self.chief_referee_pin.is_some()
+    }
+
+    /// Whether an action that needs `role` can be done without entering a PIN
+    pub fn allows(&self, role: Role) -> bool {
+        !self.is_active() || role <= Role::Timekeeper
+    }
+
+    /// The role granted by entering `pin`
+    pub fn role_for_pin(&self, pin: u16) -> Role {
+        if Some(pin) == self.chief_referee_pin {
+            Role::ChiefReferee
+        } else {
+            Role::Timekeeper
+        }
+    }
+}
+
+/// Rules for suggesting a penalty when warnings or fouls are repeated. A count of 0 disables that
+/// rule. Each suggestion is only made once, when the count reaches the threshold.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Derivative)]
+#[derivative(Default)]
+pub struct EscalationRules {
+    pub enabled: bool,
+    /// The number of warnings for the same player that suggests a penalty for that player
+    #[derivative(Default(value = "2"))]
+    pub player_warnings: u8,
+    /// The number of warnings for the same infraction by one team that suggests a penalty
+    #[derivative(Default(value = "2"))]
+    pub infraction_warnings: u8,
+    /// The number of fouls by one team that suggests a team penalty
+    #[derivative(Default(value = "3"))]
+    pub team_fouls: u8,
+}
+
+impl EscalationRules {
+    pub fn migrate(old: &Table) -> Self {
+        let Self {
+            mut enabled,
+            mut player_warnings,
+            mut infraction_warnings,
+            mut team_fouls,
+        } = Default::default();
+
+        get_boolean_value(old, "enabled", &mut enabled);
+        get_integer_value(old, "player_warnings", &mut player_warnings);
+        get_integer_value(old, "infraction_warnings", &mut infraction_warnings);
+        get_integer_value(old, "team_fouls", &mut team_fouls);
+
+        Self {
+            enabled,
+            player_warnings,
+            infraction_warnings,
+            team_fouls,
+        }
+    }
+}
+
+/// How the refbox tries to win back time when the tournament is running behind its published
+/// schedule. Half times and breaks are never shortened below their floors.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Derivative)]
+#[derivative(Default)]
+pub struct CatchUp {
+    pub policy: CatchUpPolicy,
+    /// The shortest half time that the policy will shorten to, in seconds
+    #[derivative(Default(value = "60"))]
+    pub min_half_time_secs: u32,
+    /// The shortest break between games that the policy will shorten to, in seconds. This only
+    /// has an effect when it is shorter than the game's minimum break
+    #[derivative(Default(value = "120"))]
+    pub min_break_secs: u32,
+}
+
+impl CatchUp {
+    pub fn migrate(old: &Table) -> Self {
+        let Self {
+            mut policy,
+            mut min_half_time_secs,
+            mut min_break_secs,
+        } = Default::default();
+
+        if let Some(old_policy) = old.get("policy") {
+            if let Some(old_policy) = old_policy.as_str() {
+                if let Ok(old_policy) = old_policy.parse() {
+                    policy = old_policy;
+                }
+            }
+        }
+        get_integer_value(old, "min_half_time_secs", &mut min_half_time_secs);
+        get_integer_value(old, "min_break_secs", &mut min_break_secs);
+
+        Self {
+            policy,
+            min_half_time_secs,
+            min_break_secs,
+        }
+    }
+
+    pub fn shortens_half_time(&self) -> bool {
+        matches!(
+            self.policy,
+            CatchUpPolicy::ShortenHalfTime | CatchUpPolicy::Both
+        )
+    }
+
+    pub fn shortens_breaks(&self) -> bool {
+        matches!(
+            self.policy,
+            CatchUpPolicy::ShortenBreaks | CatchUpPolicy::Both
+        )
+    }
+}
+
 #[derive(Derivative, Serialize, Deserialize)]
 #[derivative(Clone, Debug, Default, PartialEq, Eq)]
 pub struct Config {
     pub mode: Mode,
+    pub language: Language,
     pub hide_time: bool,
     #[derivative(Default(value = "true"))]
     pub collect_scorer_cap_num: bool,
     #[derivative(Default(value = "true"))]
     pub track_fouls_and_warnings: bool,
+    /// The number of the team's following games that a player is suspended for after a total
+    /// dismissal
+    #[derivative(Default(value = "1"))]
+    pub dismissal_suspension_games: u8,
+    /// Show a banner when a penalty runs out, naming the player who may return
+    #[derivative(Default(value = "true"))]
+    pub penalty_expiry_alerts: bool,
     pub game: Game,
     pub hardware: Hardware,
     pub uwhscores: UwhScores,
     pub uwhportal: UwhPortal,
     pub sound: SoundSettings,
+    pub key_bindings: KeyBindings,
+    pub settings_lock: SettingsLock,
+    pub infractions: InfractionLists,
+    pub escalation: EscalationRules,
+    pub catch_up: CatchUp,
+    #[derivative(Default(value = "training::default_programs()"))]
+    pub training_programs: Vec<IntervalProgram>,
 }
 
 impl Config {
     pub fn migrate(old: &Table) -> Self {
         let Self {
             mut mode,
+            mut language,
             mut hide_time,
             mut collect_scorer_cap_num,
             mut track_fouls_and_warnings,
+            mut dismissal_suspension_games,
+            mut penalty_expiry_alerts,
             mut game,
             mut hardware,
             mut uwhscores,
             mut uwhportal,
             mut sound,
+            mut key_bindings,
+            mut settings_lock,
+            mut infractions,
+            mut escalation,
+            mut catch_up,
+            mut training_programs,
         } = Default::default();
 
         if let Some(old_mode) = old.get("mode") {
@@ -147,6 +453,13 @@ impl Config {
                 }
             }
         }
+        if let Some(old_language) = old.get("language") {
+            if let Some(old_language) = old_language.as_str() {
+                if let Ok(old_language) = old_language.parse() {
+                    language = old_language;
+                }
+            }
+        }
         get_boolean_value(old, "hide_time", &mut hide_time);
         get_boolean_value(old, "collect_scorer_cap_num", &mut collect_scorer_cap_num);
         get_boolean_value(
@@ -154,6 +467,12 @@ impl Config {
             "track_fouls_and_warnings",
             &mut track_fouls_and_warnings,
         );
+        get_integer_value(
+            old,
+            "dismissal_suspension_games",
+            &mut dismissal_suspension_games,
+        );
+        get_boolean_value(old, "penalty_expiry_alerts", &mut penalty_expiry_alerts);
         if let Some(old_game) = old.get("game") {
             if let Some(old_game) = old_game.as_table() {
                 game = Game::migrate(old_game);
@@ -179,17 +498,52 @@ impl Config {
                 sound = SoundSettings::migrate(old_sound);
             }
         }
+        if let Some(old_key_bindings) = old.get("key_bindings") {
+            if let Some(old_key_bindings) = old_key_bindings.as_table() {
+                key_bindings = KeyBindings::migrate(old_key_bindings);
+            }
+        }
+        if let Some(old_settings_lock) = old.get("settings_lock") {
+            if let Some(old_settings_lock) = old_settings_lock.as_table() {
+                settings_lock = SettingsLock::migrate(old_settings_lock);
+            }
+        }
+        if let Some(old_infractions) = old.get("infractions") {
+            if let Some(old_infractions) = old_infractions.as_table() {
+                infractions = InfractionLists::migrate(old_infractions);
+            }
+        }
+        if let Some(old_escalation) = old.get("escalation") {
+            if let Some(old_escalation) = old_escalation.as_table() {
+                escalation = EscalationRules::migrate(old_escalation);
+            }
+        }
+        if let Some(old_catch_up) = old.get("catch_up") {
+            if let Some(old_catch_up) = old_catch_up.as_table() {
+                catch_up = CatchUp::migrate(old_catch_up);
+            }
+        }
+        training::get_program_list(old, "training_programs", &mut training_programs);
 
         Self {
             mode,
+            language,
             hide_time,
             collect_scorer_cap_num,
             track_fouls_and_warnings,
+            dismissal_suspension_games,
+            penalty_expiry_alerts,
             game,
             hardware,
             uwhscores,
             uwhportal,
             sound,
+            key_bindings,
+            settings_lock,
+            infractions,
+            escalation,
+            catch_up,
+            training_programs,
         }
     }
 }
@@ -205,6 +559,18 @@ macro_attr! {
     }
 }
 
+macro_attr! {
+    #[derive(Debug, Clone, Copy, Derivative, PartialEq, Eq, Serialize, Deserialize, EnumDisplay!, EnumFromStr!)]
+    #[derivative(Default)]
+    pub enum CatchUpPolicy {
+        #[derivative(Default)]
+        Off,
+        ShortenHalfTime,
+        ShortenBreaks,
+        Both,
+    }
+}
+
 fn get_integer_value<T: DeserializeOwned + TryFrom<i64>>(table: &Table, key: &str, save: &mut T) {
     if let Some(value) = table.get(key) {
         if let Some(value) = value.as_integer() {
@@ -231,6 +597,17 @@ fn get_string_value(table: &Table, key: &str, save: &mut String) {
     }
 }
 
+fn get_string_list_value(table: &Table, key: &str, save: &mut Vec<String>) {
+    if let Some(value) = table.get(key) {
+        if let Some(value) = value.as_array() {
+            *save = value
+                .iter()
+                .filter_map(|v| Some(v.as_str()?.to_string()))
+                .collect();
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::sound_controller::Volume;
@@ -262,6 +639,14 @@ mod test {
         assert_eq!(deser, Ok(u));
     }
 
+    #[test]
+    fn test_ser_key_bindings() {
+        let k: KeyBindings = Default::default();
+        let serialized = toml::to_string(&k).unwrap();
+        let deser = toml::from_str(&serialized);
+        assert_eq!(deser, Ok(k));
+    }
+
     #[test]
     fn test_ser_config() {
         let config: Config = Default::default();
@@ -280,6 +665,11 @@ mod test {
         assert_eq!(hw.screen_x, 123);
         assert_eq!(hw.screen_y, 456);
         assert_eq!(hw.white_on_right, true);
+        assert_eq!(hw.ui_scale, 100);
+
+        old.insert("ui_scale".to_string(), toml::Value::Integer(125));
+        let hw = Hardware::migrate(&old);
+        assert_eq!(hw.ui_scale, 125);
     }
 
     #[test]
@@ -319,11 +709,120 @@ mod test {
         assert_eq!(u.token, "token");
     }
 
+    #[test]
+    fn test_migrate_key_bindings() {
+        let mut old: Table = Default::default();
+        old.insert("enabled".to_string(), toml::Value::Boolean(false));
+        old.insert(
+            "single_key_start_stop".to_string(),
+            toml::Value::Boolean(true),
+        );
+        old.insert(
+            "black_goal".to_string(),
+            toml::Value::Array(vec![
+                toml::Value::String("Z".to_string()),
+                toml::Value::String("X".to_string()),
+            ]),
+        );
+        let k = KeyBindings::migrate(&old);
+        assert!(!k.enabled);
+        assert!(k.single_key_start_stop);
+        assert_eq!(k.black_goal, vec!["Z".to_string(), "X".to_string()]);
+        assert_eq!(k.white_goal, KeyBindings::default().white_goal);
+    }
+
+    #[test]
+    fn test_key_binding_action() {
+        let mut k: KeyBindings = Default::default();
+        assert_eq!(k.action("B"), Some(KeyAction::BlackGoal));
+        assert_eq!(k.action("NumpadMultiply"), Some(KeyAction::WhiteGoal));
+        assert_eq!(k.action("F3"), Some(KeyAction::PenaltyShot));
+        assert_eq!(k.action("NumpadAdd"), Some(KeyAction::StartClock));
+        assert_eq!(k.action("Space"), Some(KeyAction::StartStopClock));
+        assert_eq!(k.action("Q"), None);
+
+        k.single_key_start_stop = true;
+        assert_eq!(k.action("Space"), Some(KeyAction::StartStopClock));
+        assert_eq!(k.action("NumpadAdd"), None);
+        assert_eq!(k.action("B"), None);
+
+        k.enabled = false;
+        assert_eq!(k.action("Space"), None);
+        assert_eq!(k.action("B"), None);
+    }
+
+    #[test]
+    fn test_migrate_settings_lock() {
+        let mut old: Table = Default::default();
+        old.insert("enabled".to_string(), toml::Value::Boolean(true));
+        old.insert("chief_referee_pin".to_string(), toml::Value::Integer(4321));
+        let lock = SettingsLock::migrate(&old);
+        assert!(lock.enabled);
+        assert_eq!(lock.chief_referee_pin, Some(4321));
+
+        let lock = SettingsLock::migrate(&Default::default());
+        assert_eq!(lock, Default::default());
+    }
+
+    #[test]
+    fn test_settings_lock_roles() {
+        let mut lock = SettingsLock {
+            enabled: false,
+            chief_referee_pin: Some(1234),
+        };
+        assert!(lock.allows(Role::Timekeeper));
+        assert!(lock.allows(Role::ChiefReferee));
+
+        lock.enabled = true;
+        assert!(lock.allows(Role::Timekeeper));
+        assert!(!lock.allows(Role::ChiefReferee));
+
+        assert_eq!(lock.role_for_pin(1234), Role::ChiefReferee);
+        assert_eq!(lock.role_for_pin(0), Role::Timekeeper);
+        assert_eq!(lock.role_for_pin(1235), Role::Timekeeper);
+
+        // Without a PIN, the lock can't be enabled and nothing unlocks it
+        lock.chief_referee_pin = None;
+        assert!(!lock.is_active());
+        assert!(lock.allows(Role::ChiefReferee));
+        assert_eq!(lock.role_for_pin(0), Role::Timekeeper);
+    }
+
+    #[test]
+    fn test_migrate_escalation_rules() {
+        let mut old: Table = Default::default();
+        old.insert("enabled".to_string(), toml::Value::Boolean(true));
+        old.insert("player_warnings".to_string(), toml::Value::Integer(3));
+        old.insert("team_fouls".to_string(), toml::Value::Integer(0));
+        let rules = EscalationRules::migrate(&old);
+        assert_eq!(
+            rules,
+            EscalationRules {
+                enabled: true,
+                player_warnings: 3,
+                infraction_warnings: 2,
+                team_fouls: 0,
+            }
+        );
+    }
+
     #[test]
     fn test_migrate_config() {
         let mut old: Table = Default::default();
         old.insert("mode".to_string(), toml::Value::String("Rugby".to_string()));
+        old.insert(
+            "language".to_string(),
+            toml::Value::String("German".to_string()),
+        );
         old.insert("hide_time".to_string(), toml::Value::Boolean(true));
+        old.insert(
+            "dismissal_suspension_games".to_string(),
+            toml::Value::Integer(2),
+        );
+        old.insert(
+            "penalty_expiry_alerts".to_string(),
+            toml::Value::Boolean(false),
+        );
         old.insert(
             "collect_scorer_cap_num".to_string(),
             toml::Value::Boolean(true),
@@ -371,9 +870,19 @@ mod test {
             toml::Value::String("Max".to_string()),
         );
         old.insert("sound".to_string(), toml::Value::Table(sound));
+        let mut catch_up: Table = Default::default();
+        catch_up.insert(
+            "policy".to_string(),
+            toml::Value::String("Both".to_string()),
+        );
+        catch_up.insert("min_break_secs".to_string(), toml::Value::Integer(90));
+        old.insert("catch_up".to_string(), toml::Value::Table(catch_up));
         let config = Config::migrate(&old);
         assert_eq!(config.mode, Mode::Rugby);
+        assert_eq!(config.language, Language::German);
         assert_eq!(config.hide_time, true);
+        assert_eq!(config.dismissal_suspension_games, 2);
+        assert!(!config.penalty_expiry_alerts);
         assert_eq!(config.collect_scorer_cap_num, true);
         assert_eq!(config.game.half_play_duration, Duration::from_secs(123));
         assert_eq!(config.hardware.screen_x, 123);
@@ -387,5 +896,8 @@ mod test {
         assert_eq!(config.uwhportal.token, "token");
         assert_eq!(config.sound.sound_enabled, false);
         assert_eq!(config.sound.whistle_vol, Volume::Max);
+        assert_eq!(config.catch_up.policy, CatchUpPolicy::Both);
+        assert_eq!(config.catch_up.min_half_time_secs, 60);
+        assert_eq!(config.catch_up.min_break_secs, 90);
     }
 }
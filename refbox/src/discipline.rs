@@ -0,0 +1,274 @@
+//! Disciplinary records that are kept across all the games of a tournament. Each total dismissal
+//! is recorded against the player's team (using the team ids from uwhscores), and the player is
+//! suspended for the team's next games. The records are saved locally so that they survive
+//! restarts of the refbox.
+
+use log::*;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+use time::PrimitiveDateTime;
+use uwh_common::{game_snapshot::Color, uwhscores::GameInfo};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DisciplinaryRecord {
+    pub tid: u32,
+    pub gid: u32,
+    pub game_start: PrimitiveDateTime,
+    pub team_id: u32,
+    pub team_name: String,
+    pub player_number: u8,
+    /// The number of the team's following games that the player is suspended for
+    pub suspended_games: u8,
+}
+
+#[derive(Debug, Default)]
+pub struct DisciplineLog {
+    path: Option<PathBuf>,
+    records: Vec<DisciplinaryRecord>,
+}
+
+impl DisciplineLog {
+    /// The default location of the saved records
+    pub fn default_path() -> Option<PathBuf> {
+        let mut path = directories::BaseDirs::new()?.data_local_dir().to_path_buf();
+        path.push("uwh-refbox");
+        path.push("discipline.json");
+        Some(path)
+    }
+
+    /// Loads the records saved at `path`. If there are none, the log starts empty
+    pub fn load(path: PathBuf) -> Self {
+        let records = match fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|e| {
+                error!("Failed to parse the disciplinary records: {e}");
+                Vec::new()
+            }),
+            Err(e) => {
+                info!(
+                    "No disciplinary records loaded from {}: {e}",
+                    path.display()
+                );
+                Vec::new()
+            }
+        };
+
+        Self {
+            path: Some(path),
+            records,
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if let Some(ref path) = self.path {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(path, serde_json::to_string_pretty(&self.records)?)?;
+        }
+        Ok(())
+    }
+
+    /// Records a total dismissal of `color` #`player_number` in `game`
+    pub fn add_dismissal(
+        &mut self,
+        game: &GameInfo,
+        color: Color,
+        player_number: u8,
+        suspended_games: u8,
+    ) {
+        let (team_id, team_name) = match color {
+            Color::Black => (game.black_id, game.black.clone()),
+            Color::White => (game.white_id, game.white.clone()),
+        };
+        info!(
+            "Recording a dismissal of {team_name} #{player_number} in game {}, suspended for \
+            {suspended_games} games",
+            game.gid
+        );
+
+        let record = DisciplinaryRecord {
+            tid: game.tid,
+            gid: game.gid,
+            game_start: game.start_time,
+            team_id,
+            team_name,
+            player_number,
+            suspended_games,
+        };
+        if !self.records.contains(&record) {
+            self.records.push(record);
+        }
+    }
+
+    /// Finds the dismissal that suspends `color` #`player_number` from playing in `game`, if any.
+    /// `games` is used to find the team's games between the dismissal and `game`
+    pub fn suspension(
+        &self,
+        game: &GameInfo,
+        color: Color,
+        player_number: u8,
+        games: &BTreeMap<u32, GameInfo>,
+    ) -> Option<&DisciplinaryRecord> {
+        let team_id = match color {
+            Color::Black => game.black_id,
+            Color::White => game.white_id,
+        };
+
+        self.records
+            .iter()
+            .filter(|rec| {
+                rec.tid == game.tid
+                    && rec.team_id == team_id
+                    && rec.player_number == player_number
+                    && rec.game_start < game.start_time
+            })
+            .find(|rec| {
+                let games_since = games
+                    .values()
+                    .filter(|g| g.tid == rec.tid && g.gid != rec.gid)
+                    .filter(|g| g.black_id == team_id || g.white_id == team_id)
+                    .filter(|g| g.start_time > rec.game_start && g.start_time <= game.start_time)
+                    .count();
+                games_since <= rec.suspended_games as usize
+            })
+    }
+
+    /// A CSV report of the dismissals in tournament `tid`
+    pub fn report(&self, tid: u32) -> String {
+        let mut report = "Team,Cap Number,Game,Game Start,Suspended Games\n".to_string();
+        for rec in self.records.iter().filter(|rec| rec.tid == tid) {
+            writeln!(
+                report,
+                "\"{}\",{},{},{},{}",
+                rec.team_name.replace('"', "\"\""),
+                rec.player_number,
+                rec.gid,
+                rec.game_start,
+                rec.suspended_games
+            )
+            .unwrap();
+        }
+        report
+    }
+
+    /// Writes the report for tournament `tid` next to the saved records, returning its path
+    pub fn export_report(&self, tid: u32) -> Result<PathBuf> {
+        let dir = self
+            .path
+            .as_deref()
+            .and_then(Path::parent)
+            .ok_or(DisciplineError::NoLocation)?;
+        fs::create_dir_all(dir)?;
+        let path = dir.join(format!("discipline-report-{tid}.csv"));
+        fs::write(&path, self.report(tid))?;
+        Ok(path)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum DisciplineError {
+    #[error("There is no location to save the records")]
+    NoLocation,
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
+
+pub type Result<T> = std::result::Result<T, DisciplineError>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use time::macros::datetime;
+
+    fn game(gid: u32, black_id: u32, white_id: u32, start_time: PrimitiveDateTime) -> GameInfo {
+        GameInfo {
+            black: format!("Team {black_id}"),
+            black_id,
+            game_type: "RR".to_string(),
+            gid,
+            pool: "1".to_string(),
+            score_b: 0,
+            score_w: 0,
+            start_time,
+            tid: 7,
+            timing_rules: None,
+            white: format!("Team {white_id}"),
+            white_id,
+        }
+    }
+
+    #[test]
+    fn test_suspension() {
+        let games: BTreeMap<u32, GameInfo> = [
+            game(1, 10, 20, datetime!(2024-05-01 09:00)),
+            game(2, 30, 40, datetime!(2024-05-01 10:00)),
+            game(3, 20, 30, datetime!(2024-05-01 11:00)),
+            game(4, 40, 20, datetime!(2024-05-01 12:00)),
+            game(5, 10, 30, datetime!(2024-05-01 13:00)),
+        ]
+        .into_iter()
+        .map(|g| (g.gid, g))
+        .collect();
+
+        let mut log = DisciplineLog::default();
+        log.add_dismissal(&games[&1], Color::White, 5, 1);
+        log.add_dismissal(&games[&1], Color::White, 5, 1);
+        assert_eq!(log.records.len(), 1);
+        assert_eq!(log.records[0].team_id, 20);
+
+        // Team 20's next game is game 3, where they play black
+        assert!(log
+            .suspension(&games[&3], Color::Black, 5, &games)
+            .is_some());
+        assert!(log
+            .suspension(&games[&3], Color::Black, 6, &games)
+            .is_none());
+        assert!(log
+            .suspension(&games[&3], Color::White, 5, &games)
+            .is_none());
+        // The suspension only lasts one game
+        assert!(log
+            .suspension(&games[&4], Color::White, 5, &games)
+            .is_none());
+        // It doesn't apply to the game of the dismissal
+        assert!(log
+            .suspension(&games[&1], Color::White, 5, &games)
+            .is_none());
+
+        log.add_dismissal(&games[&2], Color::Black, 9, 2);
+        assert!(log
+            .suspension(&games[&3], Color::White, 9, &games)
+            .is_some());
+        assert!(log
+            .suspension(&games[&5], Color::White, 9, &games)
+            .is_some());
+    }
+
+    #[test]
+    fn test_report() {
+        let mut log = DisciplineLog::default();
+        log.add_dismissal(
+            &game(3, 10, 20, datetime!(2024-05-01 11:00)),
+            Color::Black,
+            4,
+            1,
+        );
+        assert_eq!(
+            log.report(7),
+            "Team,Cap Number,Game,Game Start,Suspended Games\n\
+            \"Team 10\",4,3,2024-05-01 11:00:00.0,1\n"
+        );
+        assert_eq!(
+            log.report(8),
+            "Team,Cap Number,Game,Game Start,Suspended Games\n"
+        );
+    }
+}
@@ -0,0 +1,134 @@
+//! Translation of the text shown in the UI. The code uses the English text directly, and each
+//! other language has a catalog in `resources/translations` that maps the English text to the
+//! translated text. Any text that is missing from a catalog is shown in English.
+
+use derivative::Derivative;
+use enum_derive_2018::{EnumDisplay, EnumFromStr};
+use log::*;
+use macro_attr_2018::macro_attr;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    sync::{OnceLock, RwLock},
+};
+
+macro_attr! {
+    #[derive(Debug, Clone, Copy, Derivative, PartialEq, Eq, Serialize, Deserialize, EnumDisplay!, EnumFromStr!)]
+    #[derivative(Default)]
+    pub enum Language {
+        #[derivative(Default)]
+        English,
+        French,
+        Spanish,
+        German,
+    }
+}
+
+impl Language {
+    /// The name of the language, in that language
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Self::English => "ENGLISH",
+            Self::French => "FRANÇAIS",
+            Self::Spanish => "ESPAÑOL",
+            Self::German => "DEUTSCH",
+        }
+    }
+
+    fn catalog_source(self) -> Option<&'static str> {
+        match self {
+            Self::English => None,
+            Self::French => Some(include_str!("../resources/translations/fr.toml")),
+            Self::Spanish => Some(include_str!("../resources/translations/es.toml")),
+            Self::German => Some(include_str!("../resources/translations/de.toml")),
+        }
+    }
+}
+
+type Catalog = HashMap<String, String>;
+
+static LANGUAGE: RwLock<Language> = RwLock::new(Language::English);
+
+pub fn set_language(language: Language) {
+    info!("Setting the UI language to {language}");
+    *LANGUAGE.write().unwrap() = language;
+}
+
+pub fn language() -> Language {
+    *LANGUAGE.read().unwrap()
+}
+
+/// Translates `text` into the current language
+pub fn tr(text: &str) -> &str {
+    translate(language(), text)
+}
+
+pub fn translate(language: Language, text: &str) -> &str {
+    catalog(language)
+        .and_then(|catalog| catalog.get(text))
+        .map(String::as_str)
+        .unwrap_or(text)
+}
+
+fn catalog(language: Language) -> Option<&'static Catalog> {
+    static FRENCH: OnceLock<Catalog> = OnceLock::new();
+    static SPANISH: OnceLock<Catalog> = OnceLock::new();
+    static GERMAN: OnceLock<Catalog> = OnceLock::new();
+
+    let cell = match language {
+        Language::English => return None,
+        Language::French => &FRENCH,
+        Language::Spanish => &SPANISH,
+        Language::German => &GERMAN,
+    };
+
+    let source = language.catalog_source()?;
+    Some(cell.get_or_init(|| {
+        toml::from_str(source).unwrap_or_else(|e| {
+            error!("Failed to load the {language} translations: {e}");
+            Default::default()
+        })
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TRANSLATED: [Language; 3] = [Language::French, Language::Spanish, Language::German];
+
+    #[test]
+    fn test_catalogs_parse() {
+        for language in TRANSLATED {
+            let catalog: Catalog = toml::from_str(language.catalog_source().unwrap()).unwrap();
+            assert!(!catalog.is_empty(), "{language} catalog is empty");
+        }
+    }
+
+    #[test]
+    fn test_catalogs_match() {
+        let french: Catalog = toml::from_str(Language::French.catalog_source().unwrap()).unwrap();
+        for language in TRANSLATED {
+            let catalog: Catalog = toml::from_str(language.catalog_source().unwrap()).unwrap();
+            for key in french.keys() {
+                assert!(catalog.contains_key(key), "{language} is missing {key:?}");
+            }
+            for key in catalog.keys() {
+                assert!(french.contains_key(key), "French is missing {key:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_translate() {
+        assert_eq!(translate(Language::English, "CANCEL"), "CANCEL");
+        assert_eq!(translate(Language::French, "CANCEL"), "ANNULER");
+        assert_eq!(translate(Language::Spanish, "CANCEL"), "CANCELAR");
+        assert_eq!(translate(Language::German, "CANCEL"), "ABBRECHEN");
+        assert_eq!(
+            translate(Language::German, "Not In The Catalog"),
+            "Not In The Catalog"
+        );
+        assert_eq!(translate(Language::French, "Stick Foul"), "Faute de crosse");
+    }
+}
@@ -0,0 +1,269 @@
+//! The infractions that can be called in each mode. Each mode has its own list in the config,
+//! which defaults to the built in infractions. Infractions that aren't built in use
+//! `Infraction::Custom` with an id, so that the snapshots sent to the other apps keep the same
+//! format.
+
+use iced::widget::svg;
+use log::*;
+use serde::{Deserialize, Serialize};
+use std::{
+    path::PathBuf,
+    sync::{Arc, RwLock},
+};
+use toml::{Table, Value};
+use uwh_common::game_snapshot::Infraction;
+
+use crate::config::Mode;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InfractionDef {
+    pub id: Infraction,
+    pub name: String,
+    /// The name shown in lists and on the main page
+    pub short_name: String,
+    /// An SVG file to show on the infraction button. Infractions without an icon are shown by
+    /// their short name, unless they are built in
+    pub icon: Option<PathBuf>,
+}
+
+impl InfractionDef {
+    pub fn built_in(id: Infraction) -> Self {
+        Self {
+            id,
+            name: id.name().to_string(),
+            short_name: id.short_name().to_string(),
+            icon: None,
+        }
+    }
+}
+
+fn default_list() -> Vec<InfractionDef> {
+    Infraction::BUILT_IN
+        .into_iter()
+        .map(InfractionDef::built_in)
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InfractionLists {
+    pub hockey6v6: Vec<InfractionDef>,
+    pub hockey3v3: Vec<InfractionDef>,
+    pub rugby: Vec<InfractionDef>,
+}
+
+impl Default for InfractionLists {
+    fn default() -> Self {
+        Self {
+            hockey6v6: default_list(),
+            hockey3v3: default_list(),
+            rugby: default_list(),
+        }
+    }
+}
+
+impl InfractionLists {
+    pub fn migrate(old: &Table) -> Self {
+        let Self {
+            mut hockey6v6,
+            mut hockey3v3,
+            mut rugby,
+        } = Default::default();
+
+        get_list_value(old, "hockey6v6", &mut hockey6v6);
+        get_list_value(old, "hockey3v3", &mut hockey3v3);
+        get_list_value(old, "rugby", &mut rugby);
+
+        Self {
+            hockey6v6,
+            hockey3v3,
+            rugby,
+        }
+    }
+
+    pub fn for_mode(&self, mode: Mode) -> &[InfractionDef] {
+        match mode {
+            Mode::Hockey6V6 => &self.hockey6v6,
+            Mode::Hockey3V3 => &self.hockey3v3,
+            Mode::Rugby => &self.rugby,
+        }
+    }
+}
+
+fn get_list_value(table: &Table, key: &str, save: &mut Vec<InfractionDef>) {
+    if let Some(Value::Array(list)) = table.get(key) {
+        let list: Vec<InfractionDef> = list
+            .iter()
+            .filter_map(|def| match def.clone().try_into() {
+                Ok(def) => Some(def),
+                Err(e) => {
+                    warn!("Ignoring invalid infraction in the {key} list: {e}");
+                    None
+                }
+            })
+            .collect();
+        if !list.is_empty() {
+            *save = list;
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    def: InfractionDef,
+    icon: Option<svg::Handle>,
+}
+
+/// The infraction list for the current mode, with the icons loaded
+#[derive(Debug, Clone, Default)]
+pub struct Catalog {
+    entries: Vec<Entry>,
+}
+
+impl Catalog {
+    pub fn new(defs: &[InfractionDef]) -> Self {
+        let entries = defs
+            .iter()
+            .map(|def| {
+                let icon = def
+                    .icon
+                    .as_ref()
+                    .and_then(|path| match std::fs::read(path) {
+                        Ok(bytes) => Some(svg::Handle::from_memory(bytes)),
+                        Err(e) => {
+                            warn!("Failed to load the icon for {}: {e}", def.name);
+                            None
+                        }
+                    });
+                Entry {
+                    def: def.clone(),
+                    icon,
+                }
+            })
+            .collect();
+        Self { entries }
+    }
+
+    fn get(&self, infraction: Infraction) -> Option<&Entry> {
+        self.entries.iter().find(|entry| entry.def.id == infraction)
+    }
+
+    /// The infractions that can be selected, in the configured order
+    pub fn available(&self) -> Vec<Infraction> {
+        self.entries.iter().map(|entry| entry.def.id).collect()
+    }
+
+    pub fn short_name(&self, infraction: Infraction) -> String {
+        match self.get(infraction) {
+            Some(entry) => entry.def.short_name.clone(),
+            None => infraction.short_name().to_string(),
+        }
+    }
+
+    /// The icon for `infraction`, if it has one. Custom infractions without an icon file don't
+    pub fn icon(&self, infraction: Infraction) -> Option<svg::Handle> {
+        match self.get(infraction).and_then(|entry| entry.icon.clone()) {
+            Some(icon) => Some(icon),
+            None if matches!(infraction, Infraction::Custom(_)) => None,
+            None => Some(svg::Handle::from_memory(infraction.svg_fouls())),
+        }
+    }
+}
+
+static CATALOG: RwLock<Option<Arc<Catalog>>> = RwLock::new(None);
+
+/// Sets the infraction list to use, normally the one for the current mode
+pub fn set_catalog(defs: &[InfractionDef]) {
+    *CATALOG.write().unwrap() = Some(Arc::new(Catalog::new(defs)));
+}
+
+/// The current infraction catalog, or the built in one if none has been set
+pub fn catalog() -> Arc<Catalog> {
+    if let Some(catalog) = CATALOG.read().unwrap().as_ref() {
+        return catalog.clone();
+    }
+    CATALOG
+        .write()
+        .unwrap()
+        .get_or_insert_with(|| Arc::new(Catalog::new(&default_list())))
+        .clone()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ser_infraction_lists() {
+        let lists: InfractionLists = Default::default();
+        let serialized = toml::to_string(&lists).unwrap();
+        let deser = toml::from_str(&serialized);
+        assert_eq!(deser, Ok(lists));
+    }
+
+    #[test]
+    fn test_migrate_infraction_lists() {
+        let old: Table = toml::from_str(
+            r#"
+            [[rugby]]
+            id = "Unknown"
+            name = "Unknown"
+            short_name = "Unknown"
+
+            [[rugby]]
+            id = { Custom = 1 }
+            name = "Attacking The Goal Keeper"
+            short_name = "Keeper Foul"
+            icon = "/path/to/keeper_foul.svg"
+
+            [[rugby]]
+            id = "NotAnInfraction"
+            name = "Bad"
+            short_name = "Bad"
+            "#,
+        )
+        .unwrap();
+
+        let migrated = InfractionLists::migrate(&old);
+        assert_eq!(migrated.hockey6v6, default_list());
+        assert_eq!(migrated.hockey3v3, default_list());
+        assert_eq!(
+            migrated.rugby,
+            vec![
+                InfractionDef::built_in(Infraction::Unknown),
+                InfractionDef {
+                    id: Infraction::Custom(1),
+                    name: "Attacking The Goal Keeper".to_string(),
+                    short_name: "Keeper Foul".to_string(),
+                    icon: Some("/path/to/keeper_foul.svg".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_catalog() {
+        let catalog = Catalog::new(&[
+            InfractionDef {
+                short_name: "Stick".to_string(),
+                ..InfractionDef::built_in(Infraction::StickInfringement)
+            },
+            InfractionDef {
+                id: Infraction::Custom(3),
+                name: "Holding".to_string(),
+                short_name: "Holding".to_string(),
+                icon: None,
+            },
+        ]);
+
+        assert_eq!(
+            catalog.available(),
+            vec![Infraction::StickInfringement, Infraction::Custom(3)]
+        );
+        assert_eq!(catalog.short_name(Infraction::StickInfringement), "Stick");
+        assert_eq!(catalog.short_name(Infraction::Custom(3)), "Holding");
+        assert_eq!(catalog.short_name(Infraction::FreeArm), "Free Arm");
+        assert!(catalog.icon(Infraction::StickInfringement).is_some());
+        assert!(catalog.icon(Infraction::FreeArm).is_some());
+        assert!(catalog.icon(Infraction::Custom(3)).is_none());
+    }
+}
@@ -30,6 +30,10 @@ mod sound_controller;
 mod tournament_manager;
 
 mod config;
+mod discipline;
+mod i18n;
+mod infractions;
+mod training;
 use config::Config;
 
 const APP_NAME: &str = "refbox";
@@ -41,6 +45,10 @@ struct Cli {
     /// Don't run the simulator GUI
     no_simulate: bool,
 
+    #[clap(long)]
+    /// Also run a simulator of the penalty box display
+    penalty_box_sim: bool,
+
     #[clap(long, short, action(clap::ArgAction::Count))]
     /// Increase the log verbosity
     verbose: u8,
@@ -61,6 +69,10 @@ struct Cli {
     /// Port to listen on for TCP connections with a binary send type
     binary_port: u16,
 
+    #[clap(long, default_value = "8002")]
+    /// Port to listen on for TCP connections with the v2 binary send type
+    binary_v2_port: u16,
+
     #[clap(long, default_value = "8000")]
     /// Port to listen on for TCP connections with a JSON send type
     json_port: u16,
@@ -93,8 +105,22 @@ struct Cli {
     /// Number of archived logs to keep
     num_old_logs: u32,
 
+    #[clap(long)]
+    /// Replay wireless remote signals from a capture file instead of the GPIO pins
+    remote_replay: Option<PathBuf>,
+
+    #[clap(long)]
+    /// Record the wireless remote signals to a capture file
+    remote_record: Option<PathBuf>,
+
     #[clap(long, hide = true)]
     is_simulator: bool,
+
+    #[clap(long, hide = true)]
+    penalty_box: bool,
+
+    #[clap(long, hide = true)]
+    white_on_right: bool,
 }
 
 fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
@@ -116,7 +142,11 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
     });
     let app_name = if args.is_simulator {
         log_base_path.push("simulator");
-        "simulator"
+        if args.penalty_box {
+            "penalty-box-simulator"
+        } else {
+            "simulator"
+        }
     } else {
         "refbox"
     };
@@ -187,7 +217,13 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
 
     if args.is_simulator {
         let flags = sim_app::SimRefBoxAppFlags {
-            tcp_port: args.binary_port,
+            tcp_port: if args.penalty_box {
+                args.json_port
+            } else {
+                args.binary_v2_port
+            },
+            penalty_box: args.penalty_box,
+            white_on_right: args.white_on_right,
         };
 
         let mut settings = Settings::with_flags(flags);
@@ -202,13 +238,55 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
         info!("Starting RefBox App");
     }
 
-    let child = if args.no_simulate {
-        None
-    } else {
+    let config_path = confy::get_configuration_file_path(APP_NAME, None).unwrap();
+    info!("Reading config file from {config_path:?}",);
+
+    let mut config: Config = match confy::load(APP_NAME, None) {
+        Ok(c) => c,
+        Err(e) => {
+            warn!("Failed to use config file. Error: {e}");
+            let config = match std::fs::read_to_string(config_path) {
+                Ok(file) => {
+                    warn!("Found old config file, attempting migration");
+                    match toml::from_str(&file) {
+                        Ok(old_config) => Config::migrate(&old_config),
+                        Err(e) => {
+                            warn!("Failed to parse old config file. Error: {e}");
+                            warn!("Using default config");
+                            Config::default()
+                        }
+                    }
+                }
+                Err(e) => {
+                    warn!("Failed to read old config file. Error: {e}");
+                    warn!("Using default config");
+                    Config::default()
+                }
+            };
+            confy::store(APP_NAME, None, &config).unwrap();
+            config
+        }
+    };
+
+    if let Ok(offset) = time::UtcOffset::current_local_offset() {
+        if offset != config.uwhscores.timezone {
+            warn!(
+                "The timezone in the config file ({}) does not match the detected system \
+                 timezone ({offset}). The system timezone will be used.",
+                config.uwhscores.timezone
+            );
+        }
+        config.uwhscores.timezone = offset;
+        confy::store(APP_NAME, None, &config).unwrap();
+    }
+
+    let mut children = vec![];
+    if !args.no_simulate || args.penalty_box_sim {
         let bin_name = std::env::current_exe()?.into_os_string();
         info!("Current bin_name: {bin_name:?}");
 
         let binary_port = args.binary_port.to_string();
+        let binary_v2_port = args.binary_v2_port.to_string();
         let json_port = args.json_port.to_string();
         let scale = args.scale.to_string();
         let spacing = spacing.to_string();
@@ -220,6 +298,8 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
             "--is-simulator",
             "--binary-port",
             &binary_port,
+            "--binary-v2-port",
+            &binary_v2_port,
             "--json-port",
             &json_port,
             "--scale",
@@ -236,16 +316,32 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
 
         child_args.resize(child_args.len() + args.verbose as usize, "--verbose");
 
-        debug!("Child args: {child_args:?}");
+        if !args.no_simulate {
+            debug!("Child args: {child_args:?}");
 
-        info!("Starting child with birany port {binary_port}");
-        let child = Command::new(bin_name)
-            .args(child_args)
-            .stdin(Stdio::null())
-            .spawn()?;
+            info!("Starting child with birany v2 port {binary_v2_port}");
+            let child = Command::new(&bin_name)
+                .args(&child_args)
+                .stdin(Stdio::null())
+                .spawn()?;
+            children.push(child);
+        }
 
-        Some(child)
-    };
+        if args.penalty_box_sim {
+            child_args.push("--penalty-box");
+            if config.hardware.white_on_right {
+                child_args.push("--white-on-right");
+            }
+            debug!("Penalty box child args: {child_args:?}");
+
+            info!("Starting penalty box child with json port {json_port}");
+            let child = Command::new(&bin_name)
+                .args(&child_args)
+                .stdin(Stdio::null())
+                .spawn()?;
+            children.push(child);
+        }
+    }
 
     let serial_ports = if let Some(port) = args.serial_port {
         info!(
@@ -262,48 +358,6 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
         vec![]
     };
 
-    let config_path = confy::get_configuration_file_path(APP_NAME, None).unwrap();
-    info!("Reading config file from {config_path:?}",);
-
-    let mut config: Config = match confy::load(APP_NAME, None) {
-        Ok(c) => c,
-        Err(e) => {
-            warn!("Failed to use config file. Error: {e}");
-            let config = match std::fs::read_to_string(config_path) {
-                Ok(file) => {
-                    warn!("Found old config file, attempting migration");
-                    match toml::from_str(&file) {
-                        Ok(old_config) => Config::migrate(&old_config),
-                        Err(e) => {
-                            warn!("Failed to parse old config file. Error: {e}");
-                            warn!("Using default config");
-                            Config::default()
-                        }
-                    }
-                }
-                Err(e) => {
-                    warn!("Failed to read old config file. Error: {e}");
-                    warn!("Using default config");
-                    Config::default()
-                }
-            };
-            confy::store(APP_NAME, None, &config).unwrap();
-            config
-        }
-    };
-
-    if let Ok(offset) = time::UtcOffset::current_local_offset() {
-        if offset != config.uwhscores.timezone {
-            warn!(
-                "The timezone in the config file ({}) does not match the detected system \
-                 timezone ({offset}). The system timezone will be used.",
-                config.uwhscores.timezone
-            );
-        }
-        config.uwhscores.timezone = offset;
-        confy::store(APP_NAME, None, &config).unwrap();
-    }
-
     let window_size = (
         config.hardware.screen_x as u32,
         config.hardware.screen_y as u32,
@@ -313,16 +367,22 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
         config,
         serial_ports,
         binary_port: args.binary_port,
+        binary_v2_port: args.binary_v2_port,
         json_port: args.json_port,
-        sim_child: child,
+        sim_children: children,
         require_https: !args.allow_http,
         fullscreen: args.fullscreen,
         list_all_tournaments: args.all_tournaments,
+        buttons: sound_controller::ButtonOptions {
+            replay: args.remote_replay,
+            record: args.remote_record,
+        },
     };
 
     let mut settings = Settings::with_flags(flags);
     settings.window.size = window_size;
-    settings.window.resizable = false;
+    settings.window.resizable = true;
+    settings.window.min_size = Some(app::style::MIN_WINDOW_SIZE);
     settings.window.icon = Some(icon);
     settings.default_text_size = app::style::SMALL_PLUS_TEXT;
     settings.default_font = Font {
@@ -3,6 +3,7 @@ use crate::tournament_manager::{
     penalty::{Penalty, PenaltyKind},
     BlackWhiteBundle, OptColorBundle, TournamentManager, TournamentManagerError,
 };
+use crate::{i18n::tr, infractions};
 use std::{
     fmt::Debug,
     mem,
@@ -744,6 +745,7 @@ fn generate_printable_infraction_list<C: ColorIndex>(
 where
     InfractionDetails: Editable<C, Number = Option<u8>>,
 {
+    let catalog = infractions::catalog();
     items
         .iter()
         .map(|pen| {
@@ -769,7 +771,7 @@ where
                     } else {
                         "T".to_string()
                     };
-                    format!("{who} - {}", infraction.short_name())
+                    format!("{who} - {}", tr(&catalog.short_name(infraction)))
                 }
                 InfractionPrintMode::Foul => {
                     let who = if let Some(p_num) = p_num {
@@ -777,7 +779,7 @@ where
                     } else {
                         "".to_string()
                     };
-                    format!("{who}{}", infraction.short_name())
+                    format!("{who}{}", tr(&catalog.short_name(infraction)))
                 }
             };
 
@@ -14,12 +14,14 @@ use iced_futures::{
 };
 use iced_runtime::{command, window};
 use log::*;
-use matrix_drawing::{draw_panels, transmitted_data::TransmittedData};
+use matrix_drawing::{draw_panels, draw_penalty_box, transmitted_data::TransmittedData};
 use std::{rc::Rc, sync::Mutex};
 use tokio::{
+    io::{AsyncBufReadExt, BufReader, Lines},
     net::TcpStream,
     time::{self, Duration},
 };
+use uwh_common::game_snapshot::GameSnapshot;
 
 mod display_simulator;
 use display_simulator::*;
@@ -37,6 +39,7 @@ pub fn window_size(scale: f32, spacing: f32) -> (u32, u32) {
 #[derive(Clone, Debug)]
 pub enum Message {
     NewSnapshot(TransmittedData),
+    NewFullSnapshot(Box<GameSnapshot>),
     Stop,
     NoAction,
 }
@@ -45,12 +48,19 @@ pub enum Message {
 pub struct SimRefBoxApp {
     buffer: Rc<Mutex<DisplayBuffer<WIDTH, HEIGHT>>>,
     cache: Cache,
-    listener: SnapshotListener,
+    tcp_port: u16,
+    penalty_box: bool,
+    white_on_right: bool,
 }
 
 #[derive(Clone, Debug)]
 pub struct SimRefBoxAppFlags {
+    /// The binary port, or the JSON port if `penalty_box` is set
     pub tcp_port: u16,
+    /// Simulate the penalty box display instead of the main panels
+    pub penalty_box: bool,
+    /// Which side the white team is on in the penalty box display
+    pub white_on_right: bool,
 }
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -83,24 +93,42 @@ impl Application for SimRefBoxApp {
     type Flags = SimRefBoxAppFlags;
 
     fn new(flags: Self::Flags) -> (Self, Command<Message>) {
-        let Self::Flags { tcp_port } = flags;
+        let Self::Flags {
+            tcp_port,
+            penalty_box,
+            white_on_right,
+        } = flags;
 
         (
             Self {
                 buffer: Rc::new(Mutex::new(Default::default())),
                 cache: Cache::new(),
-                listener: SnapshotListener { port: tcp_port },
+                tcp_port,
+                penalty_box,
+                white_on_right,
             },
             Command::none(),
         )
     }
 
     fn subscription(&self) -> Subscription<Message> {
-        Subscription::from_recipe(self.listener.clone())
+        if self.penalty_box {
+            Subscription::from_recipe(FullSnapshotListener {
+                port: self.tcp_port,
+            })
+        } else {
+            Subscription::from_recipe(SnapshotListener {
+                port: self.tcp_port,
+            })
+        }
     }
 
     fn title(&self) -> String {
-        "Panel Simulator".into()
+        if self.penalty_box {
+            "Penalty Box Simulator".into()
+        } else {
+            "Panel Simulator".into()
+        }
     }
 
     fn update(&mut self, message: Message) -> Command<Message> {
@@ -113,6 +141,13 @@ impl Application for SimRefBoxApp {
                 self.cache.clear();
                 Command::none()
             }
+            Message::NewFullSnapshot(snapshot) => {
+                let mut buffer = self.buffer.lock().unwrap();
+                buffer.clear_buffer();
+                draw_penalty_box(&mut *buffer, &snapshot, self.white_on_right).unwrap();
+                self.cache.clear();
+                Command::none()
+            }
             Message::Stop => Command::single(command::Action::Window(window::Action::Close)),
             Message::NoAction => Command::none(),
         }
@@ -229,10 +264,10 @@ impl Recipe for SnapshotListener {
             }
 
             // Make the buffer longer than needed so that we can detect messages that are too long
-            let mut buffer = [0u8; TransmittedData::ENCODED_LEN + 1];
+            let mut buffer = [0u8; TransmittedData::ENCODED_LEN_V2 + 1];
 
             match state.stream.as_mut().unwrap().read(&mut buffer).await {
-                Ok(val) if val == TransmittedData::ENCODED_LEN => {}
+                Ok(val) if val == TransmittedData::ENCODED_LEN_V2 => {}
                 Ok(0) => {
                     error!("Sim: TCP connection closed, stopping");
                     state.stop = true;
@@ -250,10 +285,10 @@ impl Recipe for SnapshotListener {
                 }
             }
 
-            let data = match TransmittedData::decode(array_ref![
+            let data = match TransmittedData::decode_v2(array_ref![
                 buffer,
                 0,
-                TransmittedData::ENCODED_LEN
+                TransmittedData::ENCODED_LEN_V2
             ]) {
                 Ok(val) => val,
                 Err(e) => {
@@ -266,3 +301,86 @@ impl Recipe for SnapshotListener {
         }))
     }
 }
+
+/// Listens to the JSON stream, which has the full penalty lists
+#[derive(Clone, Debug)]
+struct FullSnapshotListener {
+    port: u16,
+}
+
+impl Recipe for FullSnapshotListener {
+    type Output = Message;
+
+    fn hash(&self, state: &mut iced_core::Hasher) {
+        use std::hash::Hash;
+
+        "FullSnapshotListener".hash(state);
+    }
+
+    fn stream(self: Box<Self>, _input: EventStream) -> BoxStream<'static, Self::Output> {
+        info!("Sim: starting JSON listener");
+
+        #[derive(Debug)]
+        struct State {
+            lines: Option<Lines<BufReader<TcpStream>>>,
+            stop: bool,
+            fail_count: u8,
+        }
+
+        let state = State {
+            lines: None,
+            stop: false,
+            fail_count: 0,
+        };
+
+        let port = self.port;
+
+        Box::pin(stream::unfold(state, move |mut state| async move {
+            if state.stop {
+                let pend: Pending<()> = pending();
+                // Won't ever return
+                pend.await;
+            }
+
+            if state.lines.is_none() {
+                match TcpStream::connect(("localhost", port)).await {
+                    Ok(conn) => state.lines = Some(BufReader::new(conn).lines()),
+                    Err(e) => {
+                        warn!("Sim: Failed to connect to refbox: {e:?}");
+                        state.fail_count += 1;
+                        time::sleep(Duration::from_millis(500)).await;
+                        if state.fail_count > 20 {
+                            state.stop = true;
+                            error!("Failed to connect to refbox too many times. Quitting");
+                            return Some((Message::Stop, state));
+                        }
+                        return Some((Message::NoAction, state));
+                    }
+                };
+            }
+
+            let line = match state.lines.as_mut().unwrap().next_line().await {
+                Ok(Some(line)) => line,
+                Ok(None) => {
+                    error!("Sim: TCP connection closed, stopping");
+                    state.stop = true;
+                    return Some((Message::Stop, state));
+                }
+                Err(e) => {
+                    error!("Sim: TCP error: {e:?}");
+                    error!("Sim: Stopping");
+                    state.stop = true;
+                    return Some((Message::Stop, state));
+                }
+            };
+
+            match serde_json::from_str(&line) {
+                Ok(snapshot) => Some((Message::NewFullSnapshot(Box::new(snapshot)), state)),
+                Err(e) => {
+                    warn!("Sim: Decoding error: {e:?}");
+                    Some((Message::NoAction, state))
+                }
+            }
+        }))
+    }
+}
@@ -0,0 +1,306 @@
+use log::*;
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use thiserror::Error;
+use tokio::{
+    sync::mpsc::UnboundedSender,
+    task::{self, JoinHandle},
+    time::{sleep_until, Instant},
+};
+
+#[cfg(target_os = "linux")]
+const ANTENNA_PIN: u8 = 16;
+#[cfg(target_os = "linux")]
+const WIRED_PIN: u8 = 12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Low,
+    High,
+}
+
+#[cfg(target_os = "linux")]
+impl From<rppal::gpio::Level> for Level {
+    fn from(level: rppal::gpio::Level) -> Self {
+        match level {
+            rppal::gpio::Level::Low => Self::Low,
+            rppal::gpio::Level::High => Self::High,
+        }
+    }
+}
+
+/// A change of the antenna pin's level, with the time at which it happened. The
+/// time is relative to an arbitrary starting point chosen by the source.
+pub type Edge = (Level, Duration);
+
+/// Where the `SoundController` should get button and remote signals from
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ButtonOptions {
+    /// Replay antenna edges from a capture file instead of using the GPIO pins
+    pub replay: Option<PathBuf>,
+    /// Record all antenna edges to a capture file
+    pub record: Option<PathBuf>,
+}
+
+/// A source of wired button and wireless antenna signals. The source must be kept
+/// alive for as long as the signals are needed.
+pub trait ButtonInputs: Send {
+    /// Start sending level changes on the provided channels. Returns the current
+    /// level of the antenna and the time it was read at.
+    fn start(
+        &mut self,
+        antenna_tx: UnboundedSender<Edge>,
+        wired_tx: UnboundedSender<Level>,
+    ) -> Result<Edge>;
+}
+
+/// Opens the button inputs described by `options`. Returns `None` if no inputs
+/// are available on the current system.
+pub fn open_inputs(options: &ButtonOptions) -> Option<Box<dyn ButtonInputs>> {
+    if let Some(ref path) = options.replay {
+        return match ReplayInputs::new(path) {
+            Ok(inputs) => {
+                info!("Replaying remote signals from {path:?}");
+                Some(Box::new(inputs))
+            }
+            Err(e) => {
+                error!("Failed to load remote capture {path:?}: {e}");
+                None
+            }
+        };
+    }
+
+    #[cfg(target_os = "linux")]
+    if let Some(inputs) = RppalInputs::new() {
+        return Some(Box::new(inputs));
+    }
+
+    None
+}
+
+#[cfg(target_os = "linux")]
+pub struct RppalInputs {
+    gpio: rppal::gpio::Gpio,
+    pins: Vec<rppal::gpio::InputPin>,
+}
+
+#[cfg(target_os = "linux")]
+impl RppalInputs {
+    /// Returns `None` if the current system is not a Raspberry Pi
+    pub fn new() -> Option<Self> {
+        let sys_info = rppal::system::DeviceInfo::new().ok()?;
+        info!("Detected a Raspberry Pi system: {sys_info:?}, starting GPIO processes");
+
+        Some(Self {
+            gpio: rppal::gpio::Gpio::new().unwrap(),
+            pins: vec![],
+        })
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl ButtonInputs for RppalInputs {
+    fn start(
+        &mut self,
+        antenna_tx: UnboundedSender<Edge>,
+        wired_tx: UnboundedSender<Level>,
+    ) -> Result<Edge> {
+        use rppal::gpio::Trigger;
+
+        let start = Instant::now();
+
+        let mut ant_pin = self.gpio.get(ANTENNA_PIN)?.into_input_pullup();
+        ant_pin.set_async_interrupt(Trigger::Both, move |level| {
+            antenna_tx
+                .send((level.into(), Instant::now().duration_since(start)))
+                .unwrap()
+        })?;
+
+        let mut wired_pin = self.gpio.get(WIRED_PIN)?.into_input_pullup();
+        wired_pin.set_async_interrupt(Trigger::Both, move |level| {
+            wired_tx.send(level.into()).unwrap()
+        })?;
+
+        let ant_start_state = (ant_pin.read().into(), Instant::now().duration_since(start));
+
+        self.pins.push(wired_pin);
+        self.pins.push(ant_pin);
+
+        Ok(ant_start_state)
+    }
+}
+
+/// Replays the antenna edges from a capture file in real time. The wired button is
+/// never pressed.
+pub struct ReplayInputs {
+    edges: Vec<Edge>,
+    channels: Option<(UnboundedSender<Edge>, UnboundedSender<Level>)>,
+    task: Option<JoinHandle<()>>,
+}
+
+impl ReplayInputs {
+    pub fn new(path: &Path) -> Result<Self> {
+        let edges = parse_capture(&std::fs::read_to_string(path)?)?;
+        if edges.is_empty() {
+            return Err(GpioError::EmptyCapture);
+        }
+
+        Ok(Self {
+            edges,
+            channels: None,
+            task: None,
+        })
+    }
+}
+
+impl ButtonInputs for ReplayInputs {
+    fn start(
+        &mut self,
+        antenna_tx: UnboundedSender<Edge>,
+        wired_tx: UnboundedSender<Level>,
+    ) -> Result<Edge> {
+        let start_edge = self.edges[0];
+        let edges = self.edges[1..].to_vec();
+        let _antenna_tx = antenna_tx.clone();
+
+        self.task = Some(task::spawn(async move {
+            let start = Instant::now();
+            for edge in edges {
+                sleep_until(start + edge.1.saturating_sub(start_edge.1)).await;
+                if _antenna_tx.send(edge).is_err() {
+                    break;
+                }
+            }
+            info!("Finished replaying remote capture");
+        }));
+
+        // Keep the channels open after the replay is done
+        self.channels = Some((antenna_tx, wired_tx));
+
+        Ok(start_edge)
+    }
+}
+
+impl Drop for ReplayInputs {
+    fn drop(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
+}
+
+/// Writes antenna edges to a capture file that can later be replayed
+pub struct CaptureRecorder {
+    writer: BufWriter<File>,
+}
+
+impl CaptureRecorder {
+    pub fn new(path: &Path) -> Result<Self> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writeln!(
+            writer,
+            "# Antenna capture, one `<microseconds> <level>` edge per line"
+        )?;
+        Ok(Self { writer })
+    }
+
+    pub fn record(&mut self, (level, time): Edge) {
+        let level = match level {
+            Level::Low => 0,
+            Level::High => 1,
+        };
+        if let Err(e) = writeln!(self.writer, "{} {level}", time.as_micros()) {
+            warn!("Failed to record antenna edge: {e}");
+        }
+    }
+
+    pub fn flush(&mut self) {
+        if let Err(e) = self.writer.flush() {
+            warn!("Failed to flush antenna capture: {e}");
+        }
+    }
+}
+
+impl Drop for CaptureRecorder {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+/// Parses a capture file. Each non-empty line that doesn't start with `#` has the
+/// time of the edge in microseconds followed by the new level (`0` or `1`). The
+/// first entry is the level of the antenna when the capture started.
+pub fn parse_capture(capture: &str) -> Result<Vec<Edge>> {
+    capture
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(line_num, line)| {
+            let mut parts = line.split_whitespace();
+            let time = parts
+                .next()
+                .and_then(|t| t.parse().ok())
+                .ok_or(GpioError::InvalidCaptureLine(line_num))?;
+            let level = match parts.next() {
+                Some("0") => Level::Low,
+                Some("1") => Level::High,
+                _ => return Err(GpioError::InvalidCaptureLine(line_num)),
+            };
+            if parts.next().is_some() {
+                return Err(GpioError::InvalidCaptureLine(line_num));
+            }
+            Ok((level, Duration::from_micros(time)))
+        })
+        .collect()
+}
+
+#[derive(Debug, Error)]
+pub enum GpioError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("Line {0} of the capture is invalid")]
+    InvalidCaptureLine(usize),
+    #[error("The capture is empty")]
+    EmptyCapture,
+    #[cfg(target_os = "linux")]
+    #[error(transparent)]
+    Rppal(#[from] rppal::gpio::Error),
+}
+
+pub type Result<T> = std::result::Result<T, GpioError>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_capture() {
+        let capture = "# A comment\n\n0 0\n  350 1\n10850 0 \n";
+        assert_eq!(
+            parse_capture(capture).unwrap(),
+            vec![
+                (Level::Low, Duration::ZERO),
+                (Level::High, Duration::from_micros(350)),
+                (Level::Low, Duration::from_micros(10850)),
+            ]
+        );
+
+        assert!(matches!(
+            parse_capture("0 0\n350 2\n"),
+            Err(GpioError::InvalidCaptureLine(2))
+        ));
+        assert!(matches!(
+            parse_capture("0 0\nabc 1\n"),
+            Err(GpioError::InvalidCaptureLine(2))
+        ));
+        assert!(matches!(
+            parse_capture("# Header\n0 0 1\n"),
+            Err(GpioError::InvalidCaptureLine(2))
+        ));
+    }
+}
@@ -1,6 +1,3 @@
-#[cfg(target_os = "linux")]
-use arrayref::array_ref;
-#[cfg(target_os = "linux")]
 use core::{
     future::Future,
     pin::Pin,
@@ -8,28 +5,21 @@ use core::{
 };
 use derivative::Derivative;
 use enum_derive_2018::{EnumDisplay, EnumFromStr};
-#[cfg(target_os = "linux")]
 use futures_lite::future::FutureExt;
 use log::*;
 use macro_attr_2018::macro_attr;
-#[cfg(target_os = "linux")]
-use rppal::gpio::{Gpio, InputPin, Level, Trigger};
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
-#[cfg(target_os = "linux")]
-use tokio::{
-    sync::watch::Receiver,
-    time::{sleep_until, Instant},
-};
+use std::{collections::BTreeMap, sync::Arc};
 use tokio::{
     sync::{
         mpsc::{unbounded_channel, UnboundedSender},
-        watch::{self, Sender},
+        watch::{self, Receiver, Sender},
     },
     task::{self, JoinHandle},
-    time::{sleep, Duration},
+    time::{sleep, sleep_until, Duration, Instant},
 };
 use toml::Table;
+use uwh_common::game_snapshot::Color;
 use web_audio_api::{
     context::{AudioContext, AudioContextOptions, BaseAudioContext},
     node::{
@@ -44,17 +34,14 @@ const FADE_WAIT: Duration = Duration::from_millis(50); // TODO: base this on `FA
 
 const SOUND_LEN: f64 = 2.0;
 
-#[cfg(target_os = "linux")]
-const MESSAGE_LEN: usize = 24;
-#[cfg(target_os = "linux")]
-const ID_LEN: usize = 20;
-#[cfg(target_os = "linux")]
-const DATA_LEN: usize = MESSAGE_LEN - ID_LEN;
-
-#[cfg(target_os = "linux")]
 const BUTTON_TIMEOUT: Duration = Duration::from_millis(500);
 
+mod gpio;
+mod remote_decoder;
 mod sounds;
+pub use gpio::ButtonOptions;
+use gpio::{open_inputs, ButtonInputs, CaptureRecorder, Level};
+use remote_decoder::RemoteDecoder;
 pub use sounds::*;
 
 use crate::app::update_sender::ServerMessage;
@@ -76,6 +63,15 @@ pub struct SoundSettings {
     #[derivative(Default(value = "true"))]
     pub auto_sound_stop_play: bool,
     pub remotes: Vec<RemoteInfo>,
+    /// Sound played above water when black scores, `None` for no sound
+    pub black_goal_sound: Option<BuzzerSound>,
+    /// Sound played above water when white scores, `None` for no sound
+    pub white_goal_sound: Option<BuzzerSound>,
+    /// Goal sounds for specific teams, keyed by the team name from the schedule. These
+    /// take priority over the per color settings.
+    pub team_goal_sounds: BTreeMap<String, BuzzerSound>,
+    /// Sound played above water when a penalty runs out, `None` for no sound
+    pub penalty_expiry_sound: Option<BuzzerSound>,
 }
 
 impl SoundSettings {
@@ -90,6 +86,10 @@ impl SoundSettings {
             mut auto_sound_start_play,
             mut auto_sound_stop_play,
             mut remotes,
+            mut black_goal_sound,
+            mut white_goal_sound,
+            mut team_goal_sounds,
+            mut penalty_expiry_sound,
         } = Default::default();
 
         if let Some(old_sound_enabled) = old.get("sound_enabled") {
@@ -156,6 +156,36 @@ impl SoundSettings {
                     .collect();
             }
         }
+        if let Some(old_black_goal_sound) = old.get("black_goal_sound") {
+            if let Some(old_black_goal_sound) = old_black_goal_sound.as_str() {
+                if let Ok(sound) = old_black_goal_sound.parse() {
+                    black_goal_sound = Some(sound);
+                }
+            }
+        }
+        if let Some(old_white_goal_sound) = old.get("white_goal_sound") {
+            if let Some(old_white_goal_sound) = old_white_goal_sound.as_str() {
+                if let Ok(sound) = old_white_goal_sound.parse() {
+                    white_goal_sound = Some(sound);
+                }
+            }
+        }
+        if let Some(old_team_goal_sounds) = old.get("team_goal_sounds") {
+            if let Some(old_team_goal_sounds) = old_team_goal_sounds.as_table() {
+                team_goal_sounds = old_team_goal_sounds
+                    .iter()
+                    .filter_map(|(team, sound)| Some((team.clone(), sound.as_str()?.parse().ok()?)))
+                    .collect();
+            }
+        }
+
+        if let Some(old_penalty_expiry_sound) = old.get("penalty_expiry_sound") {
+            if let Some(old_penalty_expiry_sound) = old_penalty_expiry_sound.as_str() {
+                if let Ok(sound) = old_penalty_expiry_sound.parse() {
+                    penalty_expiry_sound = Some(sound);
+                }
+            }
+        }
 
         Self {
             sound_enabled,
@@ -167,6 +197,22 @@ impl SoundSettings {
             auto_sound_start_play,
             auto_sound_stop_play,
             remotes,
+            black_goal_sound,
+            white_goal_sound,
+            team_goal_sounds,
+            penalty_expiry_sound,
+        }
+    }
+
+    /// Returns the sound to play when `color` scores. A sound set for `team_name`
+    /// overrides the sound set for the color.
+    pub fn goal_sound(&self, color: Color, team_name: Option<&str>) -> Option<BuzzerSound> {
+        if let Some(sound) = team_name.and_then(|name| self.team_goal_sounds.get(name)) {
+            return Some(*sound);
+        }
+        match color {
+            Color::Black => self.black_goal_sound,
+            Color::White => self.white_goal_sound,
         }
     }
 }
@@ -206,10 +252,43 @@ pub struct RemoteInfo {
 enum SoundMessage {
     TriggerBuzzer,
     TriggerWhistle,
-    #[cfg(target_os = "linux")]
     StartBuzzer(Option<BuzzerSound>),
-    #[cfg(target_os = "linux")]
     StopBuzzer,
+    TriggerGoal(BuzzerSound),
+    TriggerPenaltyExpiry(BuzzerSound),
+}
+
+/// How a goal or penalty expiry sound has to wait for the buzzer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BuzzerWait {
+    /// The sound can play straight away
+    None,
+    /// The buzzer is held, so the sound is not played
+    Held,
+    /// The auto-triggered buzzer is still playing, so the sound is played once it ends
+    Playing,
+}
+
+impl BuzzerWait {
+    fn new(
+        msg: &SoundMessage,
+        buzzer_held: bool,
+        buzzer_end: Option<Instant>,
+        now: Instant,
+    ) -> Self {
+        if !matches!(
+            msg,
+            SoundMessage::TriggerGoal(_) | SoundMessage::TriggerPenaltyExpiry(_)
+        ) {
+            Self::None
+        } else if buzzer_held {
+            Self::Held
+        } else if buzzer_end.is_some_and(|end| end > now) {
+            Self::Playing
+        } else {
+            Self::None
+        }
+    }
 }
 
 pub struct SoundController {
@@ -218,15 +297,12 @@ pub struct SoundController {
     settings_tx: Sender<SoundSettings>,
     stop_tx: Sender<bool>,
     tasks: Vec<JoinHandle<()>>,
-    #[cfg(target_os = "linux")]
     remote_id_rx: Option<Receiver<u32>>,
-    #[cfg(target_os = "linux")]
-    _pins: Option<(InputPin, InputPin)>,
+    _inputs: Option<Box<dyn ButtonInputs>>,
 }
 
 impl SoundController {
-    #[cfg_attr(not(target_os = "linux"), allow(unused_mut))]
-    pub fn new<F>(mut settings: SoundSettings, trigger_flash: F) -> Self
+    pub fn new<F>(mut settings: SoundSettings, buttons: ButtonOptions, trigger_flash: F) -> Self
     where
         F: Send
             + Fn() -> Result<(), tokio::sync::mpsc::error::TrySendError<ServerMessage>>
@@ -251,22 +327,57 @@ impl SoundController {
 
         let mut _stop_rx = stop_rx.clone();
         let mut _settings_rx = settings_rx.clone();
-        #[cfg_attr(not(target_os = "linux"), allow(clippy::redundant_clone))]
         let mut _settings = settings.clone();
         let _context = context.clone();
 
         let handler = task::spawn(async move {
-            #[cfg_attr(not(target_os = "linux"), allow(unused_assignments))]
             let mut last_sound: Option<Sound> = None;
+            let mut buzzer_held = false;
+            // When the auto-triggered buzzer finishes, and the goal or expiry sound waiting for it
+            let mut buzzer_end: Option<Instant> = None;
+            let mut queued_sound: Option<SoundMessage> = None;
+
+            let above_water_sound = |settings: &SoundSettings, msg: SoundMessage| {
+                let (sound, repeat) = match msg {
+                    SoundMessage::TriggerGoal(sound) => {
+                        info!("Playing goal sound");
+                        (sound, true)
+                    }
+                    SoundMessage::TriggerPenaltyExpiry(sound) => {
+                        info!("Playing penalty expiry sound");
+                        (sound, false)
+                    }
+                    _ => unreachable!(),
+                };
+                let volumes = ChannelVolumes::above_water_only(settings);
+                Sound::new(
+                    _context.clone(),
+                    volumes,
+                    library[sound].clone(),
+                    repeat,
+                    true,
+                )
+            };
 
             loop {
                 tokio::select! {
                     msg = msg_rx.recv() => {
                         match msg {
+                            Some(msg) if BuzzerWait::new(&msg, buzzer_held, buzzer_end, Instant::now()) == BuzzerWait::Held => {
+                                info!("Not playing {msg:?} while the buzzer is held");
+                            }
+                            Some(msg) if BuzzerWait::new(&msg, buzzer_held, buzzer_end, Instant::now()) == BuzzerWait::Playing => {
+                                info!("Queueing {msg:?} until the buzzer ends");
+                                queued_sound = Some(msg);
+                            }
                             Some(msg) => {
                                 if let Some(sound) = last_sound.take() {
                                     sound.stop().await;
                                 }
+                                buzzer_end = None;
+                                if let Some(queued) = queued_sound.take() {
+                                    info!("Dropping queued {queued:?}");
+                                }
 
                                 match msg {
                                     SoundMessage::TriggerBuzzer => {
@@ -275,6 +386,7 @@ impl SoundController {
                                         let sound = Sound::new(_context.clone(), volumes, library[_settings.buzzer_sound].clone(), true, true);
                                         trigger_flash().unwrap();
                                         last_sound = Some(sound);
+                                        buzzer_end = Some(Instant::now() + Duration::from_secs_f64(SOUND_LEN + 2.0 * FADE_LEN));
                                     }
                                     SoundMessage::TriggerWhistle => {
                                         info!("Playing whistle once");
@@ -282,7 +394,6 @@ impl SoundController {
                                         let sound = Sound::new(_context.clone(), volumes, library.whistle().clone(), false, false);
                                         last_sound = Some(sound);
                                     }
-                                    #[cfg(target_os = "linux")]
                                     SoundMessage::StartBuzzer(sound_option) => {
                                         info!("Starting buzzer");
                                         let buzzer_sound = sound_option.unwrap_or(_settings.buzzer_sound);
@@ -290,16 +401,27 @@ impl SoundController {
                                         let sound = Sound::new(_context.clone(), volumes, library[buzzer_sound].clone(), true, false);
                                         trigger_flash().unwrap();
                                         last_sound = Some(sound);
+                                        buzzer_held = true;
                                     }
-                                    #[cfg(target_os = "linux")]
                                     SoundMessage::StopBuzzer => {
                                         info!("Stopped buzzer");
+                                        buzzer_held = false;
+                                    }
+                                    SoundMessage::TriggerGoal(_) | SoundMessage::TriggerPenaltyExpiry(_) => {
+                                        last_sound = Some(above_water_sound(&_settings, msg));
                                     }
                                 }
                             },
                             None => break,
                         }
                     }
+                    _ = sleep_until(buzzer_end.unwrap_or_else(Instant::now)), if queued_sound.is_some() => {
+                        if let Some(sound) = last_sound.take() {
+                            sound.stop().await;
+                        }
+                        buzzer_end = None;
+                        last_sound = queued_sound.take().map(|msg| above_water_sound(&_settings, msg));
+                    }
                     maybe_err = _settings_rx.changed() => {
                         match maybe_err {
                             Ok(()) => {
@@ -315,30 +437,38 @@ impl SoundController {
             }
         });
 
-        #[cfg_attr(not(target_os = "linux"), allow(unused_mut))]
         let mut tasks = vec![handler];
 
-        #[cfg(target_os = "linux")]
-        let (_pins, remote_id_rx) = if let Ok(sys_info) = rppal::system::DeviceInfo::new() {
-            info!("Detected a Raspberry Pi system: {sys_info:?}, starting GPIO processes");
-
-            let gpio = Gpio::new().unwrap();
-
-            let mut ant_pin = gpio.get(16).unwrap().into_input_pullup();
-            let (ant_tx, mut ant_rx) = unbounded_channel();
-            ant_pin
-                .set_async_interrupt(Trigger::Both, move |level| {
-                    ant_tx.send((level, Instant::now())).unwrap()
-                })
-                .unwrap();
-
-            let mut wired_pin = gpio.get(12).unwrap().into_input_pullup();
-            let (wired_tx, mut wired_rx) = unbounded_channel();
-            wired_pin
-                .set_async_interrupt(Trigger::Both, move |level| wired_tx.send(level).unwrap())
-                .unwrap();
-
-            let ant_start_state = ant_pin.read();
+        let (ant_tx, mut ant_rx) = unbounded_channel();
+        let (wired_tx, mut wired_rx) = unbounded_channel();
+
+        let mut inputs = open_inputs(&buttons);
+        let ant_start_state =
+            inputs
+                .as_mut()
+                .and_then(|inputs| match inputs.start(ant_tx, wired_tx) {
+                    Ok(state) => Some(state),
+                    Err(e) => {
+                        error!("Failed to start button inputs: {e}");
+                        None
+                    }
+                });
+
+        let remote_id_rx = if let Some((start_level, start_time)) = ant_start_state {
+            let mut recorder = buttons
+                .record
+                .as_ref()
+                .and_then(|path| match CaptureRecorder::new(path) {
+                    Ok(mut recorder) => {
+                        info!("Recording antenna signals to {path:?}");
+                        recorder.record((start_level, start_time));
+                        Some(recorder)
+                    }
+                    Err(e) => {
+                        error!("Failed to record antenna signals to {path:?}: {e}");
+                        None
+                    }
+                });
 
             let (wireless_tx, mut wireless_rx) = unbounded_channel();
             let (remote_id_tx, mut remote_id_rx) = watch::channel(0);
@@ -347,100 +477,23 @@ impl SoundController {
             let mut _stop_rx = stop_rx.clone();
 
             let wireless_button_listener = task::spawn(async move {
-                let mut state = RemoteDetectorState {
-                    preamble_detected: false,
-                    bits: vec![],
-                    last_pin_state: ant_start_state,
-                    last_edge_time: Instant::now(),
-                    last_pulse: None,
-                };
+                let mut decoder = RemoteDecoder::new(start_level, start_time);
 
                 loop {
                     tokio::select! {
-                        pin_update = ant_rx.recv() => {
-                            match pin_update {
-                                Some((l @ Level::High, now)) => {
-                                    if state.last_pin_state != l {
-                                        let pulse = now.duration_since(state.last_edge_time).as_micros();
-                                        trace!("Detected LOW  pulse {pulse:>5}us long");
-                                        state.last_pin_state = l;
-                                        state.last_edge_time = now;
-
-                                        let maybe_pulse_type = identify_pulse(pulse);
-                                        debug!("Detected a LOW  pulse of length {maybe_pulse_type:?}");
-
-                                        if let Some(pulse_type) = maybe_pulse_type {
-                                            if !state.preamble_detected {
-                                                if matches!(state.last_pulse, Some(PulseType::Short))
-                                                    && pulse_type == PulseType::Preamble
-                                                {
-                                                    debug!("Detected a preamble");
-                                                    state.preamble_detected = true;
-                                                }
-                                            } else {
-                                                if matches!(state.last_pulse, Some(PulseType::Short))
-                                                    && pulse_type == PulseType::Long
-                                                {
-                                                    debug!("Detected a low bit");
-                                                    state.bits.push(false);
-                                                } else if matches!(state.last_pulse, Some(PulseType::Long))
-                                                    && pulse_type == PulseType::Short
-                                                {
-                                                    debug!("Detected a high bit");
-                                                    state.bits.push(true);
-                                                } else {
-                                                    info!("Detected an invalid pulse sequence");
-                                                    state.preamble_detected = false;
-                                                    state.bits.clear();
-                                                }
-
-                                                if state.bits.len() == MESSAGE_LEN {
-                                                    let message: String = state
-                                                        .bits
-                                                        .iter()
-                                                        .map(|bit| if *bit { '1' } else { '0' })
-                                                        .collect();
-                                                    debug!("Received a complete message: 0b{message}");
-
-                                                    let remote_id = state.bits[..ID_LEN]
-                                                        .iter()
-                                                        .fold(0, |acc, &b| acc * 2 + b as u32);
-                                                    let data = array_ref![state.bits, ID_LEN, DATA_LEN];
-
-                                                    debug!("Remote {remote_id} sent data {data:?}");
-                                                    wireless_tx.send(remote_id).unwrap();
-                                                    remote_id_tx.send(remote_id).unwrap();
-
-                                                    state.preamble_detected = false;
-                                                    state.bits.clear();
-                                                }
-                                            }
-                                        } else {
-                                            trace!("Detected an invalid pulse");
-                                            state.preamble_detected = false;
-                                            state.bits.clear();
-                                        }
-
-                                        state.last_pulse = maybe_pulse_type;
+                        edge = ant_rx.recv() => {
+                            match edge {
+                                Some(edge) => {
+                                    if let Some(ref mut recorder) = recorder {
+                                        recorder.record(edge);
                                     }
-                                }
-                                Some((l @ Level::Low, now)) => {
-                                    if state.last_pin_state != l {
-                                        let pulse = now.duration_since(state.last_edge_time).as_micros();
-                                        trace!("Detected HIGH pulse {pulse:>5}us long");
-                                        state.last_pin_state = l;
-                                        state.last_edge_time = now;
-
-                                        let maybe_pulse_type = identify_pulse(pulse);
-                                        debug!("Detected a LOW  pulse of length {maybe_pulse_type:?}");
-
-                                        if maybe_pulse_type.is_none() {
-                                            trace!("Detected an invalid pulse");
-                                            state.preamble_detected = false;
-                                            state.bits.clear();
+                                    if let Some(message) = decoder.process_edge(edge) {
+                                        wireless_tx.send(message.id).unwrap();
+                                        remote_id_tx.send(message.id).unwrap();
+                                        // Keep the capture up to date with each decoded press
+                                        if let Some(ref mut recorder) = recorder {
+                                            recorder.flush();
                                         }
-
-                                        state.last_pulse = maybe_pulse_type;
                                     }
                                 }
                                 None => panic!("The Pin has been dropped"),
@@ -451,6 +504,10 @@ impl SoundController {
                         }
                     }
                 }
+
+                if let Some(ref mut recorder) = recorder {
+                    recorder.flush();
+                }
             });
 
             tasks.push(wireless_button_listener);
@@ -530,9 +587,9 @@ impl SoundController {
 
             tasks.push(button_listener);
 
-            (Some((wired_pin, ant_pin)), Some(remote_id_rx))
+            Some(remote_id_rx)
         } else {
-            (None, None)
+            None
         };
 
         Self {
@@ -541,10 +598,8 @@ impl SoundController {
             settings_tx,
             stop_tx,
             tasks,
-            #[cfg(target_os = "linux")]
             remote_id_rx,
-            #[cfg(target_os = "linux")]
-            _pins,
+            _inputs: inputs,
         }
     }
 
@@ -560,15 +615,25 @@ impl SoundController {
         self.msg_tx.send(SoundMessage::TriggerBuzzer).unwrap()
     }
 
+    /// Plays `sound` on the above water speakers only
+    pub fn trigger_goal_sound(&self, sound: BuzzerSound) {
+        self.msg_tx.send(SoundMessage::TriggerGoal(sound)).unwrap()
+    }
+
+    /// Plays `sound` once on the above water speakers only
+    pub fn trigger_penalty_expiry_sound(&self, sound: BuzzerSound) {
+        self.msg_tx
+            .send(SoundMessage::TriggerPenaltyExpiry(sound))
+            .unwrap()
+    }
+
     /// Waits for a remote to be detected, then passes the id value to `callback`.
     /// If buttons are not available on the current system, `callback` will never
     /// be called.
-    #[cfg_attr(not(target_os = "linux"), allow(unused_variables))]
     pub fn request_next_remote_id<F>(&self, callback: F)
     where
         F: FnOnce(u32) + Send + 'static,
     {
-        #[cfg(target_os = "linux")]
         if let Some(mut rx) = self.remote_id_rx.clone() {
             rx.borrow_and_update();
             task::spawn(async move {
@@ -616,6 +681,17 @@ impl ChannelVolumes {
             },
         }
     }
+
+    fn above_water_only(settings: &SoundSettings) -> Self {
+        Self {
+            left: if settings.sound_enabled {
+                settings.above_water_vol.as_f32()
+            } else {
+                0.0
+            },
+            right: 0.0,
+        }
+    }
 }
 
 struct Sound {
@@ -712,48 +788,11 @@ impl Sound {
     }
 }
 
-#[cfg(target_os = "linux")]
-const fn identify_pulse(len: u128) -> Option<PulseType> {
-    const SHORT_PULSE_BOT_THRESH: u128 = 200;
-    const SHORT_PULSE_TOP_THRESH: u128 = 500;
-    const LONG_PULSE_BOT_THRESH: u128 = 800;
-    const LONG_PULSE_TOP_THRESH: u128 = 1500;
-    const PREAMBLE_PULSE_BOT_THRESH: u128 = 9000;
-    const PREAMBLE_PULSE_TOP_THRESH: u128 = 12000;
-
-    match len {
-        SHORT_PULSE_BOT_THRESH..=SHORT_PULSE_TOP_THRESH => Some(PulseType::Short),
-        LONG_PULSE_BOT_THRESH..=LONG_PULSE_TOP_THRESH => Some(PulseType::Long),
-        PREAMBLE_PULSE_BOT_THRESH..=PREAMBLE_PULSE_TOP_THRESH => Some(PulseType::Preamble),
-        _ => None,
-    }
-}
-
-#[cfg(target_os = "linux")]
-#[derive(Debug, PartialEq, Eq)]
-struct RemoteDetectorState {
-    preamble_detected: bool,
-    bits: Vec<bool>,
-    last_pin_state: Level,
-    last_edge_time: Instant,
-    last_pulse: Option<PulseType>,
-}
-
-#[cfg(target_os = "linux")]
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-enum PulseType {
-    Short,
-    Long,
-    Preamble,
-}
-
-#[cfg(target_os = "linux")]
 enum WirelessTimeout {
     Never(core::future::Pending<()>),
     Time(Pin<Box<tokio::time::Sleep>>),
 }
 
-#[cfg(target_os = "linux")]
 impl Future for WirelessTimeout {
     type Output = ();
 
@@ -775,6 +814,19 @@ mod tests {
         let serialized = toml::to_string(&settings).unwrap();
         let deser = toml::from_str(&serialized);
         assert_eq!(deser, Ok(settings));
+
+        let settings = SoundSettings {
+            black_goal_sound: Some(BuzzerSound::Whoop),
+            team_goal_sounds: BTreeMap::from([
+                ("Team A".to_string(), BuzzerSound::Crazy),
+                ("Team B".to_string(), BuzzerSound::TwoTone),
+            ]),
+            remotes: vec![RemoteInfo { id: 3, sound: None }],
+            ..Default::default()
+        };
+        let serialized = toml::to_string(&settings).unwrap();
+        let deser = toml::from_str(&serialized);
+        assert_eq!(deser, Ok(settings));
     }
 
     #[test]
@@ -865,6 +917,31 @@ mod tests {
                 ),
             ]),
         );
+        old.insert(
+            "black_goal_sound".to_string(),
+            toml::Value::String("Whoop".to_string()),
+        );
+        old.insert(
+            "team_goal_sounds".to_string(),
+            toml::Value::Table(
+                vec![
+                    (
+                        "Team A".to_string(),
+                        toml::Value::String("TwoTone".to_string()),
+                    ),
+                    (
+                        "Team B".to_string(),
+                        toml::Value::String("Invalid".to_string()),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+        );
+        old.insert(
+            "penalty_expiry_sound".to_string(),
+            toml::Value::String("Crazy".to_string()),
+        );
 
         let settings = SoundSettings::migrate(&old);
 
@@ -889,5 +966,79 @@ mod tests {
                 },
             ]
         );
+        assert_eq!(settings.black_goal_sound, Some(BuzzerSound::Whoop));
+        assert_eq!(settings.white_goal_sound, None);
+        assert_eq!(
+            settings.team_goal_sounds,
+            BTreeMap::from([("Team A".to_string(), BuzzerSound::TwoTone)])
+        );
+        assert_eq!(settings.penalty_expiry_sound, Some(BuzzerSound::Crazy));
+    }
+
+    #[test]
+    fn test_buzzer_wait() {
+        let now = Instant::now();
+        let buzzer_end = Some(now + Duration::from_secs(1));
+        let goal = SoundMessage::TriggerGoal(BuzzerSound::Buzz);
+        let expiry = SoundMessage::TriggerPenaltyExpiry(BuzzerSound::Crazy);
+
+        for msg in [&goal, &expiry] {
+            assert_eq!(BuzzerWait::new(msg, false, None, now), BuzzerWait::None);
+            assert_eq!(BuzzerWait::new(msg, true, None, now), BuzzerWait::Held);
+            assert_eq!(
+                BuzzerWait::new(msg, true, buzzer_end, now),
+                BuzzerWait::Held
+            );
+            assert_eq!(
+                BuzzerWait::new(msg, false, buzzer_end, now),
+                BuzzerWait::Playing
+            );
+            assert_eq!(
+                BuzzerWait::new(msg, false, buzzer_end, now + Duration::from_secs(2)),
+                BuzzerWait::None
+            );
+        }
+
+        assert_eq!(
+            BuzzerWait::new(&SoundMessage::TriggerWhistle, false, buzzer_end, now),
+            BuzzerWait::None
+        );
+        assert_eq!(
+            BuzzerWait::new(&SoundMessage::StopBuzzer, true, None, now),
+            BuzzerWait::None
+        );
+    }
+
+    #[test]
+    fn test_goal_sound() {
+        let mut settings = SoundSettings {
+            black_goal_sound: Some(BuzzerSound::Buzz),
+            team_goal_sounds: BTreeMap::from([("Team A".to_string(), BuzzerSound::Whoop)]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            settings.goal_sound(Color::Black, None),
+            Some(BuzzerSound::Buzz)
+        );
+        assert_eq!(settings.goal_sound(Color::White, None), None);
+        assert_eq!(
+            settings.goal_sound(Color::Black, Some("Team B")),
+            Some(BuzzerSound::Buzz)
+        );
+        assert_eq!(
+            settings.goal_sound(Color::White, Some("Team A")),
+            Some(BuzzerSound::Whoop)
+        );
+
+        settings.white_goal_sound = Some(BuzzerSound::DeDeDu);
+        assert_eq!(
+            settings.goal_sound(Color::White, Some("Team B")),
+            Some(BuzzerSound::DeDeDu)
+        );
+        assert_eq!(
+            settings.goal_sound(Color::Black, Some("Team A")),
+            Some(BuzzerSound::Whoop)
+        );
     }
 }
@@ -0,0 +1,342 @@
+use super::gpio::{Edge, Level};
+use arrayref::array_ref;
+use log::*;
+use std::time::Duration;
+
+pub const MESSAGE_LEN: usize = 24;
+pub const ID_LEN: usize = 20;
+pub const DATA_LEN: usize = MESSAGE_LEN - ID_LEN;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RemoteMessage {
+    pub id: u32,
+    pub data: [bool; DATA_LEN],
+}
+
+/// Decodes the pulses received from the wireless remotes. Each message starts with
+/// a short HIGH pulse followed by a very long LOW pulse (the preamble), then each
+/// bit is a pair of HIGH and LOW pulses: short-long for a `0` and long-short for a
+/// `1`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteDecoder {
+    preamble_detected: bool,
+    bits: Vec<bool>,
+    last_level: Level,
+    last_edge_time: Duration,
+    last_pulse: Option<PulseType>,
+}
+
+impl RemoteDecoder {
+    pub fn new(start_level: Level, start_time: Duration) -> Self {
+        Self {
+            preamble_detected: false,
+            bits: Vec::with_capacity(MESSAGE_LEN),
+            last_level: start_level,
+            last_edge_time: start_time,
+            last_pulse: None,
+        }
+    }
+
+    /// Processes a change of the antenna pin's level. Returns a message if this edge
+    /// completed one.
+    pub fn process_edge(&mut self, (level, time): Edge) -> Option<RemoteMessage> {
+        if self.last_level == level {
+            return None;
+        }
+
+        let pulse = time.saturating_sub(self.last_edge_time).as_micros();
+        self.last_level = level;
+        self.last_edge_time = time;
+
+        let maybe_pulse_type = identify_pulse(pulse);
+        let mut message = None;
+
+        match level {
+            Level::High => {
+                trace!("Detected LOW  pulse {pulse:>5}us long");
+                debug!("Detected a LOW  pulse of length {maybe_pulse_type:?}");
+
+                if let Some(pulse_type) = maybe_pulse_type {
+                    if !self.preamble_detected {
+                        if matches!(self.last_pulse, Some(PulseType::Short))
+                            && pulse_type == PulseType::Preamble
+                        {
+                            debug!("Detected a preamble");
+                            self.preamble_detected = true;
+                        }
+                    } else {
+                        if matches!(self.last_pulse, Some(PulseType::Short))
+                            && pulse_type == PulseType::Long
+                        {
+                            debug!("Detected a low bit");
+                            self.bits.push(false);
+                        } else if matches!(self.last_pulse, Some(PulseType::Long))
+                            && pulse_type == PulseType::Short
+                        {
+                            debug!("Detected a high bit");
+                            self.bits.push(true);
+                        } else {
+                            info!("Detected an invalid pulse sequence");
+                            self.reset();
+                        }
+
+                        if self.bits.len() == MESSAGE_LEN {
+                            message = Some(self.take_message());
+                        }
+                    }
+                } else {
+                    trace!("Detected an invalid pulse");
+                    self.reset();
+                }
+            }
+            Level::Low => {
+                trace!("Detected HIGH pulse {pulse:>5}us long");
+                debug!("Detected a HIGH pulse of length {maybe_pulse_type:?}");
+
+                if maybe_pulse_type.is_none() {
+                    trace!("Detected an invalid pulse");
+                    self.reset();
+                }
+            }
+        }
+
+        self.last_pulse = maybe_pulse_type;
+        message
+    }
+
+    fn reset(&mut self) {
+        self.preamble_detected = false;
+        self.bits.clear();
+    }
+
+    fn take_message(&mut self) -> RemoteMessage {
+        let message: String = self
+            .bits
+            .iter()
+            .map(|bit| if *bit { '1' } else { '0' })
+            .collect();
+        debug!("Received a complete message: 0b{message}");
+
+        let id = self.bits[..ID_LEN]
+            .iter()
+            .fold(0, |acc, &b| acc * 2 + b as u32);
+        let data = *array_ref![self.bits, ID_LEN, DATA_LEN];
+
+        debug!("Remote {id} sent data {data:?}");
+
+        self.reset();
+        RemoteMessage { id, data }
+    }
+}
+
+const fn identify_pulse(len: u128) -> Option<PulseType> {
+    const SHORT_PULSE_BOT_THRESH: u128 = 200;
+    const SHORT_PULSE_TOP_THRESH: u128 = 500;
+    const LONG_PULSE_BOT_THRESH: u128 = 800;
+    const LONG_PULSE_TOP_THRESH: u128 = 1500;
+    const PREAMBLE_PULSE_BOT_THRESH: u128 = 9000;
+    const PREAMBLE_PULSE_TOP_THRESH: u128 = 12000;
+
+    match len {
+        SHORT_PULSE_BOT_THRESH..=SHORT_PULSE_TOP_THRESH => Some(PulseType::Short),
+        LONG_PULSE_BOT_THRESH..=LONG_PULSE_TOP_THRESH => Some(PulseType::Long),
+        PREAMBLE_PULSE_BOT_THRESH..=PREAMBLE_PULSE_TOP_THRESH => Some(PulseType::Preamble),
+        _ => None,
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum PulseType {
+    Short,
+    Long,
+    Preamble,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::sound_controller::gpio::parse_capture;
+
+    fn decode(capture: &str) -> Vec<RemoteMessage> {
+        let edges = parse_capture(capture).unwrap();
+        let (start_level, start_time) = edges[0];
+        let mut decoder = RemoteDecoder::new(start_level, start_time);
+        edges[1..]
+            .iter()
+            .filter_map(|edge| decoder.process_edge(*edge))
+            .collect()
+    }
+
+    fn edges_for(id: u32, data: [bool; DATA_LEN]) -> Vec<(Level, u64)> {
+        let mut pulses = vec![(Level::High, 350), (Level::Low, 10_500)];
+        let bits = (0..ID_LEN)
+            .rev()
+            .map(|i| id & (1 << i) != 0)
+            .chain(data.iter().copied());
+        for bit in bits {
+            if bit {
+                pulses.extend([(Level::High, 1_050), (Level::Low, 350)]);
+            } else {
+                pulses.extend([(Level::High, 350), (Level::Low, 1_050)]);
+            }
+        }
+        pulses
+    }
+
+    fn run_pulses(pulses: &[(Level, u64)]) -> Vec<RemoteMessage> {
+        let mut time = 0;
+        let mut decoder = RemoteDecoder::new(Level::Low, Duration::ZERO);
+        let mut messages = vec![];
+        for (level, len) in pulses {
+            if let Some(msg) = decoder.process_edge((*level, Duration::from_micros(time))) {
+                messages.push(msg);
+            }
+            time += len;
+        }
+        // Close out the last pulse
+        let level = match pulses.last().unwrap().0 {
+            Level::High => Level::Low,
+            Level::Low => Level::High,
+        };
+        if let Some(msg) = decoder.process_edge((level, Duration::from_micros(time))) {
+            messages.push(msg);
+        }
+        messages
+    }
+
+    #[test]
+    fn test_identify_pulse() {
+        assert_eq!(identify_pulse(0), None);
+        assert_eq!(identify_pulse(199), None);
+        assert_eq!(identify_pulse(200), Some(PulseType::Short));
+        assert_eq!(identify_pulse(500), Some(PulseType::Short));
+        assert_eq!(identify_pulse(650), None);
+        assert_eq!(identify_pulse(800), Some(PulseType::Long));
+        assert_eq!(identify_pulse(1500), Some(PulseType::Long));
+        assert_eq!(identify_pulse(5000), None);
+        assert_eq!(identify_pulse(9000), Some(PulseType::Preamble));
+        assert_eq!(identify_pulse(12000), Some(PulseType::Preamble));
+        assert_eq!(identify_pulse(12001), None);
+    }
+
+    #[test]
+    fn test_decode_message() {
+        let data = [true, false, false, false];
+        let pulses = edges_for(0xA_5C3E, data);
+        assert_eq!(
+            run_pulses(&pulses),
+            vec![RemoteMessage { id: 0xA_5C3E, data }]
+        );
+
+        let data = [false, false, true, true];
+        let pulses = edges_for(1, data);
+        assert_eq!(run_pulses(&pulses), vec![RemoteMessage { id: 1, data }]);
+    }
+
+    #[test]
+    fn test_repeated_level_ignored() {
+        let data = [false, true, false, false];
+        let mut pulses = edges_for(0x1_2345, data);
+        pulses.insert(5, (Level::Low, 0));
+        pulses.insert(12, (Level::High, 0));
+        assert_eq!(
+            run_pulses(&pulses),
+            vec![RemoteMessage { id: 0x1_2345, data }]
+        );
+    }
+
+    #[test]
+    fn test_invalid_pulse_resets() {
+        let data = [false, false, false, true];
+        let mut pulses = edges_for(0xF_0F0F, data);
+        // Make a HIGH pulse in the middle of the message too long to be identified
+        pulses[20].1 = 2_000;
+        assert_eq!(run_pulses(&pulses), vec![]);
+
+        // A complete message afterwards is still decoded
+        pulses.extend(edges_for(0xF_0F0F, data));
+        assert_eq!(
+            run_pulses(&pulses),
+            vec![RemoteMessage { id: 0xF_0F0F, data }]
+        );
+    }
+
+    #[test]
+    fn test_invalid_sequence_resets() {
+        let data = [true, true, false, false];
+        let mut pulses = edges_for(0x8_0001, data);
+        // Two valid, but short, pulses don't make a bit
+        pulses[10].1 = 350;
+        pulses[11].1 = 350;
+        assert_eq!(run_pulses(&pulses), vec![]);
+    }
+
+    #[test]
+    fn test_missing_preamble() {
+        let data = [true, false, false, false];
+        let pulses = edges_for(0x3_3333, data);
+        assert_eq!(run_pulses(&pulses[2..]), vec![]);
+    }
+
+    #[test]
+    fn test_capture_single_press() {
+        let messages = decode(include_str!(
+            "../../resources/remote_captures/single_press.txt"
+        ));
+        assert_eq!(
+            messages,
+            vec![
+                RemoteMessage {
+                    id: 0x5_A3C1,
+                    data: [true, false, false, false],
+                };
+                4
+            ]
+        );
+    }
+
+    #[test]
+    fn test_capture_noisy_press() {
+        let messages = decode(include_str!(
+            "../../resources/remote_captures/noisy_press.txt"
+        ));
+        assert_eq!(
+            messages,
+            vec![
+                RemoteMessage {
+                    id: 0x5_A3C1,
+                    data: [false, true, false, false],
+                };
+                3
+            ]
+        );
+    }
+
+    #[test]
+    fn test_capture_two_remotes() {
+        let messages = decode(include_str!(
+            "../../resources/remote_captures/two_remotes.txt"
+        ));
+        assert_eq!(
+            messages,
+            vec![
+                RemoteMessage {
+                    id: 0x0_1B7F,
+                    data: [false, false, true, false],
+                },
+                RemoteMessage {
+                    id: 0x0_1B7F,
+                    data: [false, false, true, false],
+                },
+                RemoteMessage {
+                    id: 0xE_6D02,
+                    data: [false, false, false, true],
+                },
+                RemoteMessage {
+                    id: 0xE_6D02,
+                    data: [false, false, false, true],
+                },
+            ]
+        );
+    }
+}
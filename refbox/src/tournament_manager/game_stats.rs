@@ -37,20 +37,21 @@ impl GameStats {
         self.end_timestamp = Some(timestamp);
     }
 
+    pub(crate) fn remove_end_time(&mut self) {
+        self.end_timestamp = None;
+    }
+
     pub(crate) fn add_goal(
         &mut self,
         period: GamePeriod,
         time_left_in_period: Option<Duration>,
         color: Color,
-        player_num: u8,
+        player_num: Option<u8>,
         instant: Instant,
     ) {
         let event = Event::Goal {
             player_cap_number: player_num,
-            side: match color {
-                Color::Black => "dark".to_string(),
-                Color::White => "light".to_string(),
-            },
+            side: color_to_side(color),
             game_period: period,
             period_time: time_left_in_period.unwrap_or(Duration::ZERO).as_secs_f32(),
             occurred_on: calculate_timestamp(instant),
@@ -58,30 +59,197 @@ impl GameStats {
         self.events.push(event);
     }
 
-    pub(crate) fn add_penalty(&mut self, penalty: &Penalty, color: Color) {
-        let event = Event::Penalty {
-            player_cap_number: penalty.player_number,
-            side: match color {
-                Color::Black => "dark".to_string(),
-                Color::White => "light".to_string(),
-            },
-            game_period: penalty.start_period,
-            period_time: penalty.start_time.as_secs_f32(),
-            occurred_on: calculate_timestamp(penalty.start_instant),
-            duration: match penalty.kind {
-                PenaltyKind::TotalDismissal => None,
-                _ => Some(penalty.kind.as_duration().unwrap().as_secs()),
-            },
-            is_total_dismissal: penalty.kind == PenaltyKind::TotalDismissal,
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn add_penalty_shot(
+        &mut self,
+        period: GamePeriod,
+        time_left_in_period: Option<Duration>,
+        color: Color,
+        player_num: u8,
+        infraction: Infraction,
+        scored: bool,
+        instant: Instant,
+    ) {
+        let event = Event::PenaltyShot {
+            player_cap_number: player_num,
+            side: color_to_side(color),
+            game_period: period,
+            period_time: time_left_in_period.unwrap_or(Duration::ZERO).as_secs_f32(),
+            occurred_on: calculate_timestamp(instant),
+            infraction,
+            is_goal: scored,
+        };
+        self.events.push(event);
+    }
+
+    pub(crate) fn add_shootout_shot(
+        &mut self,
+        round: u8,
+        color: Color,
+        player_num: u8,
+        scored: bool,
+        instant: Instant,
+    ) {
+        let event = Event::ShootoutShot {
+            player_cap_number: player_num,
+            side: color_to_side(color),
+            round,
+            occurred_on: calculate_timestamp(instant),
+            is_goal: scored,
+        };
+        self.events.push(event);
+    }
+
+    pub(crate) fn add_shootout_win(&mut self, color: Color, instant: Instant) {
+        let event = Event::ShootoutWin {
+            side: color_to_side(color),
+            occurred_on: calculate_timestamp(instant),
         };
         self.events.push(event);
     }
 
+    pub(crate) fn remove_shootout_win(&mut self) {
+        self.events
+            .retain(|event| !matches!(event, Event::ShootoutWin { .. }));
+    }
+
+    pub(crate) fn add_mercy_rule(
+        &mut self,
+        period: GamePeriod,
+        time_left_in_period: Option<Duration>,
+        instant: Instant,
+    ) {
+        let event = Event::MercyRule {
+            game_period: period,
+            period_time: time_left_in_period.unwrap_or(Duration::ZERO).as_secs_f32(),
+            occurred_on: calculate_timestamp(instant),
+        };
+        self.events.push(event);
+    }
+
+    /// Returns the goals in the order they were recorded
+    pub(crate) fn goals(&self) -> Vec<Goal> {
+        self.events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Goal {
+                    player_cap_number,
+                    side,
+                    game_period,
+                    period_time,
+                    ..
+                } => Some(Goal {
+                    color: side_to_color(side),
+                    player_number: *player_cap_number,
+                    period: *game_period,
+                    time: Duration::from_secs_f32(*period_time),
+                }),
+                Event::Penalty { .. }
+                | Event::PenaltyShot { .. }
+                | Event::ShootoutShot { .. }
+                | Event::ShootoutWin { .. }
+                | Event::MercyRule { .. } => None,
+            })
+            .collect()
+    }
+
+    /// Changes the color, scorer and time of the goal at `index` in the list returned by
+    /// `goals()`. Returns `false` if there is no such goal.
+    pub(crate) fn edit_goal(
+        &mut self,
+        index: usize,
+        color: Color,
+        player_num: u8,
+        time_left_in_period: Duration,
+    ) -> bool {
+        if let Some(Event::Goal {
+            player_cap_number,
+            side,
+            period_time,
+            ..
+        }) = self.goal_events_mut().nth(index)
+        {
+            *player_cap_number = Some(player_num);
+            *side = color_to_side(color);
+            *period_time = time_left_in_period.as_secs_f32();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Removes the goal at `index` in the list returned by `goals()`
+    pub(crate) fn delete_goal(&mut self, index: usize) -> Option<Goal> {
+        let goal = *self.goals().get(index)?;
+        let event_index = self
+            .events
+            .iter()
+            .enumerate()
+            .filter(|(_, event)| matches!(event, Event::Goal { .. }))
+            .nth(index)
+            .map(|(i, _)| i)?;
+        self.events.remove(event_index);
+        Some(goal)
+    }
+
+    fn goal_events_mut(&mut self) -> impl Iterator<Item = &mut Event> {
+        self.events
+            .iter_mut()
+            .filter(|event| matches!(event, Event::Goal { .. }))
+    }
+
+    pub(crate) fn add_penalty(&mut self, penalty: &Penalty, color: Color) {
+        self.events.push(penalty_event(penalty, color));
+    }
+
+    /// Removes the most recent record of `penalty`, if there is one. The timestamps are
+    /// recalculated on each call, so they are not compared
+    pub(crate) fn remove_penalty(&mut self, penalty: &Penalty, color: Color) {
+        let target = penalty_event(penalty, color);
+        let index = self.events.iter().rposition(|event| {
+            let mut event = event.clone();
+            if let (
+                Event::Penalty { occurred_on, .. },
+                Event::Penalty {
+                    occurred_on: target_time,
+                    ..
+                },
+            ) = (&mut event, &target)
+            {
+                *occurred_on = *target_time;
+            }
+            event == target
+        });
+        if let Some(index) = index {
+            self.events.remove(index);
+        }
+    }
+
+    /// The players that were given a total dismissal
+    pub(crate) fn dismissals(&self) -> Vec<(Color, u8)> {
+        self.events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Penalty {
+                    player_cap_number,
+                    side,
+                    is_total_dismissal: true,
+                    ..
+                } => Some((side_to_color(side), *player_cap_number)),
+                _ => None,
+            })
+            .collect()
+    }
+
     pub(crate) fn as_json(&self) -> String {
         let mut events = self.events.clone();
         events.sort_unstable_by_key(|event| match event {
             Event::Goal { occurred_on, .. } => *occurred_on,
             Event::Penalty { occurred_on, .. } => *occurred_on,
+            Event::PenaltyShot { occurred_on, .. } => *occurred_on,
+            Event::ShootoutShot { occurred_on, .. } => *occurred_on,
+            Event::ShootoutWin { occurred_on, .. } => *occurred_on,
+            Event::MercyRule { occurred_on, .. } => *occurred_on,
         });
         serde_json::to_string(&events).unwrap()
     }
@@ -90,10 +258,12 @@ impl GameStats {
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(tag = "$type")]
 enum Event {
+    /// A goal. `player_cap_number` is left out of the upload if the scorer is not known
     #[serde(rename = "goal")]
     Goal {
         #[serde(rename = "playerCapNumber")]
-        player_cap_number: u8,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        player_cap_number: Option<u8>,
         side: String,
         #[serde(rename = "gamePeriod")]
         game_period: GamePeriod,
@@ -119,6 +289,85 @@ enum Event {
         #[serde(rename = "isTotalDismissal")]
         is_total_dismissal: bool,
     },
+    #[serde(rename = "penaltyShot")]
+    PenaltyShot {
+        #[serde(rename = "playerCapNumber")]
+        player_cap_number: u8,
+        side: String,
+        #[serde(rename = "gamePeriod")]
+        game_period: GamePeriod,
+        #[serde(rename = "periodTime")]
+        period_time: f32,
+        #[serde(with = "iso8601_short_year")]
+        #[serde(rename = "occurredOn")]
+        occurred_on: OffsetDateTime,
+        infraction: Infraction,
+        #[serde(rename = "isGoal")]
+        is_goal: bool,
+    },
+    /// A shot taken in a shootout. `round` starts at 1
+    #[serde(rename = "shootoutShot")]
+    ShootoutShot {
+        #[serde(rename = "playerCapNumber")]
+        player_cap_number: u8,
+        side: String,
+        round: u8,
+        #[serde(with = "iso8601_short_year")]
+        #[serde(rename = "occurredOn")]
+        occurred_on: OffsetDateTime,
+        #[serde(rename = "isGoal")]
+        is_goal: bool,
+    },
+    /// The team that won the shootout. The extra point in the final score comes from this, not
+    /// from a goal
+    #[serde(rename = "shootoutWin")]
+    ShootoutWin {
+        side: String,
+        #[serde(with = "iso8601_short_year")]
+        #[serde(rename = "occurredOn")]
+        occurred_on: OffsetDateTime,
+    },
+    /// The game was ended early because one team's lead reached the mercy rule margin
+    #[serde(rename = "mercyRule")]
+    MercyRule {
+        #[serde(rename = "gamePeriod")]
+        game_period: GamePeriod,
+        #[serde(rename = "periodTime")]
+        period_time: f32,
+        #[serde(with = "iso8601_short_year")]
+        #[serde(rename = "occurredOn")]
+        occurred_on: OffsetDateTime,
+    },
+}
+
+fn penalty_event(penalty: &Penalty, color: Color) -> Event {
+    Event::Penalty {
+        player_cap_number: penalty.player_number,
+        side: color_to_side(color),
+        game_period: penalty.start_period,
+        period_time: penalty.start_time.as_secs_f32(),
+        occurred_on: calculate_timestamp(penalty.start_instant),
+        duration: match penalty.kind {
+            PenaltyKind::TotalDismissal => None,
+            _ => Some(penalty.kind.as_duration().unwrap().as_secs()),
+        },
+        is_total_dismissal: penalty.kind == PenaltyKind::TotalDismissal,
+    }
+}
+
+fn color_to_side(color: Color) -> String {
+    match color {
+        Color::Black => "dark".to_string(),
+        Color::White => "light".to_string(),
+    }
+}
+
+fn side_to_color(side: &str) -> Color {
+    if side == "dark" {
+        Color::Black
+    } else {
+        Color::White
+    }
 }
 
 fn calculate_timestamp(instant: Instant) -> OffsetDateTime {
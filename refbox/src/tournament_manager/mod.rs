@@ -14,9 +14,12 @@ use tokio::{
     time::{Duration, Instant},
 };
 use uwh_common::{
-    config::Game as GameConfig,
+    config::{Game as GameConfig, SuddenDeathResolution},
     drawing_support::*,
-    game_snapshot::{Color, GamePeriod, GameSnapshot, Infraction, TimeoutSnapshot},
+    game_snapshot::{
+        Color, GamePeriod, GameSnapshot, Infraction, ShootoutShot, ShootoutSnapshot,
+        TimeoutSnapshot,
+    },
     uwhscores::TimingRules,
 };
 
@@ -29,10 +32,42 @@ use infraction::*;
 mod game_stats;
 use game_stats::*;
 
-use crate::penalty_editor::IterHelp;
+use crate::{
+    config::{CatchUp, EscalationRules},
+    penalty_editor::IterHelp,
+};
 
 const MAX_TIME_VAL: Duration = Duration::from_secs(MAX_LONG_STRINGABLE_SECS as u64);
 const RECENT_GOAL_TIME: Duration = Duration::from_secs(10);
+const RECENT_EXPIRY_TIME: Duration = Duration::from_secs(10);
+
+/// A goal as recorded in the game stats. `time` is the time that was left in `period`.
+/// `player_number` is `None` if the scorer is not known
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Goal {
+    pub color: Color,
+    pub player_number: Option<u8>,
+    pub period: GamePeriod,
+    pub time: Duration,
+}
+
+/// Why a penalty is being suggested for repeated warnings or fouls
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EscalationReason {
+    PlayerWarnings(u8),
+    InfractionWarnings(Infraction),
+    TeamFouls,
+}
+
+/// A penalty suggested by the `EscalationRules`. `player_number` is `None` for a team penalty
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Escalation {
+    pub color: Color,
+    pub player_number: Option<u8>,
+    pub infraction: Infraction,
+    pub reason: EscalationReason,
+    pub count: usize,
+}
 
 #[derive(Debug)]
 pub struct TournamentManager {
@@ -54,7 +89,15 @@ pub struct TournamentManager {
     next_scheduled_start: Option<Instant>,
     reset_game_time: Duration,
     timezone: UtcOffset,
+    catch_up: CatchUp,
+    /// When the current session suspension started, if the session is suspended
+    session_suspended_at: Option<Instant>,
+    /// How far the published schedule has been pushed back by suspended sessions
+    schedule_delay: Duration,
     recent_goal: Option<(Color, u8, GamePeriod, Duration)>,
+    recent_penalty_expiries: Vec<(Color, u8, GamePeriod, Duration)>,
+    last_expiry_check: Option<(GamePeriod, Duration)>,
+    shootout: Option<ShootoutSnapshot>,
     current_game_stats: GameStats,
     last_game_stats: Option<GameStats>,
 }
@@ -83,7 +126,13 @@ impl TournamentManager {
             reset_game_time: config.nominal_break,
             config,
             timezone: UtcOffset::UTC,
+            catch_up: Default::default(),
+            session_suspended_at: None,
+            schedule_delay: Duration::ZERO,
             recent_goal: None,
+            recent_penalty_expiries: vec![],
+            last_expiry_check: None,
+            shootout: None,
             current_game_stats: GameStats::new(0),
             last_game_stats: None,
         }
@@ -108,33 +157,365 @@ impl TournamentManager {
             self.current_period,
             self.game_clock_time(now),
             color,
-            player_num,
+            Some(player_num),
             now,
         );
         self.recent_goal = self
             .game_clock_time(now)
             .map(|time| (color, player_num, self.current_period, time));
+        let period = self.current_period;
+        let stop_clock = self.in_stop_clock_time(now);
         let mut scores = self.scores;
         scores[color] += 1;
         self.set_scores(scores, now);
+
+        if stop_clock && self.current_period == period {
+            self.stop_clock_for_whistle("a goal", now);
+        }
+    }
+
+    /// Stops the clock for a whistle in stop-clock time, unless a timeout is already running
+    fn stop_clock_for_whistle(&mut self, reason: &str, now: Instant) {
+        if matches!(self.timeout_state, TimeoutState::None) && self.clock_is_running() {
+            info!(
+                "{} Stopping the clock for {reason} in stop-clock time",
+                self.status_string(now)
+            );
+            if let Err(e) = self.stop_clock(now) {
+                error!("Failed to stop the clock for {reason}: {e}");
+            }
+        }
+    }
+
+    /// Stops the clock if the stop-clock rules apply to every whistle and the game is in
+    /// stop-clock time
+    fn stop_clock_if_every_whistle(&mut self, reason: &str, now: Instant) {
+        if self.config.stop_clock_at_every_whistle && self.in_stop_clock_time(now) {
+            self.stop_clock_for_whistle(reason, now);
+        }
+    }
+
+    /// Whether the game is in the final minutes of a half where the clock stops after every goal
+    pub fn in_stop_clock_time(&self, now: Instant) -> bool {
+        self.game_clock_time(now)
+            .is_some_and(|clock_time| self.is_stop_clock_time(clock_time))
+    }
+
+    fn is_stop_clock_time(&self, clock_time: Duration) -> bool {
+        let window_period = match self.current_period {
+            GamePeriod::SecondHalf => true,
+            GamePeriod::OvertimeSecondHalf => self.config.stop_clock_in_overtime,
+            _ => false,
+        };
+        window_period
+            && !self.config.stop_clock_duration.is_zero()
+            && clock_time <= self.config.stop_clock_duration
     }
 
     pub fn get_scores(&self) -> BlackWhiteBundle<u8> {
         self.scores
     }
 
+    /// Sets the scores directly. The recorded goals are kept in sync with the new scores
+    /// by adding goals with an unknown scorer or removing the most recent goals.
     pub fn set_scores(&mut self, scores: BlackWhiteBundle<u8>, now: Instant) {
+        self.update_scores(scores, now);
+        if matches!(
+            self.current_period,
+            GamePeriod::SuddenDeath | GamePeriod::Shootout
+        ) && scores.black != scores.white
+        {
+            self.end_game(now);
+        }
+    }
+
+    /// Sets the scores without ending a game that is decided by the next goal
+    fn update_scores(&mut self, scores: BlackWhiteBundle<u8>, now: Instant) {
         self.scores = scores;
         info!("{} Scores set to {scores}", self.status_string(now));
-        if self.current_period == GamePeriod::SuddenDeath && scores.black != scores.white {
-            self.end_game(now);
+        self.sync_goals_to_scores(now);
+    }
+
+    /// Whether the current scores would end the game if they were confirmed, because the game
+    /// is being decided by the next goal
+    pub fn scores_would_end_game(&self) -> bool {
+        matches!(
+            self.current_period,
+            GamePeriod::SuddenDeath | GamePeriod::Shootout
+        ) && self.scores.black != self.scores.white
+    }
+
+    /// The team that won the shootout, whose score includes one point that is not a goal
+    fn shootout_winner(&self) -> Option<Color> {
+        self.shootout.as_ref().and_then(ShootoutSnapshot::winner)
+    }
+
+    fn sync_goals_to_scores(&mut self, now: Instant) {
+        let shootout_winner = self.shootout_winner();
+        for color in [Color::Black, Color::White] {
+            let goals = self.current_game_stats.goals();
+            let mut recorded = goals.iter().filter(|goal| goal.color == color).count();
+            let score = (self.scores[color] as usize)
+                .saturating_sub((shootout_winner == Some(color)).into());
+
+            while recorded < score {
+                self.current_game_stats.add_goal(
+                    self.current_period,
+                    self.game_clock_time(now),
+                    color,
+                    None,
+                    now,
+                );
+                recorded += 1;
+            }
+
+            if recorded > score {
+                let mut to_remove: Vec<_> = goals
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, goal)| goal.color == color)
+                    .map(|(i, _)| i)
+                    .skip(score)
+                    .collect();
+                to_remove.reverse();
+                for i in to_remove {
+                    self.current_game_stats.delete_goal(i);
+                }
+            }
+        }
+    }
+
+    /// Records the outcome of a penalty shot. If the shot scored, the goal must be added
+    /// separately with `add_score`
+    pub fn record_penalty_shot(
+        &mut self,
+        color: Color,
+        player_num: u8,
+        infraction: Infraction,
+        scored: bool,
+        now: Instant,
+    ) {
+        info!(
+            "{} Penalty shot by {color} player #{player_num} for {infraction} {}",
+            self.status_string(now),
+            if scored { "scored" } else { "did not score" }
+        );
+        self.current_game_stats.add_penalty_shot(
+            self.current_period,
+            self.game_clock_time(now),
+            color,
+            player_num,
+            infraction,
+            scored,
+            now,
+        );
+    }
+
+    /// Records a shot taken during a shootout. Once the shootout has been decided, the winning
+    /// team's score goes up by one and the game ends. The win is recorded separately from the
+    /// goals
+    pub fn record_shootout_shot(
+        &mut self,
+        color: Color,
+        player_num: u8,
+        scored: bool,
+        now: Instant,
+    ) -> Result<()> {
+        let status_str = self.status_string(now);
+        let shootout = self
+            .shootout
+            .as_mut()
+            .filter(|_| self.current_period == GamePeriod::Shootout)
+            .ok_or(TournamentManagerError::NotInShootout)?;
+
+        let shots = match color {
+            Color::Black => &mut shootout.b_shots,
+            Color::White => &mut shootout.w_shots,
+        };
+        shots.push(ShootoutShot {
+            player_number: player_num,
+            scored,
+        });
+        let round = shots.len().try_into().unwrap_or(u8::MAX);
+        info!(
+            "{status_str} Shootout round {round} shot by {color} player #{player_num} {}",
+            if scored { "scored" } else { "did not score" }
+        );
+        self.current_game_stats
+            .add_shootout_shot(round, color, player_num, scored, now);
+
+        if let Some(winner) = shootout.winner() {
+            info!("{status_str} {winner} wins the shootout");
+            self.current_game_stats.add_shootout_win(winner, now);
+            let mut scores = self.scores;
+            scores[winner] += 1;
+            self.set_scores(scores, now);
+        }
+        Ok(())
+    }
+
+    /// Whether one team's lead is large enough for the mercy rule to end the game
+    pub fn mercy_rule_reached(&self) -> bool {
+        let margin = self.config.mercy_rule_margin;
+        margin > 0
+            && self.current_period != GamePeriod::BetweenGames
+            && self.current_period >= self.config.mercy_rule_period
+            && self.scores.black.abs_diff(self.scores.white) >= margin
+    }
+
+    /// Ends the current game immediately because the mercy rule has been reached
+    pub fn end_game_for_mercy_rule(&mut self, now: Instant) -> Result<()> {
+        if !self.mercy_rule_reached() {
+            return Err(TournamentManagerError::MercyRuleNotReached);
+        }
+        let clock_time = self
+            .game_clock_time(now)
+            .ok_or(TournamentManagerError::InvalidNowValue)?;
+        info!(
+            "{} Ending the game early due to the mercy rule",
+            self.status_string(now)
+        );
+
+        self.current_game_stats
+            .add_mercy_rule(self.current_period, Some(clock_time), now);
+        self.timeout_state = TimeoutState::None;
+        self.clock_state = ClockState::Stopped { clock_time };
+        self.end_game(now);
+        Ok(())
+    }
+
+    /// Whether a practice is being played. Practices have no set length, so their halves only
+    /// end when the refs end them
+    pub fn in_practice(&self) -> bool {
+        self.config.practice_mode
+            && matches!(
+                self.current_period,
+                GamePeriod::FirstHalf | GamePeriod::SecondHalf
+            )
+    }
+
+    /// Ends the first half of a practice, moving on to half time. The second half starts when
+    /// half time runs out, and counts up like the first
+    pub fn end_practice_half(&mut self, now: Instant) -> Result<()> {
+        if !self.in_practice() {
+            return Err(TournamentManagerError::NotInPractice);
+        }
+        if self.current_period != GamePeriod::FirstHalf {
+            return Err(TournamentManagerError::PracticeInSecondHalf);
+        }
+        let was_running = self.clock_is_running();
+
+        self.timeout_state = TimeoutState::None;
+        self.end_first_half(now);
+        self.clock_state = ClockState::CountingDown {
+            start_time: now,
+            time_remaining_at_start: self.current_period_duration(now).unwrap(),
+        };
+
+        if !was_running {
+            self.send_clock_running(true);
+        }
+        Ok(())
+    }
+
+    /// Ends the current practice, moving on to the break before the next game
+    pub fn end_practice(&mut self, now: Instant) -> Result<()> {
+        if !self.in_practice() {
+            return Err(TournamentManagerError::NotInPractice);
+        }
+        let clock_time = self
+            .game_clock_time(now)
+            .ok_or(TournamentManagerError::InvalidNowValue)?;
+        info!("{} Ending the practice", self.status_string(now));
+
+        self.timeout_state = TimeoutState::None;
+        self.clock_state = ClockState::Stopped { clock_time };
+        self.end_game(now);
+        Ok(())
+    }
+
+    /// Returns all the goals scored in the current game, in the order they were recorded
+    pub fn goals(&self) -> Vec<Goal> {
+        self.current_game_stats.goals()
+    }
+
+    /// Changes the team, scorer and time of a goal, updating the scores to match. The game is not
+    /// ended if the new scores would end it, use `scores_would_end_game` to check for that
+    pub fn edit_goal(
+        &mut self,
+        index: usize,
+        new_color: Color,
+        new_player_number: u8,
+        new_time: Duration,
+        now: Instant,
+    ) -> Result<()> {
+        let goals = self.goals();
+        let old = *goals
+            .get(index)
+            .ok_or(TournamentManagerError::InvalidGoalIndex(index))?;
+        let is_last = index + 1 == goals.len();
+        info!(
+            "{} Editing {} player #{}'s goal at {:?}: it is now {new_color} player #{new_player_number}'s goal at {new_time:?}",
+            self.status_string(now),
+            old.color,
+            old.player_number.unwrap_or(0),
+            old.time,
+        );
+
+        self.current_game_stats
+            .edit_goal(index, new_color, new_player_number, new_time);
+
+        if is_last {
+            if let Some((ref mut color, ref mut player_num, _, ref mut time)) = self.recent_goal {
+                *color = new_color;
+                *player_num = new_player_number;
+                *time = new_time;
+            }
+        }
+
+        if old.color != new_color {
+            let mut scores = self.scores;
+            scores[old.color] = scores[old.color].saturating_sub(1);
+            scores[new_color] = scores[new_color].saturating_add(1);
+            self.update_scores(scores, now);
+        }
+        Ok(())
+    }
+
+    /// Deletes a goal, updating the scores to match. The game is not ended if the new scores
+    /// would end it, use `scores_would_end_game` to check for that
+    pub fn delete_goal(&mut self, index: usize, now: Instant) -> Result<()> {
+        let is_last = index + 1 == self.goals().len();
+        let goal = self
+            .current_game_stats
+            .delete_goal(index)
+            .ok_or(TournamentManagerError::InvalidGoalIndex(index))?;
+        info!(
+            "{} Deleting {} player #{}'s goal",
+            self.status_string(now),
+            goal.color,
+            goal.player_number.unwrap_or(0)
+        );
+
+        if is_last {
+            self.recent_goal = None;
         }
+
+        let mut scores = self.scores;
+        scores[goal.color] = scores[goal.color].saturating_sub(1);
+        self.update_scores(scores, now);
+        Ok(())
     }
 
     pub fn set_timezone(&mut self, timezone: UtcOffset) {
         self.timezone = timezone;
     }
 
+    pub fn set_catch_up(&mut self, catch_up: CatchUp) {
+        info!("Schedule catch up set to {catch_up:?}");
+        self.catch_up = catch_up;
+    }
+
     pub fn current_period(&self) -> GamePeriod {
         self.current_period
     }
@@ -160,10 +541,70 @@ impl TournamentManager {
         self.last_game_stats.as_ref()
     }
 
+    /// The players that were given a total dismissal in the last game
+    pub fn last_game_dismissals(&self) -> Vec<(Color, u8)> {
+        self.last_game_stats
+            .as_ref()
+            .map(GameStats::dismissals)
+            .unwrap_or_default()
+    }
+
     pub fn clear_scheduled_game_start(&mut self) {
         self.next_scheduled_start = None;
     }
 
+    /// Forgets any delay to the schedule from suspended sessions, such as when a different
+    /// tournament is selected
+    pub fn clear_schedule_delay(&mut self) {
+        self.schedule_delay = Duration::ZERO;
+    }
+
+    /// How far the published schedule has been pushed back by suspended sessions
+    pub fn schedule_delay(&self) -> Duration {
+        self.schedule_delay
+    }
+
+    /// When the current session suspension started, if the session is suspended
+    pub fn session_suspended_at(&self) -> Option<Instant> {
+        self.session_suspended_at
+    }
+
+    /// Freezes the countdown to the next game, such as when the pool has to be closed. The
+    /// schedule is shifted by the length of the suspension when the session is resumed
+    pub fn suspend_session(&mut self, now: Instant) -> Result<()> {
+        if self.current_period != GamePeriod::BetweenGames {
+            return Err(TournamentManagerError::GameInProgress);
+        }
+        if self.session_suspended_at.is_some() {
+            return Err(TournamentManagerError::SessionAlreadySuspended);
+        }
+        info!("{} Suspending the session", self.status_string(now));
+        self.stop_clock(now)?;
+        self.session_suspended_at = Some(now);
+        Ok(())
+    }
+
+    /// Restarts the countdown to the next game, and pushes back all the following scheduled
+    /// game starts by the length of the suspension. Returns the length of the suspension
+    pub fn resume_session(&mut self, now: Instant) -> Result<Duration> {
+        let suspended_at = self
+            .session_suspended_at
+            .ok_or(TournamentManagerError::SessionNotSuspended)?;
+        let suspension = now
+            .checked_duration_since(suspended_at)
+            .ok_or(TournamentManagerError::InvalidNowValue)?;
+        self.session_suspended_at = None;
+        info!(
+            "{} Resuming the session after {suspension:?}",
+            self.status_string(now)
+        );
+
+        self.schedule_delay += suspension;
+        self.next_scheduled_start = self.next_scheduled_start.map(|start| start + suspension);
+        self.start_clock(now);
+        Ok(suspension)
+    }
+
     pub fn game_number(&self) -> u32 {
         self.game_number
     }
@@ -209,6 +650,7 @@ impl TournamentManager {
         self.warnings.iter_mut().for_each(|(_, w)| w.clear());
         self.fouls.iter_mut().for_each(|(_, f)| f.clear());
         self.current_game_stats = GameStats::new(self.next_game_number());
+        self.shootout = None;
         self.has_reset = true;
     }
 
@@ -612,6 +1054,7 @@ impl TournamentManager {
             infraction,
         };
         self.penalties[color].push(penalty);
+        self.stop_clock_if_every_whistle("a penalty", now);
         Ok(())
     }
 
@@ -739,6 +1182,7 @@ impl TournamentManager {
             infraction,
         };
         self.warnings[color].push(warning);
+        self.stop_clock_if_every_whistle("a warning", now);
         Ok(())
     }
 
@@ -767,6 +1211,7 @@ impl TournamentManager {
             infraction,
         };
         self.fouls[color].push(foul);
+        self.stop_clock_if_every_whistle("a foul", now);
         Ok(())
     }
 
@@ -778,6 +1223,72 @@ impl TournamentManager {
         &self.fouls
     }
 
+    /// Checks whether the latest warning for `color` means a penalty should be suggested. A
+    /// suggestion is only made by the warning that reaches a threshold, not by any later ones
+    pub fn warning_escalation(&self, rules: &EscalationRules, color: Color) -> Option<Escalation> {
+        if !rules.enabled {
+            return None;
+        }
+        let latest = self.warnings[color].last()?;
+
+        if let Some(player_number) = latest.player_number {
+            let count = self.warnings[color]
+                .iter()
+                .filter(|w| w.player_number == Some(player_number))
+                .count();
+            if rules.player_warnings > 0 && count == rules.player_warnings as usize {
+                return Some(Escalation {
+                    color,
+                    player_number: Some(player_number),
+                    infraction: latest.infraction,
+                    reason: EscalationReason::PlayerWarnings(player_number),
+                    count,
+                });
+            }
+        }
+
+        if latest.infraction != Infraction::Unknown {
+            let count = self.warnings[color]
+                .iter()
+                .filter(|w| w.infraction == latest.infraction)
+                .count();
+            if rules.infraction_warnings > 0 && count == rules.infraction_warnings as usize {
+                return Some(Escalation {
+                    color,
+                    player_number: latest.player_number,
+                    infraction: latest.infraction,
+                    reason: EscalationReason::InfractionWarnings(latest.infraction),
+                    count,
+                });
+            }
+        }
+
+        None
+    }
+
+    /// Checks whether the latest foul for `color` means a team penalty should be suggested. A
+    /// suggestion is only made by the foul that reaches the threshold, not by any later ones
+    pub fn foul_escalation(&self, rules: &EscalationRules, color: Color) -> Option<Escalation> {
+        if !rules.enabled || rules.team_fouls == 0 {
+            return None;
+        }
+        let fouls = &self.fouls[Some(color)];
+        let latest = fouls.last()?;
+
+        let count = fouls.len();
+        if count == rules.team_fouls as usize {
+            Some(Escalation {
+                color,
+                player_number: None,
+                infraction: latest.infraction,
+                reason: EscalationReason::TeamFouls,
+                count,
+            })
+        } else {
+            None
+        }
+    }
+
     pub fn edit_warning(
         &mut self,
         old_color: Color,
@@ -873,42 +1384,170 @@ impl TournamentManager {
         Ok(())
     }
 
+    /// The published start time of the next game, if there is a schedule to follow. A uwhscores
+    /// start time is converted using the real current time, since the projections that use it
+    /// are made for instants other than now
+    fn scheduled_next_start(&self) -> Option<Instant> {
+        if let Some(start_time) = self.next_game.as_ref().and_then(|info| info.start_time) {
+            let now = Instant::now();
+            let cur_time = OffsetDateTime::now_utc().to_offset(self.timezone);
+            let start_time = start_time.assume_offset(self.timezone);
+            let time_to_game = start_time - cur_time + self.schedule_delay;
+
+            // The start time may already have passed if the schedule is running late
+            if time_to_game.is_negative() {
+                Some(now.checked_sub(time_to_game.unsigned_abs()).unwrap_or(now))
+            } else {
+                Some(now + time_to_game.unsigned_abs())
+            }
+        } else {
+            self.next_scheduled_start
+        }
+    }
+
+    /// The shortest break allowed between games, taking the catch up policy into account
+    fn break_floor(&self) -> Duration {
+        if self.catch_up.shortens_breaks() {
+            min(
+                self.config.minimum_break,
+                Duration::from_secs(self.catch_up.min_break_secs.into()),
+            )
+        } else {
+            self.config.minimum_break
+        }
+    }
+
+    /// The length of a half time starting at `half_time_start`. If the catch up policy allows it,
+    /// the half time is shortened (down to its floor) by as much as the next game is projected to
+    /// start late. This is also used to project the schedule drift while rendering, so it must not
+    /// log anything
+    fn half_time_duration(&self, half_time_start: Instant) -> Duration {
+        let nominal = self.config.half_time_duration;
+        if !self.catch_up.shortens_half_time() || self.config.practice_mode {
+            return nominal;
+        }
+        let Some(scheduled_start) = self.scheduled_next_start() else {
+            return nominal;
+        };
+
+        let projected_start =
+            half_time_start + nominal + self.config.half_play_duration + self.break_floor();
+        let lateness = projected_start.saturating_duration_since(scheduled_start);
+        let floor = min(
+            Duration::from_secs(self.catch_up.min_half_time_secs.into()),
+            nominal,
+        );
+        max(nominal.saturating_sub(lateness), floor)
+    }
+
+    /// The length of the current period if it starts at `period_start`
+    fn current_period_duration(&self, period_start: Instant) -> Option<Duration> {
+        if self.current_period == GamePeriod::HalfTime {
+            let duration = self.half_time_duration(period_start);
+            if duration < self.config.half_time_duration {
+                info!(
+                    "Shortening half time to {:?} to catch up with the schedule",
+                    duration
+                );
+            }
+            Some(duration)
+        } else {
+            self.current_period.duration(&self.config)
+        }
+    }
+
+    /// How late the next game is projected to start compared to the published schedule,
+    /// assuming that the current game ends without any more time being added. Returns `None` if
+    /// there is no schedule to compare against
+    pub fn schedule_drift(&self, now: Instant) -> Option<Duration> {
+        if self.config.practice_mode {
+            return None;
+        }
+        let scheduled_start = self.scheduled_next_start()?;
+        let clock_time = if self.current_period.counts_up(&self.config) {
+            Duration::ZERO
+        } else {
+            self.game_clock_time(now).unwrap_or(Duration::ZERO)
+        };
+        let period_end = now + clock_time;
+
+        let projected_start = match self.current_period {
+            GamePeriod::BetweenGames => period_end,
+            GamePeriod::FirstHalf => {
+                period_end
+                    + self.half_time_duration(period_end)
+                    + self.config.half_play_duration
+                    + self.break_floor()
+            }
+            GamePeriod::HalfTime => {
+                period_end + self.config.half_play_duration + self.break_floor()
+            }
+            GamePeriod::PreOvertime => {
+                period_end
+                    + 2 * self.config.ot_half_play_duration
+                    + self.config.ot_half_time_duration
+                    + self.break_floor()
+            }
+            GamePeriod::OvertimeFirstHalf => {
+                period_end
+                    + self.config.ot_half_time_duration
+                    + self.config.ot_half_play_duration
+                    + self.break_floor()
+            }
+            GamePeriod::OvertimeHalfTime => {
+                period_end + self.config.ot_half_play_duration + self.break_floor()
+            }
+            GamePeriod::SecondHalf
+            | GamePeriod::OvertimeSecondHalf
+            | GamePeriod::PreSuddenDeath
+            | GamePeriod::SuddenDeath
+            | GamePeriod::Shootout => period_end + self.break_floor(),
+        };
+
+        Some(projected_start.saturating_duration_since(scheduled_start))
+    }
+
     fn calc_time_to_next_game(&self, now: Instant, from_time: Instant) -> Duration {
         info!("Next game info is: {:?}", self.next_game);
-        let scheduled_start =
-            if let Some(start_time) = self.next_game.as_ref().and_then(|info| info.start_time) {
-                let cur_time = OffsetDateTime::now_utc().to_offset(self.timezone);
-                info!("Current time is: {cur_time}");
-
-                let start_time = start_time.assume_offset(self.timezone);
-                info!("Start time is: {start_time}");
-
-                let time_to_game = start_time - cur_time;
-                info!("Calculated time to next game: {time_to_game}");
-
-                match time_to_game.try_into() {
-                    Ok(dur) => Instant::now() + dur,
-                    Err(e) => {
-                        error!("Failed to calculate time to next game start: {e}");
-                        now
-                    }
-                }
-            } else {
-                self.next_scheduled_start
-                    .unwrap_or(now + self.config.nominal_break)
-            };
+        let scheduled_start = self
+            .scheduled_next_start()
+            .unwrap_or(now + self.config.nominal_break);
+        info!(
+            "Calculated time to next game: {:?}",
+            scheduled_start.saturating_duration_since(now)
+        );
 
+        let break_floor = self.break_floor();
         let time_remaining_at_start =
             if let Some(time_until_start) = scheduled_start.checked_duration_since(from_time) {
-                max(time_until_start, self.config.minimum_break)
+                max(time_until_start, break_floor)
             } else {
-                self.config.minimum_break
+                break_floor
             };
 
         // Make sure the value isn't too big
         min(time_remaining_at_start, MAX_TIME_VAL)
     }
 
+    /// Replaces the config with the timing rules from uwhscores, keeping the settings that
+    /// uwhscores doesn't know about
+    fn apply_timing_rules(&mut self, timing: TimingRules) {
+        self.config = GameConfig {
+            td_team_short_duration: self.config.td_team_short_duration,
+            mercy_rule_margin: self.config.mercy_rule_margin,
+            mercy_rule_period: self.config.mercy_rule_period,
+            shootout_allowed: self.config.shootout_allowed,
+            shootout_rounds: self.config.shootout_rounds,
+            sudden_death_max_duration: self.config.sudden_death_max_duration,
+            sudden_death_resolution: self.config.sudden_death_resolution,
+            stop_clock_duration: self.config.stop_clock_duration,
+            stop_clock_in_overtime: self.config.stop_clock_in_overtime,
+            stop_clock_at_every_whistle: self.config.stop_clock_at_every_whistle,
+            practice_mode: self.config.practice_mode,
+            ..timing.into()
+        };
+    }
+
     pub fn apply_next_game_start(&mut self, now: Instant) -> Result<()> {
         if self.current_period != GamePeriod::BetweenGames {
             return Err(TournamentManagerError::GameInProgress);
@@ -920,8 +1559,8 @@ impl TournamentManager {
             return Err(TournamentManagerError::NoNextGameInfo);
         };
 
-        if let Some(ref timing) = next_game_info.timing {
-            self.config = timing.clone().into();
+        if let Some(timing) = next_game_info.timing.clone() {
+            self.apply_timing_rules(timing);
         }
 
         let time_remaining_at_start = self.calc_time_to_next_game(now, now);
@@ -1002,7 +1641,7 @@ impl TournamentManager {
         self.game_number = self.next_game_number();
 
         if let Some(timing) = self.next_game.take().and_then(|info| info.timing) {
-            self.config = timing.into();
+            self.apply_timing_rules(timing);
         }
 
         info!(
@@ -1065,13 +1704,18 @@ impl TournamentManager {
         Ok(time >= time_remaining_at_start
             && ((self.current_period == GamePeriod::SecondHalf
                 && (self.scores.are_not_equal()
-                    || (!self.config.overtime_allowed && !self.config.sudden_death_allowed)))
+                    || (!self.config.overtime_allowed
+                        && !self.config.sudden_death_allowed
+                        && !self.config.shootout_allowed)))
                 || (self.current_period == GamePeriod::OvertimeSecondHalf
-                    && (self.scores.are_not_equal() || !self.config.sudden_death_allowed))))
+                    && (self.scores.are_not_equal()
+                        || (!self.config.sudden_death_allowed && !self.config.shootout_allowed)))
+                || (self.current_period == GamePeriod::SuddenDeath
+                    && self.config.sudden_death_resolution == SuddenDeathResolution::Draw)))
     }
 
     pub(super) fn update(&mut self, now: Instant) -> Result<()> {
-        // Case of clock running, with no timeout and not SD
+        // Case of clock running, with no timeout and not an unlimited SD
         if let ClockState::CountingDown {
             start_time,
             time_remaining_at_start,
@@ -1178,21 +1822,34 @@ impl TournamentManager {
                         self.current_period = GamePeriod::SuddenDeath;
                         need_cull = true;
                     }
-                    (GamePeriod::SuddenDeath, _) => {
+                    (GamePeriod::SuddenDeath, false) => {
+                        self.end_sudden_death(now);
+                        leave_game_clock_running = self.current_period != GamePeriod::SuddenDeath;
+                    }
+                    (GamePeriod::SuddenDeath, true) => {
+                        info!(
+                            "{} Extending Sudden Death for unfinished penalty shot",
+                            self.status_string(now)
+                        );
+                        leave_game_clock_running = false;
+                    }
+                    (GamePeriod::Shootout, _) => {
                         error!(
-                            "{} Impossible state: in sudden death with clock counting down",
+                            "{} Impossible state: in shootout with clock counting down",
                             self.status_string(now)
                         )
                     }
                 }
                 if leave_game_clock_running {
-                    if self.current_period != GamePeriod::BetweenGames {
-                        self.clock_state = if self.current_period != GamePeriod::SuddenDeath {
+                    if !matches!(
+                        self.current_period,
+                        GamePeriod::BetweenGames | GamePeriod::Shootout
+                    ) {
+                        self.clock_state = if !self.current_period.counts_up(&self.config) {
                             ClockState::CountingDown {
                                 start_time: start_time + time_remaining_at_start,
                                 time_remaining_at_start: self
-                                    .current_period
-                                    .duration(&self.config)
+                                    .current_period_duration(start_time + time_remaining_at_start)
                                     .unwrap(),
                             }
                         } else {
@@ -1223,9 +1880,17 @@ impl TournamentManager {
                         if now.duration_since(*start_time) >= *time_remaining_at_start {
                             if let ClockState::Stopped { clock_time } = self.clock_state {
                                 info!("{} Ending {color} team timeout", self.status_string(now));
-                                self.clock_state = ClockState::CountingDown {
-                                    start_time: *start_time + *time_remaining_at_start,
-                                    time_remaining_at_start: clock_time,
+                                let start_time = *start_time + *time_remaining_at_start;
+                                self.clock_state = if self.current_period.counts_up(&self.config) {
+                                    ClockState::CountingUp {
+                                        start_time,
+                                        time_at_start: clock_time,
+                                    }
+                                } else {
+                                    ClockState::CountingDown {
+                                        start_time,
+                                        time_remaining_at_start: clock_time,
+                                    }
                                 }
                             } else {
                                 panic!("Cannot end {color} team timeout because game clock isn't stopped");
@@ -1264,7 +1929,9 @@ impl TournamentManager {
 
     fn end_second_half(&mut self, now: Instant) {
         if self.scores.are_not_equal()
-            || (!self.config.overtime_allowed && !self.config.sudden_death_allowed)
+            || (!self.config.overtime_allowed
+                && !self.config.sudden_death_allowed
+                && !self.config.shootout_allowed)
         {
             self.end_game(now);
         } else if self.config.overtime_allowed {
@@ -1274,13 +1941,15 @@ impl TournamentManager {
                 self.scores
             );
             self.current_period = GamePeriod::PreOvertime;
-        } else {
+        } else if self.config.sudden_death_allowed {
             info!(
                 "{} Entering pre-sudden death. Score is {}",
                 self.status_string(now),
                 self.scores
             );
             self.current_period = GamePeriod::PreSuddenDeath;
+        } else {
+            self.start_shootout(now);
         }
     }
 
@@ -1290,24 +1959,105 @@ impl TournamentManager {
     }
 
     fn end_overtime_second_half(&mut self, now: Instant) {
-        if self.scores.are_not_equal() || !self.config.sudden_death_allowed {
+        if self.scores.are_not_equal()
+            || (!self.config.sudden_death_allowed && !self.config.shootout_allowed)
+        {
             self.end_game(now);
-        } else {
+        } else if self.config.sudden_death_allowed {
             info!(
                 "{} Entering pre-sudden death. Score is {}",
                 self.status_string(now),
                 self.scores
             );
             self.current_period = GamePeriod::PreSuddenDeath;
+        } else {
+            self.start_shootout(now);
         }
     }
 
-    fn handle_rugby_pen_shot_end(
-        &mut self,
-        now: Instant,
-        start_time: Instant,
-        time_remaining_at_start: Duration,
-    ) -> Result<()> {
+    fn start_shootout(&mut self, now: Instant) {
+        info!(
+            "{} Entering shootout. Score is {}",
+            self.status_string(now),
+            self.scores
+        );
+        let was_running = self.clock_is_running();
+        self.current_period = GamePeriod::Shootout;
+        self.clock_state = ClockState::Stopped {
+            clock_time: Duration::ZERO,
+        };
+        self.shootout = Some(ShootoutSnapshot {
+            rounds: self.config.shootout_rounds,
+            ..Default::default()
+        });
+        if was_running {
+            self.send_clock_running(false);
+        }
+    }
+
+    fn end_sudden_death(&mut self, now: Instant) {
+        info!(
+            "{} Sudden death has reached its maximum length",
+            self.status_string(now)
+        );
+        match self.config.sudden_death_resolution {
+            SuddenDeathResolution::Draw => self.end_game(now),
+            SuddenDeathResolution::Shootout => self.start_shootout(now),
+            SuddenDeathResolution::RefereeDecision => {
+                info!(
+                    "{} Waiting for the referees to decide the game",
+                    self.status_string(now)
+                );
+                let was_running = self.clock_is_running();
+                self.clock_state = ClockState::Stopped {
+                    clock_time: Duration::ZERO,
+                };
+                if was_running {
+                    self.send_clock_running(false);
+                }
+            }
+        }
+    }
+
+    /// Whether sudden death has reached its maximum length and is waiting for the referees to
+    /// decide the game
+    pub fn awaiting_sudden_death_decision(&self) -> bool {
+        self.current_period == GamePeriod::SuddenDeath
+            && !self.current_period.counts_up(&self.config)
+            && self.config.sudden_death_resolution == SuddenDeathResolution::RefereeDecision
+            && matches!(self.timeout_state, TimeoutState::None)
+            && matches!(self.clock_state, ClockState::Stopped { clock_time } if clock_time.is_zero())
+    }
+
+    /// Decides a game that is waiting for a referee decision at the end of sudden death, either
+    /// by ending it as a draw or by starting a shootout. Awarding a goal instead is done by
+    /// setting the scores
+    pub fn decide_sudden_death(
+        &mut self,
+        resolution: SuddenDeathResolution,
+        now: Instant,
+    ) -> Result<()> {
+        if !self.awaiting_sudden_death_decision() {
+            return Err(TournamentManagerError::NotAwaitingDecision);
+        }
+        info!(
+            "{} Referees decided the game by {resolution:?}",
+            self.status_string(now)
+        );
+        match resolution {
+            SuddenDeathResolution::Draw => self.end_game(now),
+            SuddenDeathResolution::Shootout => self.start_shootout(now),
+            SuddenDeathResolution::RefereeDecision => {}
+        }
+        Ok(())
+    }
+
+    fn handle_rugby_pen_shot_end(
+        &mut self,
+        now: Instant,
+        start_time: Instant,
+        time_remaining_at_start: Duration,
+    ) -> Result<()> {
         info!(
             "{} Handling end of rugby penalty shot",
             self.status_string(now)
@@ -1315,6 +2065,9 @@ impl TournamentManager {
         if let ClockState::Stopped { clock_time } = self.clock_state {
             if clock_time == Duration::ZERO {
                 match self.current_period {
+                    // A practice half has just started, so there is no period to end
+                    GamePeriod::FirstHalf | GamePeriod::SecondHalf if self.config.practice_mode => {
+                    }
                     GamePeriod::FirstHalf => {
                         self.end_first_half(now);
                     }
@@ -1328,17 +2081,21 @@ impl TournamentManager {
                         self.end_overtime_second_half(now);
                     }
                     GamePeriod::SuddenDeath => {
-                        error!(
-                            "{} Penalty shot ended during sudden death with clock stopped",
-                            self.status_string(now)
-                        );
-                        return Err(TournamentManagerError::InvalidState);
+                        if self.current_period.counts_up(&self.config) {
+                            error!(
+                                "{} Penalty shot ended during sudden death with clock stopped",
+                                self.status_string(now)
+                            );
+                            return Err(TournamentManagerError::InvalidState);
+                        }
+                        self.end_sudden_death(now);
                     }
                     GamePeriod::BetweenGames
                     | GamePeriod::HalfTime
                     | GamePeriod::PreOvertime
                     | GamePeriod::OvertimeHalfTime
-                    | GamePeriod::PreSuddenDeath => {
+                    | GamePeriod::PreSuddenDeath
+                    | GamePeriod::Shootout => {
                         error!(
                             "{} Impossible state: penalty shot ended during non-play period",
                             self.status_string(now)
@@ -1347,9 +2104,17 @@ impl TournamentManager {
                     }
                 }
             }
-            self.clock_state = ClockState::CountingDown {
-                start_time: min(now, start_time + time_remaining_at_start),
-                time_remaining_at_start: self.current_period.duration(&self.config).unwrap(),
+            self.timeout_state = TimeoutState::None;
+            // Ending the period may have also ended the game, started a shootout or left sudden
+            // death waiting for a referee decision, none of which has a period clock to restart
+            if !self.awaiting_sudden_death_decision() {
+                let period_start = min(now, start_time + time_remaining_at_start);
+                if let Some(period_duration) = self.current_period_duration(period_start) {
+                    self.clock_state = ClockState::CountingDown {
+                        start_time: period_start,
+                        time_remaining_at_start: period_duration,
+                    }
+                }
             }
         }
         self.timeout_state = TimeoutState::None;
@@ -1361,23 +2126,24 @@ impl TournamentManager {
         self.start_stop_rx.clone()
     }
 
-    // Returns true if the clock was started, false if it was already running
+    // Returns true if the clock was started, false if it was already running or there is no game
+    // clock (during a shootout)
     fn start_game_clock(&mut self, now: Instant) -> bool {
+        if self.current_period == GamePeriod::Shootout {
+            return false;
+        }
         if let ClockState::Stopped { clock_time } = self.clock_state {
             info!("{} Starting the game clock", self.status_string(now));
-            match self.current_period {
-                GamePeriod::SuddenDeath => {
-                    self.clock_state = ClockState::CountingUp {
-                        start_time: now,
-                        time_at_start: clock_time,
-                    };
-                }
-                _ => {
-                    self.clock_state = ClockState::CountingDown {
-                        start_time: now,
-                        time_remaining_at_start: clock_time,
-                    };
-                }
+            if self.current_period.counts_up(&self.config) {
+                self.clock_state = ClockState::CountingUp {
+                    start_time: now,
+                    time_at_start: clock_time,
+                };
+            } else {
+                self.clock_state = ClockState::CountingDown {
+                    start_time: now,
+                    time_remaining_at_start: clock_time,
+                };
             }
             true
         } else {
@@ -1409,6 +2175,10 @@ impl TournamentManager {
     pub fn start_clock(&mut self, now: Instant) {
         let mut need_to_send = false;
         let status_str = self.status_string(now);
+        if self.session_suspended_at.is_some() {
+            warn!("{status_str} Not starting the clock while the session is suspended");
+            return;
+        }
         match &mut self.timeout_state {
             TimeoutState::None => need_to_send = self.start_game_clock(now),
             TimeoutState::Team(_, ref mut cs) => {
@@ -1571,7 +2341,8 @@ impl TournamentManager {
             | GamePeriod::SecondHalf
             | GamePeriod::OvertimeFirstHalf
             | GamePeriod::OvertimeSecondHalf
-            | GamePeriod::SuddenDeath => return Err(TournamentManagerError::AlreadyInPlayPeriod),
+            | GamePeriod::SuddenDeath
+            | GamePeriod::Shootout => return Err(TournamentManagerError::AlreadyInPlayPeriod),
             GamePeriod::BetweenGames => {
                 self.start_game(now);
             }
@@ -1598,19 +2369,16 @@ impl TournamentManager {
                 need_cull = true;
             }
         }
-        self.clock_state = match self.current_period {
-            p @ GamePeriod::FirstHalf
-            | p @ GamePeriod::SecondHalf
-            | p @ GamePeriod::OvertimeFirstHalf
-            | p @ GamePeriod::OvertimeSecondHalf => ClockState::CountingDown {
-                start_time: now,
-                time_remaining_at_start: p.duration(&self.config).unwrap(),
-            },
-            GamePeriod::SuddenDeath => ClockState::CountingUp {
+        self.clock_state = if self.current_period.counts_up(&self.config) {
+            ClockState::CountingUp {
                 start_time: now,
                 time_at_start: Duration::ZERO,
-            },
-            _ => unreachable!(),
+            }
+        } else {
+            ClockState::CountingDown {
+                start_time: now,
+                time_remaining_at_start: self.current_period_duration(now).unwrap(),
+            }
         };
         if need_cull {
             self.cull_penalties(now)?;
@@ -1638,26 +2406,108 @@ impl TournamentManager {
                 time % 60.0
             );
 
-            for pen in self
-                .penalties
-                .black
-                .iter_mut()
-                .chain(self.penalties.white.iter_mut())
-            {
-                if (pen.kind != PenaltyKind::TotalDismissal)
-                    && (pen.time_remaining(self.current_period, clock_time, &self.config)?
-                        > pen.kind.as_duration().unwrap())
-                {
+            self.restart_future_penalties(clock_time)?;
+            self.clock_state = ClockState::Stopped { clock_time };
+            Ok(())
+        } else {
+            Err(TournamentManagerError::ClockIsRunning)
+        }
+    }
+
+    /// Penalties that would have started after the new clock time are restarted at that time, so
+    /// that none of them has more time remaining than its duration
+    fn restart_future_penalties(&mut self, clock_time: Duration) -> Result<()> {
+        for pen in self
+            .penalties
+            .black
+            .iter_mut()
+            .chain(self.penalties.white.iter_mut())
+        {
+            if let Some(duration) = pen.duration(&self.config) {
+                if pen.time_remaining(self.current_period, clock_time, &self.config)? > duration {
                     pen.start_period = self.current_period;
                     pen.start_time = clock_time;
                 }
             }
+        }
+        Ok(())
+    }
 
-            self.clock_state = ClockState::Stopped { clock_time };
+    /// Returns `Ok` if the game can be moved to `period`, otherwise returns `Err` describing why
+    /// not
+    pub fn can_change_period(&self, period: GamePeriod) -> Result<()> {
+        if !matches!(self.timeout_state, TimeoutState::None) {
+            return Err(TournamentManagerError::AlreadyInTimeout(
+                self.timeout_state.as_snapshot(Instant::now()),
+            ));
+        }
+        if self.current_period == GamePeriod::BetweenGames && self.has_reset {
+            return Err(TournamentManagerError::GameAlreadyReset);
+        }
+        let allowed = match period {
+            GamePeriod::BetweenGames | GamePeriod::Shootout => false,
+            GamePeriod::FirstHalf | GamePeriod::HalfTime | GamePeriod::SecondHalf => true,
+            GamePeriod::PreOvertime
+            | GamePeriod::OvertimeFirstHalf
+            | GamePeriod::OvertimeHalfTime
+            | GamePeriod::OvertimeSecondHalf => self.config.overtime_allowed,
+            GamePeriod::PreSuddenDeath | GamePeriod::SuddenDeath => {
+                self.config.sudden_death_allowed
+            }
+        };
+        if allowed {
             Ok(())
         } else {
-            Err(TournamentManagerError::ClockIsRunning)
+            Err(TournamentManagerError::InvalidPeriodChange(period))
+        }
+    }
+
+    /// Moves the game to `period` with `clock_time` on the clock, to recover from a period that
+    /// was ended by mistake. A game that has ended can be reopened until it is reset
+    pub fn change_period(
+        &mut self,
+        period: GamePeriod,
+        clock_time: Duration,
+        now: Instant,
+    ) -> Result<()> {
+        if self.clock_is_running() {
+            return Err(TournamentManagerError::ClockIsRunning);
+        }
+        self.can_change_period(period)?;
+
+        info!(
+            "{} Changing period to {period} with {clock_time:?} on the clock",
+            self.status_string(now)
+        );
+
+        if self.current_period == GamePeriod::BetweenGames {
+            info!(
+                "{} Reopening game {}",
+                self.status_string(now),
+                self.game_number
+            );
+            for color in [Color::Black, Color::White] {
+                for penalty in self.penalties[color].iter() {
+                    self.current_game_stats.remove_penalty(penalty, color);
+                }
+            }
+            self.current_game_stats.remove_end_time();
+            if let Some(winner) = self.shootout_winner() {
+                info!(
+                    "{} Removing {winner}'s shootout win from the score",
+                    self.status_string(now)
+                );
+                self.scores[winner] = self.scores[winner].saturating_sub(1);
+                self.current_game_stats.remove_shootout_win();
+            }
         }
+        // A shootout can't be returned to, so any shootout is over after a period change
+        self.shootout = None;
+
+        self.current_period = period;
+        self.restart_future_penalties(clock_time)?;
+        self.clock_state = ClockState::Stopped { clock_time };
+        Ok(())
     }
 
     pub fn set_timeout_clock_time(&mut self, clock_time: Duration) -> Result<()> {
@@ -1765,6 +2615,10 @@ impl TournamentManager {
         trace!("Got current time: {cur_time:?}");
         let secs_in_period = cur_time.as_secs().try_into().ok()?;
         trace!("Got seconds remaining: {secs_in_period}");
+        let tenths_in_period = self
+            .current_period
+            .shows_tenths(cur_time, &self.config)
+            .then(|| (cur_time.subsec_millis() / 100) as u8);
 
         let b_penalties = self
             .penalties
@@ -1828,6 +2682,8 @@ impl TournamentManager {
             }
         }
 
+        self.check_penalty_expiries(cur_time);
+
         let next_period_len_secs = self
             .current_period
             .next_period_dur(&self.config)
@@ -1836,6 +2692,7 @@ impl TournamentManager {
         Some(GameSnapshot {
             current_period: self.current_period,
             secs_in_period,
+            tenths_in_period,
             timeout: self.timeout_state.as_snapshot(now),
             b_score: self.scores.black,
             w_score: self.scores.white,
@@ -1851,17 +2708,58 @@ impl TournamentManager {
             next_game_number: self.next_game_number(),
             tournament_id: 0,
             recent_goal: self.recent_goal.map(|(c, n, _, _)| (c, n)),
+            recent_penalty_expiries: self
+                .recent_penalty_expiries
+                .iter()
+                .map(|(c, n, _, _)| (*c, *n))
+                .collect(),
             next_period_len_secs,
+            shootout: self.shootout.clone(),
+            stop_clock_time: self.is_stop_clock_time(cur_time),
+            schedule_delay_secs: self.schedule_delay.as_secs().try_into().unwrap_or(u32::MAX),
         })
     }
 
+    /// Finds the penalties that have run out since the last check, so that the players who
+    /// may return can be announced
+    fn check_penalty_expiries(&mut self, cur_time: Duration) {
+        let period = self.current_period;
+
+        if let Some((last_per, last_time)) = self.last_expiry_check.replace((period, cur_time)) {
+            // All penalties end with the game, there's no need to announce them
+            if period != GamePeriod::BetweenGames {
+                for color in [Color::Black, Color::White] {
+                    for pen in self.penalties[color].iter() {
+                        if pen.is_complete(period, cur_time, &self.config) == Ok(true)
+                            && pen.is_complete(last_per, last_time, &self.config) == Ok(false)
+                        {
+                            info!("{color} player #{} may return", pen.player_number);
+                            self.recent_penalty_expiries.push((
+                                color,
+                                pen.player_number,
+                                period,
+                                cur_time,
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        self.recent_penalty_expiries
+            .retain(|(_, _, exp_per, exp_time)| {
+                *exp_per == period
+                    && max(*exp_time, cur_time) - min(*exp_time, cur_time) <= RECENT_EXPIRY_TIME
+            });
+    }
+
     pub fn next_update_time(&self, now: Instant) -> Option<Instant> {
         match (&self.timeout_state, self.current_period) {
             // cases where the clock is counting up
             (TimeoutState::Ref(cs), _) | (TimeoutState::PenaltyShot(cs), _) => cs
                 .clock_time(now)
                 .map(|ct| now + Duration::from_nanos(1_000_000_000 - ct.subsec_nanos() as u64)),
-            (TimeoutState::None, GamePeriod::SuddenDeath) => self
+            (TimeoutState::None, period) if period.counts_up(&self.config) => self
                 .clock_state
                 .clock_time(now)
                 .map(|ct| now + Duration::from_nanos(1_000_000_000 - ct.subsec_nanos() as u64)),
@@ -1874,10 +2772,10 @@ impl TournamentManager {
                     .clock_time(now)
                     .map(|ct| now + Duration::from_nanos(ct.subsec_nanos() as u64));
                 let time_to_period_update = self.clock_state.clock_time(now).map(|ct| {
-                    if period == GamePeriod::SuddenDeath {
+                    if period.counts_up(&self.config) {
                         now + Duration::from_nanos(1_000_000_000 - ct.subsec_nanos() as u64)
                     } else {
-                        now + Duration::from_nanos(ct.subsec_nanos() as u64)
+                        now + self.time_to_countdown_update(period, ct)
                     }
                 });
                 if cs.is_running() && !self.clock_state.is_running() {
@@ -1886,10 +2784,25 @@ impl TournamentManager {
                     time_to_period_update.or(time_to_pen_update)
                 }
             }
-            (TimeoutState::None, _) => self
+            (TimeoutState::None, period) => self
                 .clock_state
                 .clock_time(now)
-                .map(|ct| now + Duration::from_nanos(ct.subsec_nanos() as u64)),
+                .map(|ct| now + self.time_to_countdown_update(period, ct)),
+        }
+    }
+
+    /// The time until the displayed value of a game clock that is counting down changes. This is
+    /// every tenth of a second when tenths are being shown, otherwise every second. When the clock
+    /// is exactly on a tenth, the next update is a full tenth away
+    fn time_to_countdown_update(&self, period: GamePeriod, clock_time: Duration) -> Duration {
+        let nanos = clock_time.subsec_nanos() as u64;
+        if period.shows_tenths(clock_time, &self.config) {
+            match nanos % 100_000_000 {
+                0 => Duration::from_millis(100),
+                rem => Duration::from_nanos(rem),
+            }
+        } else {
+            Duration::from_nanos(nanos)
         }
     }
 
@@ -1922,6 +2835,7 @@ impl TournamentManager {
             GamePeriod::OvertimeSecondHalf => "OTSCNDH]",
             GamePeriod::PreSuddenDeath => "PRESDND]",
             GamePeriod::SuddenDeath => "SUDNDTH]",
+            GamePeriod::Shootout => "SHOOTOT]",
         });
 
         string
@@ -2265,8 +3179,28 @@ pub enum TournamentManagerError {
     InvalidWarnIndex(Color, usize),
     #[error("No {0:?} penalty exists at the index {1}")]
     InvalidFoulIndex(Option<Color>, usize),
+    #[error("No goal exists at the index {0}")]
+    InvalidGoalIndex(usize),
     #[error("Can't halt game from the current state")]
     InvalidState,
+    #[error("Shootout shots can only be recorded during a shootout")]
+    NotInShootout,
+    #[error("The mercy rule has not been reached")]
+    MercyRuleNotReached,
+    #[error("Sudden death is not waiting for a referee decision")]
+    NotAwaitingDecision,
+    #[error("There is no practice in progress")]
+    NotInPractice,
+    #[error("The practice is already in its second half")]
+    PracticeInSecondHalf,
+    #[error("Can't change the period to {0}")]
+    InvalidPeriodChange(GamePeriod),
+    #[error("The last game has already been reset")]
+    GameAlreadyReset,
+    #[error("The session is already suspended")]
+    SessionAlreadySuspended,
+    #[error("The session is not suspended")]
+    SessionNotSuspended,
     #[error("Next Game Info is needed to perform this action")]
     NoNextGameInfo,
     #[error("Penalty error: {0}")]
@@ -2303,9 +3237,13 @@ fn print_p_num_foul(num: Option<u8>) -> String {
 mod test {
     use super::TournamentManagerError as TMErr;
     use super::*;
+    use crate::config::CatchUpPolicy;
     use std::convert::TryInto;
     use std::sync::Once;
-    use uwh_common::game_snapshot::{PenaltySnapshot, PenaltyTime};
+    use uwh_common::{
+        game_snapshot::{PenaltySnapshot, PenaltyTime},
+        uwhscores::GameTimeouts,
+    };
 
     static INIT: Once = Once::new();
 
@@ -2497,6 +3435,186 @@ mod test {
         assert_eq!(tm.game_clock_time(now), Some(Duration::from_secs(14)));
     }
 
+    #[test]
+    fn test_schedule_catch_up() {
+        initialize();
+        // Total time between starts of games is nominally 35s
+        let config = GameConfig {
+            half_play_duration: Duration::from_secs(10),
+            half_time_duration: Duration::from_secs(6),
+            nominal_break: Duration::from_secs(9),
+            minimum_break: Duration::from_secs(4),
+            overtime_allowed: false,
+            sudden_death_allowed: false,
+            ..Default::default()
+        };
+        let mut tm = TournamentManager::new(config);
+        assert_eq!(tm.schedule_drift(Instant::now()), None);
+
+        let start = Instant::now();
+        tm.start_clock(start);
+        tm.start_play_now(start).unwrap();
+        assert_eq!(tm.schedule_drift(start), Some(Duration::ZERO));
+
+        // Fall 1s behind during the first half
+        tm.stop_clock(start).unwrap();
+        tm.set_period_and_game_clock_time(GamePeriod::FirstHalf, Duration::from_secs(1));
+        let mut now = start + Duration::from_secs(15);
+        tm.start_clock(now);
+        assert_eq!(tm.schedule_drift(now), Some(Duration::from_secs(1)));
+
+        // The half time is shortened to catch up
+        tm.set_catch_up(CatchUp {
+            policy: CatchUpPolicy::ShortenHalfTime,
+            min_half_time_secs: 2,
+            min_break_secs: 1,
+        });
+        assert_eq!(tm.schedule_drift(now), Some(Duration::ZERO));
+        now += Duration::from_secs(2);
+        tm.update(now).unwrap();
+        assert_eq!(tm.current_period(), GamePeriod::HalfTime);
+        assert_eq!(tm.game_clock_time(now), Some(Duration::from_secs(4)));
+
+        // The half time is never shortened below the floor
+        tm.stop_clock(now).unwrap();
+        tm.set_period_and_game_clock_time(GamePeriod::FirstHalf, Duration::from_secs(1));
+        now = start + Duration::from_secs(21);
+        tm.start_clock(now);
+        assert_eq!(tm.schedule_drift(now), Some(Duration::from_secs(3)));
+        now += Duration::from_secs(1);
+        tm.update(now).unwrap();
+        assert_eq!(tm.current_period(), GamePeriod::HalfTime);
+        assert_eq!(tm.game_clock_time(now), Some(Duration::from_secs(2)));
+
+        // Breaks are only shortened below the minimum break when the policy allows it
+        tm.stop_clock(now).unwrap();
+        tm.set_period_and_game_clock_time(GamePeriod::SecondHalf, Duration::from_secs(1));
+        now = start + Duration::from_secs(34);
+        tm.start_clock(now);
+        assert_eq!(tm.schedule_drift(now), Some(Duration::from_secs(4)));
+        tm.set_catch_up(CatchUp {
+            policy: CatchUpPolicy::Both,
+            min_half_time_secs: 2,
+            min_break_secs: 1,
+        });
+        assert_eq!(tm.schedule_drift(now), Some(Duration::from_secs(1)));
+        now += Duration::from_secs(1);
+        tm.update(now).unwrap();
+        assert_eq!(tm.current_period(), GamePeriod::BetweenGames);
+        assert_eq!(tm.game_clock_time(now), Some(Duration::from_secs(1)));
+        assert_eq!(tm.schedule_drift(now), Some(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn test_schedule_drift_with_uwhscores_start() {
+        initialize();
+        let config = GameConfig {
+            half_play_duration: Duration::from_secs(60),
+            half_time_duration: Duration::from_secs(30),
+            nominal_break: Duration::from_secs(20),
+            minimum_break: Duration::from_secs(10),
+            overtime_allowed: false,
+            sudden_death_allowed: false,
+            ..Default::default()
+        };
+        let mut tm = TournamentManager::new(config);
+        // The wall clock keeps moving while the test runs, so compare to the nearest second
+        let secs = |duration: Option<Duration>| duration.map(|d| d.as_secs_f64().round() as u64);
+
+        let start = Instant::now();
+        let wall_start = OffsetDateTime::now_utc();
+        tm.start_play_now(start).unwrap();
+        tm.set_next_game(NextGameInfo {
+            number: 2,
+            timing: None,
+            start_time: Some(
+                PrimitiveDateTime::new(wall_start.date(), wall_start.time())
+                    + time::Duration::seconds(130),
+            ),
+        });
+
+        // The next game is projected to start at 40 + 30 + 60 + 10 = 140s, 10s late
+        tm.stop_clock(start).unwrap();
+        tm.set_period_and_game_clock_time(GamePeriod::FirstHalf, Duration::from_secs(40));
+        tm.start_clock(start);
+        assert_eq!(secs(tm.schedule_drift(start)), Some(10));
+
+        let mut now = start + Duration::from_secs(40);
+        tm.update(now).unwrap();
+        assert_eq!(tm.current_period(), GamePeriod::HalfTime);
+        assert_eq!(secs(tm.schedule_drift(now)), Some(10));
+
+        // With the half time shortened, the drift seen in the first half matches what happens
+        tm.stop_clock(now).unwrap();
+        tm.set_period_and_game_clock_time(GamePeriod::FirstHalf, Duration::from_secs(40));
+        tm.set_catch_up(CatchUp {
+            policy: CatchUpPolicy::ShortenHalfTime,
+            min_half_time_secs: 2,
+            min_break_secs: 1,
+        });
+        tm.start_clock(start);
+        assert_eq!(secs(tm.schedule_drift(start)), Some(0));
+
+        now = start + Duration::from_secs(40);
+        tm.update(now).unwrap();
+        assert_eq!(tm.current_period(), GamePeriod::HalfTime);
+        assert_eq!(secs(tm.game_clock_time(now)), Some(20));
+        assert_eq!(secs(tm.schedule_drift(now)), Some(0));
+    }
+
+    #[test]
+    fn test_suspend_session() {
+        initialize();
+        let config = GameConfig {
+            half_play_duration: Duration::from_secs(10),
+            half_time_duration: Duration::from_secs(3),
+            nominal_break: Duration::from_secs(9),
+            minimum_break: Duration::from_secs(2),
+            overtime_allowed: false,
+            sudden_death_allowed: false,
+            ..Default::default()
+        };
+        let mut tm = TournamentManager::new(config);
+
+        let start = Instant::now();
+        tm.start_clock(start);
+        tm.start_play_now(start).unwrap();
+        assert_eq!(tm.suspend_session(start), Err(TMErr::GameInProgress));
+        assert_eq!(tm.resume_session(start), Err(TMErr::SessionNotSuspended));
+
+        let mut now = start + Duration::from_secs(1);
+        tm.stop_clock(now).unwrap();
+        tm.set_period_and_game_clock_time(GamePeriod::SecondHalf, Duration::from_secs(1));
+        tm.start_clock(now);
+        now += Duration::from_secs(2);
+        tm.update(now).unwrap();
+        assert_eq!(tm.current_period(), GamePeriod::BetweenGames);
+        assert_eq!(tm.game_clock_time(now), Some(Duration::from_secs(29)));
+
+        // The countdown is frozen while the session is suspended
+        tm.suspend_session(now).unwrap();
+        assert!(!tm.clock_is_running());
+        assert_eq!(tm.suspend_session(now), Err(TMErr::SessionAlreadySuspended));
+        tm.start_clock(now);
+        assert!(!tm.clock_is_running());
+        now += Duration::from_secs(40);
+        assert_eq!(tm.game_clock_time(now), Some(Duration::from_secs(29)));
+
+        // Resuming shifts the schedule by the length of the suspension
+        assert_eq!(tm.resume_session(now), Ok(Duration::from_secs(40)));
+        assert!(tm.clock_is_running());
+        assert_eq!(tm.schedule_delay(), Duration::from_secs(40));
+        assert_eq!(
+            tm.next_scheduled_start,
+            Some(start + Duration::from_secs(72))
+        );
+        assert_eq!(tm.schedule_drift(now), Some(Duration::ZERO));
+        assert_eq!(tm.generate_snapshot(now).unwrap().schedule_delay_secs, 40);
+
+        tm.clear_schedule_delay();
+        assert_eq!(tm.schedule_delay(), Duration::ZERO);
+    }
+
     #[test]
     fn test_reset() {
         initialize();
@@ -4051,25 +5169,155 @@ mod test {
     }
 
     #[test]
-    fn test_end_sd() {
+    fn test_transition_psd_to_capped_sd() {
         initialize();
         let config = GameConfig {
             sudden_death_allowed: true,
-            half_play_duration: Duration::from_secs(9),
-            half_time_duration: Duration::from_secs(2),
-            nominal_break: Duration::from_secs(8),
-            minimum_break: Duration::from_secs(1),
+            sudden_death_max_duration: Duration::from_secs(10),
             ..Default::default()
         };
-        // 2*9 + 2 + 8 = 28 sec from game start to game start
-
-        let start = Instant::now();
-        let game_start = start - Duration::from_secs(17);
-        let second_time = start + Duration::from_secs(2);
-        let third_time = second_time + Duration::from_secs(2);
-        let fourth_time = third_time + Duration::from_secs(3);
-
-        let mut tm = TournamentManager::new(config);
+        test_transition(TransitionTestSetup {
+            config,
+            game_start_offset: 0,
+            start_period: GamePeriod::PreSuddenDeath,
+            remaining: 1,
+            score: None,
+            time_delay: 2,
+            end_period: GamePeriod::SuddenDeath,
+            end_clock_time: 9,
+        });
+    }
+
+    #[test]
+    fn test_transition_capped_sd_to_bg() {
+        initialize();
+        let config = GameConfig {
+            sudden_death_allowed: true,
+            sudden_death_max_duration: Duration::from_secs(10),
+            sudden_death_resolution: SuddenDeathResolution::Draw,
+            half_play_duration: Duration::from_secs(9),
+            half_time_duration: Duration::from_secs(2),
+            nominal_break: Duration::from_secs(8),
+            minimum_break: Duration::from_secs(1),
+            ..Default::default()
+        };
+        // 2*9 + 2 + 8 = 28 sec from game start to game start
+        test_transition(TransitionTestSetup {
+            config,
+            game_start_offset: -21,
+            start_period: GamePeriod::SuddenDeath,
+            remaining: 1,
+            score: Some(BlackWhiteBundle::new(2, 2)),
+            time_delay: 2,
+            end_period: GamePeriod::BetweenGames,
+            end_clock_time: 5,
+        });
+    }
+
+    #[test]
+    fn test_transition_capped_sd_to_shootout() {
+        initialize();
+        let config = GameConfig {
+            sudden_death_allowed: true,
+            sudden_death_max_duration: Duration::from_secs(10),
+            sudden_death_resolution: SuddenDeathResolution::Shootout,
+            ..Default::default()
+        };
+        test_transition(TransitionTestSetup {
+            config,
+            game_start_offset: 0,
+            start_period: GamePeriod::SuddenDeath,
+            remaining: 1,
+            score: Some(BlackWhiteBundle::new(2, 2)),
+            time_delay: 2,
+            end_period: GamePeriod::Shootout,
+            end_clock_time: 0,
+        });
+    }
+
+    #[test]
+    fn test_capped_sd_referee_decision() {
+        initialize();
+        let config = GameConfig {
+            sudden_death_allowed: true,
+            sudden_death_max_duration: Duration::from_secs(10),
+            sudden_death_resolution: SuddenDeathResolution::RefereeDecision,
+            ..Default::default()
+        };
+
+        let start = Instant::now();
+        let mid_time = start + Duration::from_millis(300);
+        let end_time = start + Duration::from_secs(10);
+
+        let setup_tm = || {
+            let mut tm = TournamentManager::new(config.clone());
+            tm.set_period_and_game_clock_time(GamePeriod::SuddenDeath, Duration::from_secs(10));
+            tm.set_scores(BlackWhiteBundle::new(2, 2), start);
+            tm.start_game_clock(start);
+            tm
+        };
+
+        let mut tm = setup_tm();
+        assert_eq!(
+            tm.game_clock_time(mid_time),
+            Some(Duration::from_millis(9_700))
+        );
+        assert_eq!(
+            tm.next_update_time(mid_time),
+            Some(start + Duration::from_millis(400))
+        );
+        assert!(!tm.awaiting_sudden_death_decision());
+        assert_eq!(
+            tm.decide_sudden_death(SuddenDeathResolution::Draw, mid_time),
+            Err(TMErr::NotAwaitingDecision)
+        );
+        assert_eq!(tm.would_end_game(end_time), Ok(false));
+
+        tm.update(end_time).unwrap();
+        assert_eq!(tm.current_period, GamePeriod::SuddenDeath);
+        assert!(!tm.clock_is_running());
+        assert_eq!(tm.game_clock_time(end_time), Some(Duration::ZERO));
+        assert!(tm.awaiting_sudden_death_decision());
+
+        // The referees award a goal
+        tm.set_scores(BlackWhiteBundle::new(2, 3), end_time);
+        assert_eq!(tm.current_period, GamePeriod::BetweenGames);
+
+        let mut tm = setup_tm();
+        tm.update(end_time).unwrap();
+        tm.decide_sudden_death(SuddenDeathResolution::Draw, end_time)
+            .unwrap();
+        assert_eq!(tm.current_period, GamePeriod::BetweenGames);
+        assert_eq!(tm.get_scores(), BlackWhiteBundle::new(2, 2));
+
+        let mut tm = setup_tm();
+        tm.update(end_time).unwrap();
+        tm.decide_sudden_death(SuddenDeathResolution::Shootout, end_time)
+            .unwrap();
+        assert_eq!(tm.current_period, GamePeriod::Shootout);
+        assert!(!tm.awaiting_sudden_death_decision());
+    }
+
+    #[test]
+    fn test_end_sd() {
+        initialize();
+        let config = GameConfig {
+            sudden_death_allowed: true,
+            half_play_duration: Duration::from_secs(9),
+            half_time_duration: Duration::from_secs(2),
+            nominal_break: Duration::from_secs(8),
+            minimum_break: Duration::from_secs(1),
+            ..Default::default()
+        };
+        // 2*9 + 2 + 8 = 28 sec from game start to game start
+
+        let start = Instant::now();
+        let game_start = start - Duration::from_secs(17);
+        let second_time = start + Duration::from_secs(2);
+        let third_time = second_time + Duration::from_secs(2);
+        let fourth_time = third_time + Duration::from_secs(3);
+
+        let mut tm = TournamentManager::new(config);
 
         let setup_tm = |tm: &mut TournamentManager| {
             tm.stop_game_clock(fourth_time).unwrap();
@@ -6002,4 +7250,849 @@ mod test {
         tm.timeout_state = TimeoutState::None;
         assert_eq!(Err(TMErr::InvalidState), tm.halt_clock(next_time, false));
     }
+
+    #[test]
+    fn test_edit_delete_goals() {
+        initialize();
+        let mut tm = TournamentManager::new(Default::default());
+        let now = Instant::now();
+
+        tm.set_period_and_game_clock_time(GamePeriod::FirstHalf, Duration::from_secs(400));
+        tm.add_score(Color::Black, 3, now);
+        tm.set_period_and_game_clock_time(GamePeriod::FirstHalf, Duration::from_secs(300));
+        tm.add_score(Color::White, 7, now);
+        tm.set_period_and_game_clock_time(GamePeriod::SecondHalf, Duration::from_secs(200));
+        tm.add_score(Color::Black, 11, now);
+
+        assert_eq!(tm.get_scores(), BlackWhiteBundle::new(2, 1));
+        assert_eq!(
+            tm.goals(),
+            vec![
+                Goal {
+                    color: Color::Black,
+                    player_number: Some(3),
+                    period: GamePeriod::FirstHalf,
+                    time: Duration::from_secs(400),
+                },
+                Goal {
+                    color: Color::White,
+                    player_number: Some(7),
+                    period: GamePeriod::FirstHalf,
+                    time: Duration::from_secs(300),
+                },
+                Goal {
+                    color: Color::Black,
+                    player_number: Some(11),
+                    period: GamePeriod::SecondHalf,
+                    time: Duration::from_secs(200),
+                },
+            ]
+        );
+
+        tm.edit_goal(0, Color::White, 4, Duration::from_secs(400), now)
+            .unwrap();
+        assert_eq!(tm.get_scores(), BlackWhiteBundle::new(1, 2));
+        assert_eq!(tm.goals()[0].color, Color::White);
+        assert_eq!(tm.goals()[0].player_number, Some(4));
+        assert_eq!(tm.goals()[0].time, Duration::from_secs(400));
+
+        tm.edit_goal(2, Color::Black, 12, Duration::from_secs(150), now)
+            .unwrap();
+        assert_eq!(tm.get_scores(), BlackWhiteBundle::new(1, 2));
+        assert_eq!(tm.goals()[2].time, Duration::from_secs(150));
+        assert_eq!(
+            tm.recent_goal.map(|(c, n, _, t)| (c, n, t)),
+            Some((Color::Black, 12, Duration::from_secs(150)))
+        );
+
+        tm.delete_goal(1, now).unwrap();
+        assert_eq!(tm.get_scores(), BlackWhiteBundle::new(1, 1));
+        assert_eq!(
+            tm.goals()
+                .iter()
+                .map(|g| (g.color, g.player_number))
+                .collect::<Vec<_>>(),
+            vec![(Color::White, Some(4)), (Color::Black, Some(12))]
+        );
+
+        tm.delete_goal(1, now).unwrap();
+        assert_eq!(tm.get_scores(), BlackWhiteBundle::new(0, 1));
+        assert_eq!(tm.recent_goal, None);
+
+        assert_eq!(
+            tm.edit_goal(1, Color::Black, 1, Duration::from_secs(100), now),
+            Err(TMErr::InvalidGoalIndex(1))
+        );
+        assert_eq!(tm.delete_goal(1, now), Err(TMErr::InvalidGoalIndex(1)));
+
+        // Changing the score of a game in sudden death waits for the scores to be confirmed
+        tm.set_period_and_game_clock_time(GamePeriod::SuddenDeath, Duration::from_secs(30));
+        tm.add_score(Color::Black, 2, now);
+        assert_eq!(tm.get_scores(), BlackWhiteBundle::new(1, 1));
+        assert!(!tm.scores_would_end_game());
+        tm.delete_goal(0, now).unwrap();
+        assert_eq!(tm.get_scores(), BlackWhiteBundle::new(1, 0));
+        assert_eq!(tm.current_period(), GamePeriod::SuddenDeath);
+        assert!(tm.scores_would_end_game());
+    }
+
+    #[test]
+    fn test_escalation() {
+        initialize();
+        let mut tm = TournamentManager::new(Default::default());
+        let now = Instant::now();
+        assert!(!EscalationRules::default().enabled);
+        let rules = EscalationRules {
+            enabled: true,
+            ..Default::default()
+        };
+
+        tm.set_period_and_game_clock_time(GamePeriod::FirstHalf, Duration::from_secs(400));
+        assert_eq!(tm.warning_escalation(&rules, Color::Black), None);
+
+        tm.add_warning(Color::Black, Some(3), Infraction::FreeArm, now)
+            .unwrap();
+        assert_eq!(tm.warning_escalation(&rules, Color::Black), None);
+
+        tm.add_warning(Color::Black, Some(4), Infraction::Obstruction, now)
+            .unwrap();
+        assert_eq!(tm.warning_escalation(&rules, Color::Black), None);
+
+        tm.add_warning(Color::Black, Some(3), Infraction::DelayOfGame, now)
+            .unwrap();
+        assert_eq!(
+            tm.warning_escalation(&rules, Color::Black),
+            Some(Escalation {
+                color: Color::Black,
+                player_number: Some(3),
+                infraction: Infraction::DelayOfGame,
+                reason: EscalationReason::PlayerWarnings(3),
+                count: 2,
+            })
+        );
+        assert_eq!(tm.warning_escalation(&rules, Color::White), None);
+
+        tm.add_warning(Color::Black, None, Infraction::Obstruction, now)
+            .unwrap();
+        assert_eq!(
+            tm.warning_escalation(&rules, Color::Black),
+            Some(Escalation {
+                color: Color::Black,
+                player_number: None,
+                infraction: Infraction::Obstruction,
+                reason: EscalationReason::InfractionWarnings(Infraction::Obstruction),
+                count: 2,
+            })
+        );
+
+        let disabled = EscalationRules {
+            enabled: false,
+            ..Default::default()
+        };
+        assert_eq!(tm.warning_escalation(&disabled, Color::Black), None);
+
+        // Once a threshold has been passed, later warnings don't prompt again
+        tm.add_warning(Color::Black, Some(3), Infraction::Obstruction, now)
+            .unwrap();
+        assert_eq!(tm.warning_escalation(&rules, Color::Black), None);
+
+        tm.add_foul(Some(Color::White), Some(1), Infraction::FreeArm, now)
+            .unwrap();
+        tm.add_foul(None, None, Infraction::FreeArm, now).unwrap();
+        tm.add_foul(Some(Color::White), Some(2), Infraction::FreeArm, now)
+            .unwrap();
+        assert_eq!(tm.foul_escalation(&rules, Color::White), None);
+        tm.add_foul(Some(Color::White), Some(5), Infraction::OutOfBounds, now)
+            .unwrap();
+        assert_eq!(
+            tm.foul_escalation(&rules, Color::White),
+            Some(Escalation {
+                color: Color::White,
+                player_number: None,
+                infraction: Infraction::OutOfBounds,
+                reason: EscalationReason::TeamFouls,
+                count: 3,
+            })
+        );
+        assert_eq!(tm.foul_escalation(&rules, Color::Black), None);
+        tm.add_foul(Some(Color::White), Some(6), Infraction::FreeArm, now)
+            .unwrap();
+        assert_eq!(tm.foul_escalation(&rules, Color::White), None);
+
+        let no_team_fouls = EscalationRules {
+            team_fouls: 0,
+            ..rules.clone()
+        };
+        assert_eq!(tm.foul_escalation(&no_team_fouls, Color::White), None);
+    }
+
+    #[test]
+    fn test_set_scores_syncs_goals() {
+        initialize();
+        let mut tm = TournamentManager::new(Default::default());
+        let now = Instant::now();
+
+        tm.set_period_and_game_clock_time(GamePeriod::FirstHalf, Duration::from_secs(400));
+        tm.add_score(Color::Black, 3, now);
+        tm.add_score(Color::Black, 5, now);
+        tm.add_score(Color::White, 7, now);
+
+        tm.set_period_and_game_clock_time(GamePeriod::FirstHalf, Duration::from_secs(100));
+        tm.set_scores(BlackWhiteBundle::new(1, 3), now);
+        assert_eq!(
+            tm.goals()
+                .iter()
+                .map(|g| (g.color, g.player_number, g.time))
+                .collect::<Vec<_>>(),
+            vec![
+                (Color::Black, Some(3), Duration::from_secs(400)),
+                (Color::White, Some(7), Duration::from_secs(400)),
+                (Color::White, None, Duration::from_secs(100)),
+                (Color::White, None, Duration::from_secs(100)),
+            ]
+        );
+
+        // The goals with an unknown scorer are uploaded without a cap number
+        let json = tm.current_game_stats.as_json();
+        assert_eq!(json.matches(r#""$type":"goal""#).count(), 4);
+        assert_eq!(json.matches("playerCapNumber").count(), 2);
+
+        tm.set_scores(BlackWhiteBundle::new(0, 1), now);
+        assert_eq!(
+            tm.goals()
+                .iter()
+                .map(|g| (g.color, g.player_number))
+                .collect::<Vec<_>>(),
+            vec![(Color::White, Some(7))]
+        );
+    }
+
+    #[test]
+    fn test_record_penalty_shot() {
+        initialize();
+        let mut tm = TournamentManager::new(Default::default());
+        let now = Instant::now();
+
+        tm.set_period_and_game_clock_time(GamePeriod::FirstHalf, Duration::from_secs(400));
+        tm.record_penalty_shot(Color::Black, 3, Infraction::FreeArm, false, now);
+        tm.set_period_and_game_clock_time(GamePeriod::SecondHalf, Duration::from_secs(200));
+        tm.record_penalty_shot(Color::White, 7, Infraction::Obstruction, true, now);
+        tm.add_score(Color::White, 7, now);
+
+        // Penalty shots are recorded separately from goals
+        assert_eq!(tm.get_scores(), BlackWhiteBundle::new(0, 1));
+        assert_eq!(tm.goals().len(), 1);
+
+        let json = tm.current_game_stats.as_json();
+        assert_eq!(json.matches(r#""$type":"penaltyShot""#).count(), 2);
+        assert!(json.contains(r#""infraction":"FreeArm","isGoal":false"#));
+        assert!(json.contains(r#""infraction":"Obstruction","isGoal":true"#));
+        assert_eq!(json.matches(r#""$type":"goal""#).count(), 1);
+    }
+
+    #[test]
+    fn test_mercy_rule() {
+        initialize();
+        let config = GameConfig {
+            mercy_rule_margin: 3,
+            mercy_rule_period: GamePeriod::SecondHalf,
+            ..Default::default()
+        };
+        let mut tm = TournamentManager::new(config);
+        let now = Instant::now();
+
+        tm.set_period_and_game_clock_time(GamePeriod::FirstHalf, Duration::from_secs(400));
+        tm.set_scores(BlackWhiteBundle::new(3, 0), now);
+        assert!(!tm.mercy_rule_reached());
+        assert_eq!(
+            tm.end_game_for_mercy_rule(now),
+            Err(TournamentManagerError::MercyRuleNotReached)
+        );
+
+        tm.set_period_and_game_clock_time(GamePeriod::SecondHalf, Duration::from_secs(300));
+        tm.set_scores(BlackWhiteBundle::new(3, 1), now);
+        assert!(!tm.mercy_rule_reached());
+        tm.add_score(Color::Black, 4, now);
+        assert!(tm.mercy_rule_reached());
+
+        tm.end_game_for_mercy_rule(now).unwrap();
+        assert_eq!(tm.current_period(), GamePeriod::BetweenGames);
+        assert_eq!(tm.get_scores(), BlackWhiteBundle::new(4, 1));
+        let json = tm.last_game_stats().unwrap().as_json();
+        assert!(
+            json.contains(r#""$type":"mercyRule","gamePeriod":"SecondHalf","periodTime":300.0"#)
+        );
+
+        let disabled = GameConfig {
+            mercy_rule_margin: 0,
+            ..Default::default()
+        };
+        let mut tm = TournamentManager::new(disabled);
+        tm.set_period_and_game_clock_time(GamePeriod::SecondHalf, Duration::from_secs(300));
+        tm.set_scores(BlackWhiteBundle::new(20, 0), now);
+        assert!(!tm.mercy_rule_reached());
+    }
+
+    #[test]
+    fn test_stop_clock_time() {
+        initialize();
+        let config = GameConfig {
+            stop_clock_duration: Duration::from_secs(60),
+            stop_clock_in_overtime: false,
+            ..Default::default()
+        };
+        let start = Instant::now();
+        let goal_time = start + Duration::from_secs(1);
+
+        let setup_tm = |config: &GameConfig, period, secs| {
+            let mut tm = TournamentManager::new(config.clone());
+            tm.set_period_and_game_clock_time(period, Duration::from_secs(secs));
+            tm.start_clock(start);
+            assert!(tm.clock_is_running());
+            tm
+        };
+
+        // Goals before the window don't stop the clock
+        let mut tm = setup_tm(&config, GamePeriod::SecondHalf, 120);
+        assert!(!tm.in_stop_clock_time(goal_time));
+        tm.add_score(Color::Black, 3, goal_time);
+        assert!(tm.clock_is_running());
+        assert!(!tm.generate_snapshot(goal_time).unwrap().stop_clock_time);
+
+        let mut tm = setup_tm(&config, GamePeriod::FirstHalf, 30);
+        tm.add_score(Color::Black, 3, goal_time);
+        assert!(tm.clock_is_running());
+
+        // Goals in the window do
+        let mut tm = setup_tm(&config, GamePeriod::SecondHalf, 30);
+        assert!(tm.in_stop_clock_time(goal_time));
+        assert!(tm.generate_snapshot(goal_time).unwrap().stop_clock_time);
+        tm.add_score(Color::White, 5, goal_time);
+        assert!(!tm.clock_is_running());
+        assert_eq!(tm.get_scores(), BlackWhiteBundle::new(0, 1));
+        assert_eq!(
+            tm.game_clock_time(goal_time + Duration::from_secs(5)),
+            Some(Duration::from_secs(29))
+        );
+        assert!(
+            tm.generate_snapshot(goal_time + Duration::from_secs(5))
+                .unwrap()
+                .stop_clock_time
+        );
+
+        let mut tm = setup_tm(&config, GamePeriod::OvertimeSecondHalf, 30);
+        assert!(!tm.in_stop_clock_time(goal_time));
+        tm.add_score(Color::Black, 3, goal_time);
+        assert!(tm.clock_is_running());
+
+        let config = GameConfig {
+            stop_clock_in_overtime: true,
+            ..config
+        };
+        let mut tm = setup_tm(&config, GamePeriod::OvertimeSecondHalf, 30);
+        tm.add_score(Color::Black, 3, goal_time);
+        assert!(!tm.clock_is_running());
+
+        // Other whistles only stop the clock when the rules apply to every whistle
+        let mut tm = setup_tm(&config, GamePeriod::SecondHalf, 30);
+        tm.add_warning(Color::Black, Some(3), Infraction::FreeArm, goal_time)
+            .unwrap();
+        tm.add_foul(Some(Color::White), Some(4), Infraction::FreeArm, goal_time)
+            .unwrap();
+        tm.start_penalty(
+            Color::Black,
+            3,
+            PenaltyKind::OneMinute,
+            goal_time,
+            Infraction::FreeArm,
+        )
+        .unwrap();
+        assert!(tm.clock_is_running());
+
+        let whistle_config = GameConfig {
+            stop_clock_at_every_whistle: true,
+            ..config.clone()
+        };
+        let mut tm = setup_tm(&whistle_config, GamePeriod::SecondHalf, 30);
+        tm.add_warning(Color::Black, Some(3), Infraction::FreeArm, goal_time)
+            .unwrap();
+        assert!(!tm.clock_is_running());
+
+        let mut tm = setup_tm(&whistle_config, GamePeriod::SecondHalf, 30);
+        tm.add_foul(None, None, Infraction::FreeArm, goal_time)
+            .unwrap();
+        assert!(!tm.clock_is_running());
+
+        let mut tm = setup_tm(&whistle_config, GamePeriod::SecondHalf, 30);
+        tm.start_penalty(
+            Color::White,
+            5,
+            PenaltyKind::OneMinute,
+            goal_time,
+            Infraction::FreeArm,
+        )
+        .unwrap();
+        assert!(!tm.clock_is_running());
+
+        let mut tm = setup_tm(&whistle_config, GamePeriod::SecondHalf, 120);
+        tm.add_warning(Color::Black, Some(3), Infraction::FreeArm, goal_time)
+            .unwrap();
+        assert!(tm.clock_is_running());
+
+        // The rules are disabled with a zero length
+        let config = GameConfig {
+            stop_clock_duration: Duration::ZERO,
+            ..config
+        };
+        let mut tm = setup_tm(&config, GamePeriod::SecondHalf, 30);
+        assert!(!tm.in_stop_clock_time(goal_time));
+        tm.add_score(Color::Black, 3, goal_time);
+        assert!(tm.clock_is_running());
+    }
+
+    #[test]
+    fn test_practice() {
+        initialize();
+        let config = GameConfig {
+            practice_mode: true,
+            ..Default::default()
+        };
+        let mut tm = TournamentManager::new(config);
+        let start = Instant::now();
+
+        tm.start_play_now(start).unwrap();
+        assert_eq!(tm.current_period, GamePeriod::FirstHalf);
+        assert!(tm.in_practice());
+
+        // The clock counts up and never ends the period
+        let time = start + Duration::from_secs(3_000);
+        tm.update(time).unwrap();
+        assert_eq!(tm.current_period, GamePeriod::FirstHalf);
+        assert_eq!(tm.game_clock_time(time), Some(Duration::from_secs(3_000)));
+        assert_eq!(tm.would_end_game(time), Ok(false));
+
+        // Penalties still count down
+        tm.start_penalty(
+            Color::Black,
+            3,
+            PenaltyKind::OneMinute,
+            time,
+            Infraction::Unknown,
+        )
+        .unwrap();
+        let pen_time = time + Duration::from_secs(20);
+        let snapshot = tm.generate_snapshot(pen_time).unwrap();
+        assert_eq!(snapshot.secs_in_period, 3_020);
+        assert_eq!(snapshot.b_penalties[0].time, PenaltyTime::Seconds(40));
+
+        // Team timeouts resume the clock counting up
+        tm.start_team_timeout(Color::White, pen_time).unwrap();
+        let to_end = pen_time + tm.config.team_timeout_duration;
+        tm.update(to_end).unwrap();
+        assert_eq!(tm.timeout_state, TimeoutState::None);
+        assert_eq!(
+            tm.game_clock_time(to_end + Duration::from_secs(5)),
+            Some(Duration::from_secs(3_025))
+        );
+
+        // The refs can move on to half time, after which the second half counts up
+        let ht_start = to_end + Duration::from_secs(10);
+        tm.end_practice_half(ht_start).unwrap();
+        assert_eq!(tm.current_period, GamePeriod::HalfTime);
+        assert!(!tm.in_practice());
+        assert!(tm.clock_is_running());
+        let sh_start = ht_start + tm.config.half_time_duration;
+        tm.update(sh_start).unwrap();
+        assert_eq!(tm.current_period, GamePeriod::SecondHalf);
+        assert!(tm.in_practice());
+        let time = sh_start + Duration::from_secs(600);
+        tm.update(time).unwrap();
+        assert_eq!(tm.current_period, GamePeriod::SecondHalf);
+        assert_eq!(tm.game_clock_time(time), Some(Duration::from_secs(600)));
+        assert_eq!(tm.end_practice_half(time), Err(TMErr::PracticeInSecondHalf));
+
+        let end_time = time + Duration::from_secs(10);
+        tm.end_practice(end_time).unwrap();
+        assert_eq!(tm.current_period, GamePeriod::BetweenGames);
+        assert!(!tm.in_practice());
+        assert_eq!(tm.end_practice(end_time), Err(TMErr::NotInPractice));
+
+        let mut tm = TournamentManager::new(Default::default());
+        tm.start_play_now(start).unwrap();
+        assert!(!tm.in_practice());
+        assert_eq!(tm.end_practice(start), Err(TMErr::NotInPractice));
+    }
+
+    #[test]
+    fn test_tenths_in_snapshot() {
+        initialize();
+        let mut tm = TournamentManager::new(Default::default());
+        let start = Instant::now();
+
+        tm.set_period_and_game_clock_time(GamePeriod::SecondHalf, Duration::from_secs(61));
+        tm.start_game_clock(start);
+
+        // Whole seconds are shown until the final minute
+        let time = start + Duration::from_millis(650);
+        let snapshot = tm.generate_snapshot(time).unwrap();
+        assert_eq!(snapshot.secs_in_period, 60);
+        assert_eq!(snapshot.tenths_in_period, None);
+        assert_eq!(
+            tm.next_update_time(time),
+            Some(start + Duration::from_secs(1))
+        );
+
+        let time = start + Duration::from_millis(1_250);
+        let snapshot = tm.generate_snapshot(time).unwrap();
+        assert_eq!(snapshot.secs_in_period, 59);
+        assert_eq!(snapshot.tenths_in_period, Some(7));
+        assert_eq!(
+            tm.next_update_time(time),
+            Some(start + Duration::from_millis(1_300))
+        );
+
+        // On an exact tenth, the next update is a tenth later
+        let time = start + Duration::from_millis(1_300);
+        assert_eq!(
+            tm.next_update_time(time),
+            Some(start + Duration::from_millis(1_400))
+        );
+
+        // Breaks stay in whole seconds
+        let mut tm = TournamentManager::new(Default::default());
+        tm.set_period_and_game_clock_time(GamePeriod::HalfTime, Duration::from_secs(30));
+        tm.start_game_clock(start);
+        let time = start + Duration::from_millis(250);
+        assert_eq!(tm.generate_snapshot(time).unwrap().tenths_in_period, None);
+        assert_eq!(
+            tm.next_update_time(time),
+            Some(start + Duration::from_secs(1))
+        );
+    }
+
+    #[test]
+    fn test_shootout() {
+        initialize();
+        let config = GameConfig {
+            overtime_allowed: false,
+            sudden_death_allowed: false,
+            shootout_allowed: true,
+            shootout_rounds: 3,
+            ..Default::default()
+        };
+        let mut tm = TournamentManager::new(config);
+        let start = Instant::now();
+        tm.start_play_now(start).unwrap();
+        tm.stop_clock(start).unwrap();
+        tm.set_period_and_game_clock_time(GamePeriod::SecondHalf, Duration::from_secs(2));
+        tm.set_scores(BlackWhiteBundle::new(2, 2), start);
+        assert_eq!(
+            tm.record_shootout_shot(Color::Black, 4, true, start),
+            Err(TournamentManagerError::NotInShootout)
+        );
+        assert_eq!(tm.would_end_game(start + Duration::from_secs(3)), Ok(false));
+
+        tm.start_game_clock(start);
+        let now = start + Duration::from_secs(3);
+        tm.update(now).unwrap();
+        assert_eq!(tm.current_period(), GamePeriod::Shootout);
+        assert!(!tm.clock_is_running());
+        tm.start_clock(now);
+        assert!(!tm.clock_is_running());
+        assert_eq!(
+            tm.start_play_now(now),
+            Err(TournamentManagerError::AlreadyInPlayPeriod)
+        );
+
+        tm.record_shootout_shot(Color::Black, 4, true, now).unwrap();
+        tm.record_shootout_shot(Color::White, 7, false, now)
+            .unwrap();
+        tm.record_shootout_shot(Color::Black, 5, false, now)
+            .unwrap();
+        assert_eq!(tm.current_period(), GamePeriod::Shootout);
+
+        let snapshot = tm.generate_snapshot(now).unwrap();
+        let shootout = snapshot.shootout.unwrap();
+        assert_eq!(shootout.rounds, 3);
+        assert_eq!(
+            shootout.b_shots,
+            vec![
+                ShootoutShot {
+                    player_number: 4,
+                    scored: true
+                },
+                ShootoutShot {
+                    player_number: 5,
+                    scored: false
+                }
+            ]
+        );
+        assert_eq!(
+            shootout.w_shots,
+            vec![ShootoutShot {
+                player_number: 7,
+                scored: false
+            }]
+        );
+
+        // White can no longer catch up
+        tm.record_shootout_shot(Color::White, 8, false, now)
+            .unwrap();
+        tm.record_shootout_shot(Color::Black, 6, false, now)
+            .unwrap();
+        tm.record_shootout_shot(Color::White, 9, false, now)
+            .unwrap();
+        assert_eq!(tm.current_period(), GamePeriod::BetweenGames);
+        assert_eq!(tm.get_scores(), BlackWhiteBundle::new(3, 2));
+        // The extra point is not a goal
+        assert_eq!(tm.goals().len(), 4);
+        assert!(tm
+            .goals()
+            .iter()
+            .all(|goal| goal.period != GamePeriod::Shootout));
+        let json = tm.last_game_stats().unwrap().as_json();
+        assert!(
+            json.contains(r#""$type":"shootoutShot","playerCapNumber":4,"side":"dark","round":1,"#)
+        );
+        assert!(json.contains(r#""$type":"shootoutWin","side":"dark","#));
+        assert_eq!(
+            tm.record_shootout_shot(Color::Black, 4, true, now),
+            Err(TournamentManagerError::NotInShootout)
+        );
+
+        // Reopening the game takes the shootout win back off the score
+        tm.stop_clock(now).unwrap();
+        tm.change_period(GamePeriod::SecondHalf, Duration::from_secs(5), now)
+            .unwrap();
+        assert_eq!(tm.get_scores(), BlackWhiteBundle::new(2, 2));
+        assert_eq!(tm.goals().len(), 4);
+        assert!(!tm.current_game_stats.as_json().contains("shootoutWin"));
+        assert_eq!(tm.generate_snapshot(now).unwrap().shootout, None);
+
+        // Without a shootout a tie at the end of the second half ends the game
+        let config = GameConfig {
+            overtime_allowed: false,
+            sudden_death_allowed: false,
+            shootout_allowed: false,
+            ..Default::default()
+        };
+        let mut tm = TournamentManager::new(config);
+        tm.set_period_and_game_clock_time(GamePeriod::SecondHalf, Duration::from_secs(2));
+        tm.set_scores(BlackWhiteBundle::new(2, 2), start);
+        tm.start_game_clock(start);
+        tm.update(start + Duration::from_secs(3)).unwrap();
+        assert_eq!(tm.current_period(), GamePeriod::BetweenGames);
+    }
+
+    #[test]
+    fn test_penalty_expiries() {
+        initialize();
+        let config = GameConfig {
+            half_play_duration: Duration::from_secs(900),
+            ..Default::default()
+        };
+
+        let start = Instant::now();
+        let mut tm = TournamentManager::new(config);
+        tm.set_period_and_game_clock_time(GamePeriod::FirstHalf, Duration::from_secs(500));
+        tm.start_game_clock(start);
+        tm.start_penalty(
+            Color::Black,
+            4,
+            PenaltyKind::ThirtySecond,
+            start,
+            Infraction::Unknown,
+        )
+        .unwrap();
+        tm.start_penalty(
+            Color::White,
+            9,
+            PenaltyKind::OneMinute,
+            start,
+            Infraction::Unknown,
+        )
+        .unwrap();
+        tm.start_penalty(
+            Color::White,
+            2,
+            PenaltyKind::TotalDismissal,
+            start,
+            Infraction::Unknown,
+        )
+        .unwrap();
+
+        let snapshot_at = |tm: &mut TournamentManager, secs| {
+            let now = start + Duration::from_secs(secs);
+            tm.update(now).unwrap();
+            tm.generate_snapshot(now).unwrap().recent_penalty_expiries
+        };
+
+        assert_eq!(snapshot_at(&mut tm, 1), vec![]);
+        assert_eq!(snapshot_at(&mut tm, 29), vec![]);
+        assert_eq!(snapshot_at(&mut tm, 31), vec![(Color::Black, 4)]);
+        assert_eq!(snapshot_at(&mut tm, 35), vec![(Color::Black, 4)]);
+        assert_eq!(snapshot_at(&mut tm, 42), vec![]);
+        assert_eq!(snapshot_at(&mut tm, 59), vec![]);
+        assert_eq!(snapshot_at(&mut tm, 61), vec![(Color::White, 9)]);
+        assert_eq!(snapshot_at(&mut tm, 75), vec![]);
+        assert_eq!(snapshot_at(&mut tm, 400), vec![]);
+    }
+
+    #[test]
+    fn test_timing_rules_keep_local_settings() {
+        initialize();
+        let config = GameConfig {
+            td_team_short_duration: Duration::from_secs(120),
+            mercy_rule_margin: 10,
+            mercy_rule_period: GamePeriod::FirstHalf,
+            shootout_allowed: true,
+            shootout_rounds: 3,
+            sudden_death_max_duration: Duration::from_secs(300),
+            sudden_death_resolution: SuddenDeathResolution::Shootout,
+            stop_clock_duration: Duration::from_secs(120),
+            stop_clock_in_overtime: false,
+            stop_clock_at_every_whistle: true,
+            practice_mode: true,
+            ..Default::default()
+        };
+        let mut tm = TournamentManager::new(config.clone());
+        let now = Instant::now();
+
+        let timing = TimingRules {
+            game_timeouts: GameTimeouts {
+                allowed: 2,
+                duration: Duration::from_secs(45),
+                per_half: false,
+            },
+            half_duration: Duration::from_secs(600),
+            half_time_duration: Duration::from_secs(120),
+            min_game_break: Duration::from_secs(180),
+            overtime_allowed: false,
+            pre_overtime_break: None,
+            overtime_break_duration: None,
+            overtime_duration: None,
+            sudden_death_allowed: false,
+            pre_sudden_death_break: None,
+        };
+        tm.set_next_game(NextGameInfo {
+            number: 2,
+            timing: Some(timing),
+            start_time: None,
+        });
+        tm.apply_next_game_start(now).unwrap();
+
+        assert_eq!(tm.config().half_play_duration, Duration::from_secs(600));
+        assert_eq!(tm.config().team_timeouts_per_half, 2);
+        assert!(!tm.config().overtime_allowed);
+        let check_local_settings = |tm: &TournamentManager| {
+            assert_eq!(
+                tm.config().td_team_short_duration,
+                config.td_team_short_duration
+            );
+            assert_eq!(tm.config().mercy_rule_margin, config.mercy_rule_margin);
+            assert_eq!(tm.config().mercy_rule_period, config.mercy_rule_period);
+            assert_eq!(tm.config().shootout_allowed, config.shootout_allowed);
+            assert_eq!(tm.config().shootout_rounds, config.shootout_rounds);
+            assert_eq!(
+                tm.config().sudden_death_max_duration,
+                config.sudden_death_max_duration
+            );
+            assert_eq!(
+                tm.config().sudden_death_resolution,
+                config.sudden_death_resolution
+            );
+            assert_eq!(tm.config().stop_clock_duration, config.stop_clock_duration);
+            assert_eq!(
+                tm.config().stop_clock_in_overtime,
+                config.stop_clock_in_overtime
+            );
+            assert_eq!(
+                tm.config().stop_clock_at_every_whistle,
+                config.stop_clock_at_every_whistle
+            );
+            assert_eq!(tm.config().practice_mode, config.practice_mode);
+        };
+        check_local_settings(&tm);
+
+        tm.start_play_now(now).unwrap();
+        assert_eq!(tm.current_period(), GamePeriod::FirstHalf);
+        assert_eq!(tm.config().half_play_duration, Duration::from_secs(600));
+        check_local_settings(&tm);
+    }
+
+    #[test]
+    fn test_change_period() {
+        initialize();
+        let mut tm = TournamentManager::new(Default::default());
+        let start = Instant::now();
+        let penalty_count =
+            |tm: &TournamentManager| tm.current_game_stats.as_json().matches("penalty").count();
+
+        tm.start_play_now(start).unwrap();
+        assert_eq!(
+            tm.change_period(GamePeriod::SecondHalf, Duration::from_secs(30), start),
+            Err(TMErr::ClockIsRunning)
+        );
+        tm.stop_clock(start).unwrap();
+        tm.change_period(GamePeriod::SecondHalf, Duration::from_secs(30), start)
+            .unwrap();
+        assert_eq!(tm.current_period, GamePeriod::SecondHalf);
+        assert_eq!(tm.game_clock_time(start), Some(Duration::from_secs(30)));
+        assert_eq!(
+            tm.change_period(GamePeriod::Shootout, Duration::ZERO, start),
+            Err(TMErr::InvalidPeriodChange(GamePeriod::Shootout))
+        );
+        assert_eq!(
+            tm.change_period(GamePeriod::BetweenGames, Duration::ZERO, start),
+            Err(TMErr::InvalidPeriodChange(GamePeriod::BetweenGames))
+        );
+
+        tm.start_penalty(
+            Color::Black,
+            3,
+            PenaltyKind::OneMinute,
+            start,
+            Infraction::Unknown,
+        )
+        .unwrap();
+        tm.set_scores(BlackWhiteBundle { black: 1, white: 0 }, start);
+
+        // Let the game end by mistake
+        tm.start_clock(start);
+        let end = start + Duration::from_secs(31);
+        tm.update(end).unwrap();
+        assert_eq!(tm.current_period, GamePeriod::BetweenGames);
+        assert_eq!(penalty_count(&tm), 1);
+
+        // Reopening the game removes the penalty from the stats until the game ends again
+        tm.stop_clock(end).unwrap();
+        tm.change_period(GamePeriod::SecondHalf, Duration::from_secs(5), end)
+            .unwrap();
+        assert_eq!(tm.current_period, GamePeriod::SecondHalf);
+        assert_eq!(penalty_count(&tm), 0);
+        assert_eq!(tm.get_scores(), BlackWhiteBundle { black: 1, white: 0 });
+        let snapshot = tm.generate_snapshot(end).unwrap();
+        assert_eq!(snapshot.secs_in_period, 5);
+        assert_eq!(snapshot.b_penalties[0].time, PenaltyTime::Seconds(35));
+
+        // Going back before the penalty started restarts it
+        tm.change_period(GamePeriod::FirstHalf, Duration::from_secs(10), end)
+            .unwrap();
+        let snapshot = tm.generate_snapshot(end).unwrap();
+        assert_eq!(snapshot.current_period, GamePeriod::FirstHalf);
+        assert_eq!(snapshot.b_penalties[0].time, PenaltyTime::Seconds(60));
+
+        tm.start_ref_timeout(end).unwrap();
+        assert!(matches!(
+            tm.can_change_period(GamePeriod::SecondHalf),
+            Err(TMErr::AlreadyInTimeout(_))
+        ));
+
+        // Once the game has been reset it can't be reopened
+        let tm = TournamentManager::new(Default::default());
+        assert_eq!(
+            tm.can_change_period(GamePeriod::SecondHalf),
+            Err(TMErr::GameAlreadyReset)
+        );
+    }
 }
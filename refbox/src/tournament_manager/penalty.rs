@@ -45,6 +45,16 @@ pub(crate) struct Penalty {
 }
 
 impl Penalty {
+    /// The length of the penalty. For a TD this is the time the team plays short, if any
+    pub(crate) fn duration(&self, config: &GameConfig) -> Option<Duration> {
+        match self.kind {
+            PenaltyKind::TotalDismissal if !config.td_team_short_duration.is_zero() => {
+                Some(config.td_team_short_duration)
+            }
+            kind => kind.as_duration(),
+        }
+    }
+
     pub fn time_elapsed(
         &self,
         cur_per: GamePeriod,
@@ -78,7 +88,11 @@ impl Penalty {
         match cur_per.cmp(&self.start_period) {
             Ordering::Equal => {
                 if cur_per.penalties_run(config) {
-                    Ok(cur_per.time_between(self.start_time.try_into()?, cur_time.try_into()?))
+                    Ok(cur_per.time_between(
+                        self.start_time.try_into()?,
+                        cur_time.try_into()?,
+                        config,
+                    ))
                 } else {
                     Ok(SignedDuration::ZERO)
                 }
@@ -107,10 +121,9 @@ impl Penalty {
             Ok(SignedDuration::ZERO)
         } else {
             // In all other cases we do the normal calculation and return `None` if the
-            // penalty is a TD or an error occurred
+            // penalty is a TD without a team short time or an error occurred
             let duration: SignedDuration = self
-                .kind
-                .as_duration()
+                .duration(config)
                 .ok_or(PenaltyError::NoDuration)?
                 .try_into()?;
 
@@ -145,6 +158,14 @@ impl Penalty {
         config: &GameConfig,
     ) -> PenaltyResult<PenaltySnapshot> {
         let time = match self.time_remaining(cur_per, cur_time, config) {
+            // Once the team short time is over the dismissal is still shown until the game ends
+            Ok(dur)
+                if self.kind == PenaltyKind::TotalDismissal
+                    && !dur.is_positive()
+                    && cur_per != GamePeriod::BetweenGames =>
+            {
+                PenaltyTime::TotalDismissal
+            }
             Ok(dur) => {
                 if dur.is_negative() {
                     PenaltyTime::Seconds(0)
@@ -537,4 +558,76 @@ mod test {
             Ok(false)
         );
     }
+
+    #[test]
+    fn test_td_team_short() {
+        initialize();
+        let config = GameConfig {
+            half_play_duration: Duration::from_secs(300),
+            td_team_short_duration: Duration::from_secs(120),
+            ..Default::default()
+        };
+
+        let penalty = Penalty {
+            player_number: 4,
+            kind: PenaltyKind::TotalDismissal,
+            start_time: Duration::from_secs(250),
+            start_period: GamePeriod::FirstHalf,
+            start_instant: Instant::now(),
+            infraction: Infraction::Unknown,
+        };
+        let snapshot_time = |period, secs| {
+            penalty
+                .as_snapshot(period, Duration::from_secs(secs), &config)
+                .unwrap()
+                .time
+        };
+
+        assert_eq!(
+            penalty.time_remaining(GamePeriod::FirstHalf, Duration::from_secs(200), &config),
+            Ok(SignedDuration::seconds(70))
+        );
+        assert_eq!(
+            snapshot_time(GamePeriod::FirstHalf, 200),
+            PenaltyTime::Seconds(70)
+        );
+        assert_eq!(
+            snapshot_time(GamePeriod::FirstHalf, 131),
+            PenaltyTime::Seconds(1)
+        );
+        assert_eq!(
+            snapshot_time(GamePeriod::FirstHalf, 130),
+            PenaltyTime::TotalDismissal
+        );
+        assert_eq!(
+            snapshot_time(GamePeriod::FirstHalf, 10),
+            PenaltyTime::TotalDismissal
+        );
+        assert_eq!(
+            snapshot_time(GamePeriod::BetweenGames, 10),
+            PenaltyTime::Seconds(0)
+        );
+
+        // The dismissal is never completed, even after the team short time
+        assert_eq!(
+            penalty.is_complete(GamePeriod::FirstHalf, Duration::from_secs(10), &config),
+            Ok(false)
+        );
+
+        let config = GameConfig {
+            td_team_short_duration: Duration::ZERO,
+            ..config
+        };
+        assert_eq!(
+            penalty.time_remaining(GamePeriod::FirstHalf, Duration::from_secs(200), &config),
+            Err(PenaltyError::NoDuration)
+        );
+        assert_eq!(
+            penalty
+                .as_snapshot(GamePeriod::FirstHalf, Duration::from_secs(200), &config)
+                .unwrap()
+                .time,
+            PenaltyTime::TotalDismissal
+        );
+    }
 }
@@ -0,0 +1,410 @@
+//! Interval programs for training sessions. A program is a list of named blocks, each of which
+//! repeats a work interval and a rest interval. The programs are stored in the config, and the
+//! `IntervalTimer` steps through one of them, driving the panels with the same snapshots that
+//! games use.
+
+use log::*;
+use serde::{Deserialize, Serialize};
+use tokio::time::{Duration, Instant};
+use toml::{Table, Value};
+use uwh_common::game_snapshot::{GamePeriod, GameSnapshot};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntervalBlock {
+    pub name: String,
+    pub work_secs: u32,
+    /// A rest of zero seconds goes straight on to the next work interval
+    pub rest_secs: u32,
+    pub repeats: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntervalProgram {
+    pub name: String,
+    pub blocks: Vec<IntervalBlock>,
+}
+
+impl IntervalProgram {
+    fn steps(&self) -> impl Iterator<Item = IntervalStep> + '_ {
+        self.blocks
+            .iter()
+            .enumerate()
+            .flat_map(|(block, def)| {
+                (1..=def.repeats).flat_map(move |repeat| {
+                    [
+                        (IntervalPhase::Work, def.work_secs),
+                        (IntervalPhase::Rest, def.rest_secs),
+                    ]
+                    .into_iter()
+                    .map(move |(phase, secs)| IntervalStep {
+                        block,
+                        repeat,
+                        phase,
+                        length: Duration::from_secs(secs.into()),
+                    })
+                })
+            })
+            .filter(|step| !step.length.is_zero())
+    }
+
+    /// The total length of the program
+    pub fn duration(&self) -> Duration {
+        self.steps().map(|step| step.length).sum()
+    }
+}
+
+pub fn default_programs() -> Vec<IntervalProgram> {
+    vec![
+        IntervalProgram {
+            name: "Sprints".to_string(),
+            blocks: vec![
+                IntervalBlock {
+                    name: "Warm Up".to_string(),
+                    work_secs: 300,
+                    rest_secs: 60,
+                    repeats: 1,
+                },
+                IntervalBlock {
+                    name: "Sprints".to_string(),
+                    work_secs: 30,
+                    rest_secs: 30,
+                    repeats: 8,
+                },
+                IntervalBlock {
+                    name: "Cool Down".to_string(),
+                    work_secs: 180,
+                    rest_secs: 0,
+                    repeats: 1,
+                },
+            ],
+        },
+        IntervalProgram {
+            name: "Breath Holds".to_string(),
+            blocks: vec![IntervalBlock {
+                name: "Holds".to_string(),
+                work_secs: 20,
+                rest_secs: 40,
+                repeats: 10,
+            }],
+        },
+    ]
+}
+
+pub fn get_program_list(table: &Table, key: &str, save: &mut Vec<IntervalProgram>) {
+    if let Some(Value::Array(list)) = table.get(key) {
+        let list: Vec<IntervalProgram> = list
+            .iter()
+            .filter_map(|program| match program.clone().try_into() {
+                Ok(program) => Some(program),
+                Err(e) => {
+                    warn!("Ignoring invalid training program: {e}");
+                    None
+                }
+            })
+            .collect();
+        if !list.is_empty() {
+            *save = list;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntervalPhase {
+    Work,
+    Rest,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct IntervalStep {
+    block: usize,
+    repeat: u16,
+    phase: IntervalPhase,
+    length: Duration,
+}
+
+/// Where the timer is in its program. `block` is the index of the block, and `repeat` counts
+/// from 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntervalStatus {
+    NotStarted,
+    Interval {
+        block: usize,
+        repeat: u16,
+        phase: IntervalPhase,
+        remaining: Duration,
+    },
+    Finished,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TimerClock {
+    Stopped {
+        elapsed: Duration,
+    },
+    Running {
+        start_time: Instant,
+        elapsed_at_start: Duration,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntervalTimer {
+    program: IntervalProgram,
+    clock: TimerClock,
+    /// The block, repeat and phase seen by the last call to `update()`
+    last_interval: Option<(usize, u16, IntervalPhase)>,
+}
+
+impl IntervalTimer {
+    pub fn new(program: IntervalProgram) -> Self {
+        Self {
+            program,
+            clock: TimerClock::Stopped {
+                elapsed: Duration::ZERO,
+            },
+            last_interval: None,
+        }
+    }
+
+    pub fn program(&self) -> &IntervalProgram {
+        &self.program
+    }
+
+    pub fn is_running(&self) -> bool {
+        matches!(self.clock, TimerClock::Running { .. })
+    }
+
+    pub fn start(&mut self, now: Instant) {
+        if let TimerClock::Stopped { elapsed } = self.clock {
+            info!("Starting the {} training program", self.program.name);
+            self.clock = TimerClock::Running {
+                start_time: now,
+                elapsed_at_start: elapsed,
+            };
+        }
+    }
+
+    pub fn stop(&mut self, now: Instant) {
+        if self.is_running() {
+            info!("Pausing the {} training program", self.program.name);
+            self.clock = TimerClock::Stopped {
+                elapsed: self.elapsed(now),
+            };
+        }
+    }
+
+    /// Goes back to the start of the program, with the timer stopped
+    pub fn reset(&mut self) {
+        self.clock = TimerClock::Stopped {
+            elapsed: Duration::ZERO,
+        };
+        self.last_interval = None;
+    }
+
+    fn elapsed(&self, now: Instant) -> Duration {
+        match self.clock {
+            TimerClock::Stopped { elapsed } => elapsed,
+            TimerClock::Running {
+                start_time,
+                elapsed_at_start,
+            } => elapsed_at_start + now.saturating_duration_since(start_time),
+        }
+    }
+
+    pub fn status(&self, now: Instant) -> IntervalStatus {
+        let elapsed = self.elapsed(now);
+        if elapsed.is_zero() && !self.is_running() {
+            return IntervalStatus::NotStarted;
+        }
+
+        let mut step_start = Duration::ZERO;
+        for step in self.program.steps() {
+            let step_end = step_start + step.length;
+            if elapsed < step_end {
+                return IntervalStatus::Interval {
+                    block: step.block,
+                    repeat: step.repeat,
+                    phase: step.phase,
+                    remaining: step_end - elapsed,
+                };
+            }
+            step_start = step_end;
+        }
+        IntervalStatus::Finished
+    }
+
+    /// Stops the clock once the program has finished. Returns `true` if a new interval has
+    /// started or the program has finished since the last call, so that a cue can be played
+    pub fn update(&mut self, now: Instant) -> bool {
+        if !self.is_running() {
+            return false;
+        }
+
+        let status = self.status(now);
+        let interval = match status {
+            IntervalStatus::Interval {
+                block,
+                repeat,
+                phase,
+                ..
+            } => Some((block, repeat, phase)),
+            IntervalStatus::NotStarted | IntervalStatus::Finished => None,
+        };
+        let finished = status == IntervalStatus::Finished;
+        if finished {
+            info!("Finished the {} training program", self.program.name);
+            self.clock = TimerClock::Stopped {
+                elapsed: self.program.duration(),
+            };
+        }
+
+        let changed = finished || interval != self.last_interval;
+        self.last_interval = interval;
+        changed
+    }
+
+    /// Builds a snapshot for the panels. Work intervals are shown as a half (in green) and rests
+    /// as half time (in yellow)
+    pub fn generate_snapshot(&self, now: Instant) -> GameSnapshot {
+        let (current_period, secs_in_period) = match self.status(now) {
+            IntervalStatus::NotStarted => {
+                (GamePeriod::BetweenGames, self.program.duration().as_secs())
+            }
+            IntervalStatus::Interval {
+                phase, remaining, ..
+            } => (
+                match phase {
+                    IntervalPhase::Work => GamePeriod::FirstHalf,
+                    IntervalPhase::Rest => GamePeriod::HalfTime,
+                },
+                remaining.as_secs(),
+            ),
+            IntervalStatus::Finished => (GamePeriod::BetweenGames, 0),
+        };
+
+        GameSnapshot {
+            current_period,
+            secs_in_period: secs_in_period.try_into().unwrap_or(u32::MAX),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_program() -> IntervalProgram {
+        IntervalProgram {
+            name: "Test".to_string(),
+            blocks: vec![
+                IntervalBlock {
+                    name: "Warm Up".to_string(),
+                    work_secs: 10,
+                    rest_secs: 0,
+                    repeats: 1,
+                },
+                IntervalBlock {
+                    name: "Sets".to_string(),
+                    work_secs: 5,
+                    rest_secs: 3,
+                    repeats: 2,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_program_duration() {
+        assert_eq!(test_program().duration(), Duration::from_secs(26));
+    }
+
+    #[test]
+    fn test_interval_timer() {
+        let start = Instant::now();
+        let mut timer = IntervalTimer::new(test_program());
+        assert_eq!(timer.status(start), IntervalStatus::NotStarted);
+        assert_eq!(timer.generate_snapshot(start).secs_in_period, 26);
+
+        timer.start(start);
+        assert!(timer.update(start));
+        assert!(!timer.update(start + Duration::from_secs(4)));
+        assert_eq!(
+            timer.status(start + Duration::from_secs(4)),
+            IntervalStatus::Interval {
+                block: 0,
+                repeat: 1,
+                phase: IntervalPhase::Work,
+                remaining: Duration::from_secs(6),
+            }
+        );
+
+        // The warm up has no rest
+        assert_eq!(
+            timer.status(start + Duration::from_secs(10)),
+            IntervalStatus::Interval {
+                block: 1,
+                repeat: 1,
+                phase: IntervalPhase::Work,
+                remaining: Duration::from_secs(5),
+            }
+        );
+        assert!(timer.update(start + Duration::from_secs(10)));
+        let snapshot = timer.generate_snapshot(start + Duration::from_secs(16));
+        assert_eq!(snapshot.current_period, GamePeriod::HalfTime);
+        assert_eq!(snapshot.secs_in_period, 2);
+
+        assert!(timer.update(start + Duration::from_secs(16)));
+
+        // Pausing holds the timer in place
+        timer.stop(start + Duration::from_secs(16));
+        assert_eq!(
+            timer.status(start + Duration::from_secs(100)),
+            IntervalStatus::Interval {
+                block: 1,
+                repeat: 1,
+                phase: IntervalPhase::Rest,
+                remaining: Duration::from_secs(2),
+            }
+        );
+
+        let restart = start + Duration::from_secs(100);
+        timer.start(restart);
+        assert!(!timer.update(restart));
+        assert_eq!(
+            timer.status(restart + Duration::from_secs(2)),
+            IntervalStatus::Interval {
+                block: 1,
+                repeat: 2,
+                phase: IntervalPhase::Work,
+                remaining: Duration::from_secs(5),
+            }
+        );
+
+        let end = restart + Duration::from_secs(10);
+        assert_eq!(timer.status(end), IntervalStatus::Finished);
+        assert!(timer.update(end));
+        assert!(!timer.is_running());
+        assert_eq!(timer.status(end), IntervalStatus::Finished);
+        assert!(!timer.update(end));
+
+        timer.reset();
+        assert_eq!(timer.status(end), IntervalStatus::NotStarted);
+    }
+
+    #[test]
+    fn test_get_program_list() {
+        let mut table = Table::new();
+        table.insert(
+            "training_programs".to_string(),
+            Value::try_from(vec![test_program()]).unwrap(),
+        );
+        let mut programs = default_programs();
+        get_program_list(&table, "training_programs", &mut programs);
+        assert_eq!(programs, vec![test_program()]);
+
+        let mut programs = default_programs();
+        get_program_list(&Table::new(), "training_programs", &mut programs);
+        assert_eq!(programs, default_programs());
+    }
+}
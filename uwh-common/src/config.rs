@@ -1,3 +1,4 @@
+use crate::game_snapshot::GamePeriod;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 use toml::Table;
@@ -9,6 +10,25 @@ pub struct Game {
     pub team_timeouts_per_half: u16,
     pub overtime_allowed: bool,
     pub sudden_death_allowed: bool,
+    /// Whether a game that is still tied after overtime (when sudden death is not allowed) is
+    /// decided by a penalty shot shootout
+    pub shootout_allowed: bool,
+    /// The number of shots each team takes in a shootout before it goes to extra rounds
+    pub shootout_rounds: u8,
+    /// The goal lead at which a game can be ended early. A margin of zero disables the mercy rule
+    pub mercy_rule_margin: u8,
+    /// The earliest period in which the mercy rule can end a game
+    pub mercy_rule_period: GamePeriod,
+    /// How a game is decided when sudden death reaches its maximum length
+    pub sudden_death_resolution: SuddenDeathResolution,
+    /// Whether the stop-clock rules also apply at the end of the overtime second half
+    pub stop_clock_in_overtime: bool,
+    /// Whether the stop-clock rules stop the clock at every whistle that is recorded (warnings,
+    /// fouls and penalties), not only after goals
+    pub stop_clock_at_every_whistle: bool,
+    /// Whether games are untimed practices, where the clock counts up until the refs end the game
+    /// and the results are not uploaded
+    pub practice_mode: bool,
     #[serde(with = "secs_only_duration")]
     pub half_play_duration: Duration,
     #[serde(with = "secs_only_duration")]
@@ -25,12 +45,23 @@ pub struct Game {
     pub pre_overtime_break: Duration,
     #[serde(with = "secs_only_duration")]
     pub pre_sudden_death_duration: Duration,
+    /// The longest sudden death can last. A length of zero means sudden death has no limit
+    #[serde(with = "secs_only_duration")]
+    pub sudden_death_max_duration: Duration,
     #[serde(with = "secs_only_duration")]
     pub post_game_duration: Duration,
     #[serde(with = "secs_only_duration")]
     pub nominal_break: Duration,
     #[serde(with = "secs_only_duration")]
     pub minimum_break: Duration,
+    /// How long a team plays short after a Total Dismissal before a substitute may enter. A
+    /// length of zero means the team plays short for the rest of the game
+    #[serde(with = "secs_only_duration")]
+    pub td_team_short_duration: Duration,
+    /// How long before the end of the second half the game clock stops after every goal. A
+    /// length of zero disables the stop-clock rules
+    #[serde(with = "secs_only_duration")]
+    pub stop_clock_duration: Duration,
 }
 
 impl Default for Game {
@@ -39,6 +70,14 @@ impl Default for Game {
             team_timeouts_per_half: 1,
             overtime_allowed: true,
             sudden_death_allowed: true,
+            shootout_allowed: false,
+            shootout_rounds: 5,
+            mercy_rule_margin: 0,
+            mercy_rule_period: GamePeriod::SecondHalf,
+            sudden_death_resolution: SuddenDeathResolution::Draw,
+            stop_clock_in_overtime: true,
+            stop_clock_at_every_whistle: false,
+            practice_mode: false,
             half_play_duration: Duration::from_secs(900),
             half_time_duration: Duration::from_secs(180),
             team_timeout_duration: Duration::from_secs(60),
@@ -47,9 +86,12 @@ impl Default for Game {
             ot_half_time_duration: Duration::from_secs(180),
             pre_overtime_break: Duration::from_secs(180),
             pre_sudden_death_duration: Duration::from_secs(60),
+            sudden_death_max_duration: Duration::ZERO,
             post_game_duration: Duration::from_secs(120),
             nominal_break: Duration::from_secs(900),
             minimum_break: Duration::from_secs(240),
+            td_team_short_duration: Duration::ZERO,
+            stop_clock_duration: Duration::ZERO,
         }
     }
 }
@@ -60,6 +102,14 @@ impl Game {
             mut team_timeouts_per_half,
             mut overtime_allowed,
             mut sudden_death_allowed,
+            mut shootout_allowed,
+            mut shootout_rounds,
+            mut mercy_rule_margin,
+            mut mercy_rule_period,
+            mut sudden_death_resolution,
+            mut stop_clock_in_overtime,
+            mut stop_clock_at_every_whistle,
+            mut practice_mode,
             mut half_play_duration,
             mut half_time_duration,
             mut team_timeout_duration,
@@ -68,9 +118,12 @@ impl Game {
             mut ot_half_time_duration,
             mut pre_overtime_break,
             mut pre_sudden_death_duration,
+            mut sudden_death_max_duration,
             mut post_game_duration,
             mut nominal_break,
             mut minimum_break,
+            mut td_team_short_duration,
+            mut stop_clock_duration,
         } = Default::default();
 
         let process_duration = |old: &Table, name: &str, save: &mut Duration| {
@@ -100,6 +153,52 @@ impl Game {
                 sudden_death_allowed = old_sudden_death_allowed;
             }
         }
+        if let Some(old_shootout_allowed) = old.get("shootout_allowed") {
+            if let Some(old_shootout_allowed) = old_shootout_allowed.as_bool() {
+                shootout_allowed = old_shootout_allowed;
+            }
+        }
+        if let Some(old_shootout_rounds) = old.get("shootout_rounds") {
+            if let Some(old_shootout_rounds) = old_shootout_rounds.as_integer() {
+                if let Ok(old_shootout_rounds) = old_shootout_rounds.try_into() {
+                    shootout_rounds = old_shootout_rounds;
+                }
+            }
+        }
+        if let Some(old_mercy_rule_margin) = old.get("mercy_rule_margin") {
+            if let Some(old_mercy_rule_margin) = old_mercy_rule_margin.as_integer() {
+                if let Ok(old_mercy_rule_margin) = old_mercy_rule_margin.try_into() {
+                    mercy_rule_margin = old_mercy_rule_margin;
+                }
+            }
+        }
+        if let Some(old_mercy_rule_period) = old.get("mercy_rule_period") {
+            if let Ok(old_mercy_rule_period) = old_mercy_rule_period.clone().try_into() {
+                mercy_rule_period = old_mercy_rule_period;
+            }
+        }
+        if let Some(old_sudden_death_resolution) = old.get("sudden_death_resolution") {
+            if let Ok(old_sudden_death_resolution) = old_sudden_death_resolution.clone().try_into()
+            {
+                sudden_death_resolution = old_sudden_death_resolution;
+            }
+        }
+        if let Some(old_stop_clock_in_overtime) = old.get("stop_clock_in_overtime") {
+            if let Some(old_stop_clock_in_overtime) = old_stop_clock_in_overtime.as_bool() {
+                stop_clock_in_overtime = old_stop_clock_in_overtime;
+            }
+        }
+        if let Some(old_stop_clock_at_every_whistle) = old.get("stop_clock_at_every_whistle") {
+            if let Some(old_stop_clock_at_every_whistle) = old_stop_clock_at_every_whistle.as_bool()
+            {
+                stop_clock_at_every_whistle = old_stop_clock_at_every_whistle;
+            }
+        }
+        if let Some(old_practice_mode) = old.get("practice_mode") {
+            if let Some(old_practice_mode) = old_practice_mode.as_bool() {
+                practice_mode = old_practice_mode;
+            }
+        }
         process_duration(old, "half_play_duration", &mut half_play_duration);
         process_duration(old, "half_time_duration", &mut half_time_duration);
         process_duration(old, "team_timeout_duration", &mut team_timeout_duration);
@@ -112,14 +211,29 @@ impl Game {
             "pre_sudden_death_duration",
             &mut pre_sudden_death_duration,
         );
+        process_duration(
+            old,
+            "sudden_death_max_duration",
+            &mut sudden_death_max_duration,
+        );
         process_duration(old, "post_game_duration", &mut post_game_duration);
         process_duration(old, "nominal_break", &mut nominal_break);
         process_duration(old, "minimum_break", &mut minimum_break);
+        process_duration(old, "td_team_short_duration", &mut td_team_short_duration);
+        process_duration(old, "stop_clock_duration", &mut stop_clock_duration);
 
         Self {
             team_timeouts_per_half,
             overtime_allowed,
             sudden_death_allowed,
+            shootout_allowed,
+            shootout_rounds,
+            mercy_rule_margin,
+            mercy_rule_period,
+            sudden_death_resolution,
+            stop_clock_in_overtime,
+            stop_clock_at_every_whistle,
+            practice_mode,
             half_play_duration,
             half_time_duration,
             team_timeout_duration,
@@ -128,13 +242,28 @@ impl Game {
             ot_half_time_duration,
             pre_overtime_break,
             pre_sudden_death_duration,
+            sudden_death_max_duration,
             post_game_duration,
             nominal_break,
             minimum_break,
+            td_team_short_duration,
+            stop_clock_duration,
         }
     }
 }
 
+/// What happens when sudden death reaches its maximum length without a goal being scored
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SuddenDeathResolution {
+    /// The game ends as a draw
+    #[default]
+    Draw,
+    /// The game is decided by a penalty shot shootout
+    Shootout,
+    /// The clock stops and the referees decide how the game ends
+    RefereeDecision,
+}
+
 mod secs_only_duration {
     use serde::{self, Deserialize, Deserializer, Serializer};
     use std::time::Duration;
@@ -178,6 +307,26 @@ pub mod test {
             "sudden_death_allowed".to_string(),
             toml::Value::Boolean(false),
         );
+        old.insert("shootout_allowed".to_string(), toml::Value::Boolean(true));
+        old.insert("shootout_rounds".to_string(), toml::Value::Integer(3));
+        old.insert("mercy_rule_margin".to_string(), toml::Value::Integer(10));
+        old.insert(
+            "mercy_rule_period".to_string(),
+            toml::Value::String("FirstHalf".to_string()),
+        );
+        old.insert(
+            "sudden_death_resolution".to_string(),
+            toml::Value::String("RefereeDecision".to_string()),
+        );
+        old.insert(
+            "stop_clock_in_overtime".to_string(),
+            toml::Value::Boolean(false),
+        );
+        old.insert(
+            "stop_clock_at_every_whistle".to_string(),
+            toml::Value::Boolean(true),
+        );
+        old.insert("practice_mode".to_string(), toml::Value::Boolean(true));
         old.insert("half_play_duration".to_string(), toml::Value::Integer(123));
         old.insert("half_time_duration".to_string(), toml::Value::Integer(45));
         old.insert(
@@ -201,14 +350,34 @@ pub mod test {
             "pre_sudden_death_duration".to_string(),
             toml::Value::Integer(90),
         );
+        old.insert(
+            "sudden_death_max_duration".to_string(),
+            toml::Value::Integer(600),
+        );
         old.insert("post_game_duration".to_string(), toml::Value::Integer(12));
         old.insert("nominal_break".to_string(), toml::Value::Integer(345));
         old.insert("minimum_break".to_string(), toml::Value::Integer(111));
+        old.insert(
+            "td_team_short_duration".to_string(),
+            toml::Value::Integer(120),
+        );
+        old.insert("stop_clock_duration".to_string(), toml::Value::Integer(180));
 
         let gm = Game::migrate(&old);
         assert_eq!(gm.team_timeouts_per_half, 2);
         assert_eq!(gm.overtime_allowed, false);
         assert_eq!(gm.sudden_death_allowed, false);
+        assert!(gm.shootout_allowed);
+        assert_eq!(gm.shootout_rounds, 3);
+        assert_eq!(gm.mercy_rule_margin, 10);
+        assert_eq!(gm.mercy_rule_period, GamePeriod::FirstHalf);
+        assert_eq!(
+            gm.sudden_death_resolution,
+            SuddenDeathResolution::RefereeDecision
+        );
+        assert!(!gm.stop_clock_in_overtime);
+        assert!(gm.stop_clock_at_every_whistle);
+        assert!(gm.practice_mode);
         assert_eq!(gm.half_play_duration, Duration::from_secs(123));
         assert_eq!(gm.half_time_duration, Duration::from_secs(45));
         assert_eq!(gm.team_timeout_duration, Duration::from_secs(67));
@@ -217,8 +386,11 @@ pub mod test {
         assert_eq!(gm.ot_half_time_duration, Duration::from_secs(56));
         assert_eq!(gm.pre_overtime_break, Duration::from_secs(78));
         assert_eq!(gm.pre_sudden_death_duration, Duration::from_secs(90));
+        assert_eq!(gm.sudden_death_max_duration, Duration::from_secs(600));
         assert_eq!(gm.post_game_duration, Duration::from_secs(12));
         assert_eq!(gm.nominal_break, Duration::from_secs(345));
         assert_eq!(gm.minimum_break, Duration::from_secs(111));
+        assert_eq!(gm.td_team_short_duration, Duration::from_secs(120));
+        assert_eq!(gm.stop_clock_duration, Duration::from_secs(180));
     }
 }
@@ -10,9 +10,6 @@ use core::{cmp::min, time::Duration};
 use defmt::Format;
 use derivative::Derivative;
 use displaydoc::Display;
-use enum_derive_2018::EnumDisplay;
-use enum_iterator::Sequence;
-use macro_attr_2018::macro_attr;
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "std")]
 use time::Duration as SignedDuration;
@@ -20,17 +17,26 @@ use time::Duration as SignedDuration;
 const PANEL_PENALTY_COUNT: usize = 3;
 
 /// Game snapshot information that the LED matrices need. Excludes some fields, limits to three
-/// penalties (the three with the lowest remaining time), and places the penalties on a stack-based
-/// `ArrayVec`, instead of the heap-based `Vec`
+/// penalties per team, and places the penalties on a stack-based `ArrayVec`, instead of the
+/// heap-based `Vec`. When a team has more penalties than the panels can show, they are split into
+/// pages (see `GameSnapshotNoHeap::from_page`)
 #[derive(Debug, PartialEq, Eq, Default, Clone, Serialize, Deserialize)]
 pub struct GameSnapshotNoHeap {
     pub current_period: GamePeriod,
     pub secs_in_period: u16,
+    /// The tenths of a second to show after `secs_in_period`, if the clock is in its final minute
+    pub tenths_in_period: Option<u8>,
     pub timeout: TimeoutSnapshot,
     pub b_score: u8,
     pub w_score: u8,
     pub b_penalties: ArrayVec<PenaltySnapshot, PANEL_PENALTY_COUNT>,
     pub w_penalties: ArrayVec<PenaltySnapshot, PANEL_PENALTY_COUNT>,
+    /// The number of active black penalties that are not on the current page
+    pub b_hidden_penalties: u8,
+    /// The number of active white penalties that are not on the current page
+    pub w_hidden_penalties: u8,
+    pub b_shootout: ShootoutTally,
+    pub w_shootout: ShootoutTally,
     pub is_old_game: bool,
 }
 
@@ -41,6 +47,9 @@ pub struct GameSnapshotNoHeap {
 pub struct GameSnapshot {
     pub current_period: GamePeriod,
     pub secs_in_period: u32,
+    /// The tenths of a second to show after `secs_in_period`, if the clock is in the final minute
+    /// of a period that counts down. Consumers that ignore this will still see whole seconds.
+    pub tenths_in_period: Option<u8>,
     pub timeout: TimeoutSnapshot,
     pub b_score: u8,
     pub w_score: u8,
@@ -56,13 +65,44 @@ pub struct GameSnapshot {
     pub next_game_number: u32,
     pub tournament_id: u32,
     pub recent_goal: Option<(Color, u8)>,
+    /// The color and cap number of each player whose penalty has just run out
+    pub recent_penalty_expiries: Vec<(Color, u8)>,
     pub next_period_len_secs: Option<u32>,
+    /// The shots taken so far, if the game is being decided by a shootout
+    pub shootout: Option<ShootoutSnapshot>,
+    /// Whether the game is in the final minutes of a half where the clock stops after every goal
+    pub stop_clock_time: bool,
+    /// How far the published start times have been pushed back by suspended sessions
+    pub schedule_delay_secs: u32,
 }
 
 #[cfg(feature = "std")]
 impl From<GameSnapshot> for GameSnapshotNoHeap {
     fn from(snapshot: GameSnapshot) -> Self {
-        let process_penalties = |mut orig: Vec<PenaltySnapshot>| {
+        Self::from_page(snapshot, 0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl GameSnapshotNoHeap {
+    /// Converts a `GameSnapshot`, showing the `page`th page of each team's penalties.
+    ///
+    /// The panels have room for three penalties per team, or two if the team's score has two
+    /// digits. If a team has more penalties than that, they are shown one fewer at a time so that
+    /// the last slot can be used to indicate how many are hidden. `page` wraps around, so it can
+    /// simply be incremented to cycle through all the penalties.
+    pub fn from_page(snapshot: GameSnapshot, page: usize) -> Self {
+        Self::paged(snapshot, page, true)
+    }
+
+    /// Like `from_page`, but for the legacy frame, which can't carry the hidden penalty counts.
+    /// Every slot is used for penalties, so the hidden counts are left at zero.
+    pub fn from_legacy_page(snapshot: GameSnapshot, page: usize) -> Self {
+        Self::paged(snapshot, page, false)
+    }
+
+    fn paged(snapshot: GameSnapshot, page: usize, indicator_slot: bool) -> Self {
+        let process_penalties = |mut orig: Vec<PenaltySnapshot>, score: u8| {
             orig.retain(|pen| {
                 if let PenaltyTime::Seconds(secs) = pen.time {
                     secs != 0
@@ -71,9 +111,42 @@ impl From<GameSnapshot> for GameSnapshotNoHeap {
                 }
             });
             orig.sort_by(|a, b| a.time.cmp(&b.time));
-            orig.into_iter().take(3).collect()
+
+            let slots = if score < 10 {
+                PANEL_PENALTY_COUNT
+            } else {
+                PANEL_PENALTY_COUNT - 1
+            };
+            let total = orig.len();
+            if total <= slots {
+                return (orig.into_iter().collect(), 0);
+            }
+
+            let per_page = if indicator_slot { slots - 1 } else { slots };
+            let num_pages = total.div_ceil(per_page);
+            let shown: ArrayVec<_, PANEL_PENALTY_COUNT> = orig
+                .into_iter()
+                .skip((page % num_pages) * per_page)
+                .take(per_page)
+                .collect();
+            let hidden = if indicator_slot {
+                (total - shown.len()).try_into().unwrap_or(u8::MAX)
+            } else {
+                0
+            };
+            (shown, hidden)
         };
 
+        let (b_penalties, b_hidden_penalties) =
+            process_penalties(snapshot.b_penalties, snapshot.b_score);
+        let (w_penalties, w_hidden_penalties) =
+            process_penalties(snapshot.w_penalties, snapshot.w_score);
+
+        let (b_shootout, w_shootout) = snapshot
+            .shootout
+            .map(|shootout| (shootout.tally(Color::Black), shootout.tally(Color::White)))
+            .unwrap_or_default();
+
         Self {
             current_period: snapshot.current_period,
             secs_in_period: min(
@@ -83,11 +156,16 @@ impl From<GameSnapshot> for GameSnapshotNoHeap {
                     .unwrap_or(MAX_STRINGABLE_SECS),
                 MAX_STRINGABLE_SECS,
             ),
+            tenths_in_period: snapshot.tenths_in_period,
             timeout: snapshot.timeout,
             b_score: snapshot.b_score,
             w_score: snapshot.w_score,
-            b_penalties: process_penalties(snapshot.b_penalties),
-            w_penalties: process_penalties(snapshot.w_penalties),
+            b_penalties,
+            w_penalties,
+            b_hidden_penalties,
+            w_hidden_penalties,
+            b_shootout,
+            w_shootout,
             is_old_game: snapshot.is_old_game,
         }
     }
@@ -100,6 +178,102 @@ pub struct PenaltySnapshot {
     pub infraction: Infraction,
 }
 
+/// A single shot taken during a shootout
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub struct ShootoutShot {
+    pub player_number: u8,
+    pub scored: bool,
+}
+
+/// The number of shots a team has taken during a shootout, and how many of them scored
+#[derive(Debug, PartialEq, Eq, Default, Clone, Copy, Serialize, Deserialize)]
+pub struct ShootoutTally {
+    pub shots: u8,
+    pub goals: u8,
+}
+
+impl ShootoutTally {
+    /// The round that is being played, starting at 1. A round is over once both teams have shot
+    pub fn current_round(black: Self, white: Self) -> u8 {
+        if black.shots == white.shots {
+            black.shots.saturating_add(1)
+        } else {
+            black.shots.max(white.shots)
+        }
+    }
+}
+
+/// Every shot taken so far in a shootout, in order, along with the number of regular rounds
+#[cfg(feature = "std")]
+#[derive(Debug, PartialEq, Eq, Default, Clone, Serialize, Deserialize)]
+pub struct ShootoutSnapshot {
+    pub rounds: u8,
+    pub b_shots: Vec<ShootoutShot>,
+    pub w_shots: Vec<ShootoutShot>,
+}
+
+#[cfg(feature = "std")]
+impl ShootoutSnapshot {
+    pub fn shots(&self, color: Color) -> &[ShootoutShot] {
+        match color {
+            Color::Black => &self.b_shots,
+            Color::White => &self.w_shots,
+        }
+    }
+
+    pub fn tally(&self, color: Color) -> ShootoutTally {
+        let shots = self.shots(color);
+        ShootoutTally {
+            shots: shots.len().try_into().unwrap_or(u8::MAX),
+            goals: shots
+                .iter()
+                .filter(|shot| shot.scored)
+                .count()
+                .try_into()
+                .unwrap_or(u8::MAX),
+        }
+    }
+
+    /// The team that should take the next shot. Black shoots first in each round
+    pub fn next_shooter(&self) -> Color {
+        if self.w_shots.len() < self.b_shots.len() {
+            Color::White
+        } else {
+            Color::Black
+        }
+    }
+
+    /// The team that has won the shootout, if it has been decided.
+    ///
+    /// During the regular rounds the shootout is over as soon as one team can't catch up with the
+    /// shots it has left. After that, extra rounds are played until one team scores and the other
+    /// doesn't.
+    pub fn winner(&self) -> Option<Color> {
+        let b = self.tally(Color::Black);
+        let w = self.tally(Color::White);
+
+        if b.shots <= self.rounds && w.shots <= self.rounds {
+            let b_left = self.rounds - b.shots;
+            let w_left = self.rounds - w.shots;
+            if b.goals > w.goals + w_left {
+                Some(Color::Black)
+            } else if w.goals > b.goals + b_left {
+                Some(Color::White)
+            } else {
+                None
+            }
+        } else if b.shots == w.shots {
+            match b.goals.cmp(&w.goals) {
+                Ordering::Greater => Some(Color::Black),
+                Ordering::Less => Some(Color::White),
+                Ordering::Equal => None,
+            }
+        } else {
+            None
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub struct InfractionSnapshot {
     pub player_number: Option<u8>,
@@ -120,6 +294,7 @@ pub enum GamePeriod {
     OvertimeSecondHalf,
     PreSuddenDeath,
     SuddenDeath,
+    Shootout,
 }
 
 impl GamePeriod {
@@ -130,7 +305,8 @@ impl GamePeriod {
             | Self::HalfTime
             | Self::PreOvertime
             | Self::OvertimeHalfTime
-            | Self::PreSuddenDeath => false,
+            | Self::PreSuddenDeath
+            | Self::Shootout => false,
             Self::FirstHalf | Self::SecondHalf => true,
             Self::OvertimeFirstHalf | Self::OvertimeSecondHalf => config.overtime_allowed,
             Self::SuddenDeath => config.sudden_death_allowed,
@@ -140,7 +316,15 @@ impl GamePeriod {
     #[cfg(feature = "std")]
     pub fn duration(self, config: &Game) -> Option<Duration> {
         match self {
-            Self::BetweenGames | Self::SuddenDeath => None,
+            Self::BetweenGames | Self::Shootout => None,
+            Self::SuddenDeath => {
+                if config.sudden_death_max_duration.is_zero() {
+                    None
+                } else {
+                    Some(config.sudden_death_max_duration)
+                }
+            }
+            Self::FirstHalf if config.practice_mode => None,
             Self::FirstHalf | Self::SecondHalf => Some(config.half_play_duration),
             Self::HalfTime => Some(config.half_time_duration),
             Self::PreOvertime => Some(config.pre_overtime_break),
@@ -152,37 +336,65 @@ impl GamePeriod {
         }
     }
 
+    /// Whether the game clock counts up during this period. Sudden death only counts up when it
+    /// has no maximum length. In practice mode both halves count up
     #[cfg(feature = "std")]
-    pub fn time_elapsed_at(self, time: Duration, config: &Game) -> Option<SignedDuration> {
+    pub fn counts_up(self, config: &Game) -> bool {
         match self {
-            p @ Self::BetweenGames
-            | p @ Self::FirstHalf
-            | p @ Self::HalfTime
-            | p @ Self::SecondHalf
-            | p @ Self::PreOvertime
-            | p @ Self::OvertimeFirstHalf
-            | p @ Self::OvertimeHalfTime
-            | p @ Self::OvertimeSecondHalf
-            | p @ Self::PreSuddenDeath => p
-                .duration(config)
-                .and_then(|d| d.try_into().ok().map(|sd: SignedDuration| sd - time)),
-            Self::SuddenDeath => time.try_into().ok(),
+            Self::FirstHalf | Self::SecondHalf => config.practice_mode,
+            Self::BetweenGames
+            | Self::HalfTime
+            | Self::PreOvertime
+            | Self::OvertimeFirstHalf
+            | Self::OvertimeHalfTime
+            | Self::OvertimeSecondHalf
+            | Self::PreSuddenDeath => false,
+            Self::SuddenDeath => config.sudden_death_max_duration.is_zero(),
+            Self::Shootout => true,
         }
     }
 
+    /// Whether the game clock should show tenths of a second when `clock_time` is left. This is
+    /// only done in the final minute of a period of play that counts down
     #[cfg(feature = "std")]
-    pub fn time_between(self, start: SignedDuration, end: SignedDuration) -> SignedDuration {
-        match self {
+    pub fn shows_tenths(self, clock_time: Duration, config: &Game) -> bool {
+        let is_play_period = match self {
             Self::BetweenGames
-            | Self::FirstHalf
             | Self::HalfTime
-            | Self::SecondHalf
             | Self::PreOvertime
-            | Self::OvertimeFirstHalf
             | Self::OvertimeHalfTime
+            | Self::PreSuddenDeath
+            | Self::Shootout => false,
+            Self::FirstHalf
+            | Self::SecondHalf
+            | Self::OvertimeFirstHalf
             | Self::OvertimeSecondHalf
-            | Self::PreSuddenDeath => start - end,
-            Self::SuddenDeath => end - start,
+            | Self::SuddenDeath => true,
+        };
+        is_play_period && !self.counts_up(config) && clock_time < Duration::from_secs(60)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn time_elapsed_at(self, time: Duration, config: &Game) -> Option<SignedDuration> {
+        if self.counts_up(config) {
+            time.try_into().ok()
+        } else {
+            self.duration(config)
+                .and_then(|d| d.try_into().ok().map(|sd: SignedDuration| sd - time))
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub fn time_between(
+        self,
+        start: SignedDuration,
+        end: SignedDuration,
+        config: &Game,
+    ) -> SignedDuration {
+        if self.counts_up(config) {
+            end - start
+        } else {
+            start - end
         }
     }
 
@@ -197,7 +409,8 @@ impl GamePeriod {
             Self::OvertimeHalfTime => Some(Self::OvertimeSecondHalf),
             Self::OvertimeSecondHalf => Some(Self::PreSuddenDeath),
             Self::PreSuddenDeath => Some(Self::SuddenDeath),
-            Self::SuddenDeath => None,
+            Self::SuddenDeath => Some(Self::Shootout),
+            Self::Shootout => None,
         }
     }
 
@@ -205,7 +418,7 @@ impl GamePeriod {
     pub fn next_period_dur(self, config: &Game) -> Option<Duration> {
         match self.next_period()? {
             Self::BetweenGames => None,
-            Self::FirstHalf => Some(config.half_play_duration),
+            Self::FirstHalf => Self::FirstHalf.duration(config),
             Self::HalfTime => Some(config.half_time_duration),
             Self::SecondHalf => Some(config.half_play_duration),
             Self::PreOvertime => {
@@ -243,7 +456,14 @@ impl GamePeriod {
                     None
                 }
             }
-            Self::SuddenDeath => None,
+            Self::SuddenDeath => {
+                if config.sudden_death_allowed {
+                    Self::SuddenDeath.duration(config)
+                } else {
+                    None
+                }
+            }
+            Self::Shootout => None,
         }
     }
 }
@@ -261,6 +481,7 @@ impl core::fmt::Display for GamePeriod {
             GamePeriod::OvertimeSecondHalf => write!(f, "Overtime Second Half"),
             GamePeriod::PreSuddenDeath => write!(f, "Pre Sudden Death"),
             GamePeriod::SuddenDeath => write!(f, "Sudden Death"),
+            GamePeriod::Shootout => write!(f, "Shootout"),
         }
     }
 }
@@ -341,27 +562,83 @@ impl PartialOrd for PenaltyTime {
     }
 }
 
-macro_attr! {
-    #[derive(Derivative, Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, Sequence, EnumDisplay!)]
+/// An infraction that a player or team can be called for. The built in variants are the defaults
+/// for every mode, and `Custom` infractions are defined by the refbox configuration, identified by
+/// their `u8` id.
+#[derive(Derivative, Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[derivative(Default)]
+pub enum Infraction {
     #[derivative(Default)]
-    pub enum Infraction {
-        #[derivative(Default)]
-        Unknown,
-        StickInfringement,
-        IllegalAdvancement,
-        IllegalSubstitution,
-        IllegallyStoppingThePuck,
-        OutOfBounds,
-        GrabbingTheBarrier,
-        Obstruction,
-        DelayOfGame,
-        UnsportsmanlikeConduct,
-        FreeArm,
-        FalseStart,
+    Unknown,
+    StickInfringement,
+    IllegalAdvancement,
+    IllegalSubstitution,
+    IllegallyStoppingThePuck,
+    OutOfBounds,
+    GrabbingTheBarrier,
+    Obstruction,
+    DelayOfGame,
+    UnsportsmanlikeConduct,
+    FreeArm,
+    FalseStart,
+    Custom(u8),
+}
+
+impl core::fmt::Display for Infraction {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Unknown => write!(f, "Unknown"),
+            Self::StickInfringement => write!(f, "StickInfringement"),
+            Self::IllegalAdvancement => write!(f, "IllegalAdvancement"),
+            Self::IllegalSubstitution => write!(f, "IllegalSubstitution"),
+            Self::IllegallyStoppingThePuck => write!(f, "IllegallyStoppingThePuck"),
+            Self::OutOfBounds => write!(f, "OutOfBounds"),
+            Self::GrabbingTheBarrier => write!(f, "GrabbingTheBarrier"),
+            Self::Obstruction => write!(f, "Obstruction"),
+            Self::DelayOfGame => write!(f, "DelayOfGame"),
+            Self::UnsportsmanlikeConduct => write!(f, "UnsportsmanlikeConduct"),
+            Self::FreeArm => write!(f, "FreeArm"),
+            Self::FalseStart => write!(f, "FalseStart"),
+            Self::Custom(id) => write!(f, "Custom({id})"),
+        }
     }
 }
 
 impl Infraction {
+    /// All the infractions that don't need to be defined in the configuration
+    pub const BUILT_IN: [Self; 12] = [
+        Self::Unknown,
+        Self::StickInfringement,
+        Self::IllegalAdvancement,
+        Self::IllegalSubstitution,
+        Self::IllegallyStoppingThePuck,
+        Self::OutOfBounds,
+        Self::GrabbingTheBarrier,
+        Self::Obstruction,
+        Self::DelayOfGame,
+        Self::UnsportsmanlikeConduct,
+        Self::FreeArm,
+        Self::FalseStart,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Unknown => "Unknown",
+            Self::StickInfringement => "Stick Infringement",
+            Self::IllegalAdvancement => "Illegal Advancement",
+            Self::IllegalSubstitution => "Illegal Substitution",
+            Self::IllegallyStoppingThePuck => "Illegally Stopping The Puck",
+            Self::OutOfBounds => "Out Of Bounds",
+            Self::GrabbingTheBarrier => "Grabbing The Barrier",
+            Self::Obstruction => "Obstruction",
+            Self::DelayOfGame => "Delay Of Game",
+            Self::UnsportsmanlikeConduct => "Unsportsmanlike Conduct",
+            Self::FreeArm => "Free Arm",
+            Self::FalseStart => "False Start",
+            Self::Custom(_) => "Custom",
+        }
+    }
+
     pub fn short_name(self) -> &'static str {
         match self {
             Self::Unknown => "Unknown",
@@ -376,6 +653,7 @@ impl Infraction {
             Self::UnsportsmanlikeConduct => "Unsportsmanlike",
             Self::FreeArm => "Free Arm",
             Self::FalseStart => "False Start",
+            Self::Custom(_) => "Custom",
         }
     }
 }
@@ -415,6 +693,9 @@ impl Infraction {
             Self::FalseStart => {
                 &include_bytes!("../../refbox/resources/infractions/false_start.svg")[..]
             }
+            Self::Custom(_) => {
+                &include_bytes!("../../refbox/resources/infractions/unknown.svg")[..]
+            }
         }
     }
 }
@@ -428,6 +709,8 @@ pub enum EncodingError {
     PenaltyTimeTooLarge(u16),
     /// Timeout time was too large for a u16: {0}
     TimeoutTimeTooLarge(u16),
+    /// Tenths of a second was more than one digit: {0}
+    TenthsOutOfRange(u8),
 }
 
 #[cfg(feature = "std")]
@@ -440,6 +723,8 @@ pub enum DecodingError {
     InvalidTimeoutType(u16),
     /// Invalid game period: {0:#04x}
     InvalidGamePeriod(u8),
+    /// Invalid tenths of a second: {0:#04x}
+    InvalidTenths(u8),
 }
 
 #[cfg(feature = "std")]
@@ -531,6 +816,7 @@ impl GamePeriod {
             Self::OvertimeSecondHalf => 7,
             Self::PreSuddenDeath => 8,
             Self::SuddenDeath => 9,
+            Self::Shootout => 10,
         }
     }
 
@@ -546,18 +832,34 @@ impl GamePeriod {
             7 => Ok(Self::OvertimeSecondHalf),
             8 => Ok(Self::PreSuddenDeath),
             9 => Ok(Self::SuddenDeath),
+            10 => Ok(Self::Shootout),
             _ => Err(DecodingError::InvalidGamePeriod(val)),
         }
     }
 }
 
+/// Marks that the clock is only showing whole seconds
+const NO_TENTHS: u8 = 0xff;
+
 impl GameSnapshotNoHeap {
+    /// The length of the legacy frame, which all panels understand
     pub const ENCODED_LEN: usize = 19;
+    /// The length of the v2 frame, which extends the legacy frame with the hidden penalty counts,
+    /// the shootout tallies and the tenths of a second
+    pub const ENCODED_LEN_V2: usize = 25;
 
+    fn encode_period_info(&self, period: GamePeriod) -> u8 {
+        period.encode() | if self.is_old_game { 0x80 } else { 0x00 }
+    }
+
+    /// Encodes the legacy frame. The fields that only the v2 frame carries are dropped, and a
+    /// shootout is sent as sudden death, since legacy panels don't know the shootout period
     pub fn encode(&self) -> Result<[u8; Self::ENCODED_LEN], EncodingError> {
         let mut val = [0u8; Self::ENCODED_LEN];
-        val[0] = self.current_period.encode();
-        val[0] |= if self.is_old_game { 0x80 } else { 0x00 };
+        val[0] = self.encode_period_info(match self.current_period {
+            GamePeriod::Shootout => GamePeriod::SuddenDeath,
+            period => period,
+        });
         val[1..=2].copy_from_slice(&self.secs_in_period.to_be_bytes());
         val[3..=4].copy_from_slice(&self.timeout.encode()?);
         val[5] = self.b_score;
@@ -585,6 +887,29 @@ impl GameSnapshotNoHeap {
         Ok(val)
     }
 
+    /// Encodes the v2 frame. The first `ENCODED_LEN` bytes are laid out as in the legacy frame
+    pub fn encode_v2(&self) -> Result<[u8; Self::ENCODED_LEN_V2], EncodingError> {
+        let mut val = [0u8; Self::ENCODED_LEN_V2];
+        val[..Self::ENCODED_LEN].copy_from_slice(&self.encode()?);
+        val[0] = self.encode_period_info(self.current_period);
+
+        val[19] = (self.b_hidden_penalties.min(0x0f) << 4) | self.w_hidden_penalties.min(0x0f);
+
+        val[20] = self.b_shootout.shots;
+        val[21] = self.b_shootout.goals;
+        val[22] = self.w_shootout.shots;
+        val[23] = self.w_shootout.goals;
+
+        val[24] = match self.tenths_in_period {
+            Some(tenths) if tenths < 10 => tenths,
+            Some(tenths) => return Err(EncodingError::TenthsOutOfRange(tenths)),
+            None => NO_TENTHS,
+        };
+
+        Ok(val)
+    }
+
+    /// Decodes the legacy frame. The fields that only the v2 frame carries are left empty
     pub fn decode(bytes: &[u8; Self::ENCODED_LEN]) -> Result<Self, DecodingError> {
         let mut b_penalties = ArrayVec::new();
         let mut w_penalties = ArrayVec::new();
@@ -610,14 +935,40 @@ impl GameSnapshotNoHeap {
         Ok(Self {
             current_period: GamePeriod::decode(bytes[0] & 0x7f)?,
             secs_in_period: u16::from_be_bytes(*array_ref![bytes, 1, 2]),
+            tenths_in_period: None,
             timeout: TimeoutSnapshot::decode(array_ref![bytes, 3, 2])?,
             b_score: bytes[5],
             w_score: bytes[6],
             b_penalties,
             w_penalties,
+            b_hidden_penalties: 0,
+            w_hidden_penalties: 0,
+            b_shootout: Default::default(),
+            w_shootout: Default::default(),
             is_old_game: ((bytes[0] & 0x80) != 0x00),
         })
     }
+
+    pub fn decode_v2(bytes: &[u8; Self::ENCODED_LEN_V2]) -> Result<Self, DecodingError> {
+        Ok(Self {
+            tenths_in_period: match bytes[24] {
+                NO_TENTHS => None,
+                tenths if tenths < 10 => Some(tenths),
+                tenths => return Err(DecodingError::InvalidTenths(tenths)),
+            },
+            b_hidden_penalties: bytes[19] >> 4,
+            w_hidden_penalties: bytes[19] & 0x0f,
+            b_shootout: ShootoutTally {
+                shots: bytes[20],
+                goals: bytes[21],
+            },
+            w_shootout: ShootoutTally {
+                shots: bytes[22],
+                goals: bytes[23],
+            },
+            ..Self::decode(array_ref![bytes, 0, GameSnapshotNoHeap::ENCODED_LEN])?
+        })
+    }
 }
 
 #[cfg(test)]
@@ -810,6 +1161,54 @@ mod test {
             Some(Duration::from_secs(15))
         );
         assert_eq!(GamePeriod::SuddenDeath.duration(&config), None);
+
+        let config = Game {
+            sudden_death_max_duration: Duration::from_secs(17),
+            ..config
+        };
+        assert_eq!(
+            GamePeriod::SuddenDeath.duration(&config),
+            Some(Duration::from_secs(17))
+        );
+
+        let config = Game {
+            practice_mode: true,
+            ..config
+        };
+        assert_eq!(GamePeriod::FirstHalf.duration(&config), None);
+        assert!(GamePeriod::FirstHalf.counts_up(&config));
+        assert_eq!(GamePeriod::BetweenGames.next_period_dur(&config), None);
+    }
+
+    #[test]
+    fn test_period_shows_tenths() {
+        let config = Game::default();
+        let last_minute = Duration::from_millis(59_900);
+        let full_minute = Duration::from_secs(60);
+
+        assert!(GamePeriod::FirstHalf.shows_tenths(last_minute, &config));
+        assert!(!GamePeriod::FirstHalf.shows_tenths(full_minute, &config));
+        assert!(GamePeriod::SecondHalf.shows_tenths(Duration::ZERO, &config));
+        assert!(GamePeriod::OvertimeFirstHalf.shows_tenths(last_minute, &config));
+        assert!(GamePeriod::OvertimeSecondHalf.shows_tenths(last_minute, &config));
+        assert!(!GamePeriod::BetweenGames.shows_tenths(last_minute, &config));
+        assert!(!GamePeriod::HalfTime.shows_tenths(last_minute, &config));
+        assert!(!GamePeriod::PreSuddenDeath.shows_tenths(last_minute, &config));
+        assert!(!GamePeriod::Shootout.shows_tenths(last_minute, &config));
+        assert!(!GamePeriod::SuddenDeath.shows_tenths(last_minute, &config));
+
+        let config = Game {
+            sudden_death_max_duration: Duration::from_secs(120),
+            ..config
+        };
+        assert!(GamePeriod::SuddenDeath.shows_tenths(last_minute, &config));
+        assert!(!GamePeriod::SuddenDeath.shows_tenths(full_minute, &config));
+
+        let config = Game {
+            practice_mode: true,
+            ..config
+        };
+        assert!(!GamePeriod::FirstHalf.shows_tenths(last_minute, &config));
     }
 
     #[test]
@@ -862,6 +1261,15 @@ mod test {
         assert_eq!(GamePeriod::PreSuddenDeath.next_period_dur(&config), None);
         assert_eq!(GamePeriod::SuddenDeath.next_period_dur(&config), None);
 
+        let capped_config = Game {
+            sudden_death_max_duration: Duration::from_secs(17),
+            ..config.clone()
+        };
+        assert_eq!(
+            GamePeriod::PreSuddenDeath.next_period_dur(&capped_config),
+            Some(Duration::from_secs(17))
+        );
+
         let config = Game {
             overtime_allowed: false,
             sudden_death_allowed: false,
@@ -945,6 +1353,14 @@ mod test {
             GamePeriod::SuddenDeath.time_elapsed_at(Duration::from_secs(3), &config),
             Some(SignedDuration::seconds(3))
         );
+        let capped_config = Game {
+            sudden_death_max_duration: Duration::from_secs(17),
+            ..config.clone()
+        };
+        assert_eq!(
+            GamePeriod::SuddenDeath.time_elapsed_at(Duration::from_secs(3), &capped_config),
+            Some(SignedDuration::seconds(14))
+        );
 
         assert_eq!(
             GamePeriod::FirstHalf.time_elapsed_at(Duration::from_secs(9), &config),
@@ -982,26 +1398,54 @@ mod test {
 
     #[test]
     fn test_period_time_between() {
+        let config = Default::default();
         let mut period = GamePeriod::BetweenGames;
         while period != GamePeriod::SuddenDeath {
             assert_eq!(
-                period.time_between(SignedDuration::seconds(6), SignedDuration::seconds(2)),
+                period.time_between(
+                    SignedDuration::seconds(6),
+                    SignedDuration::seconds(2),
+                    &config
+                ),
                 SignedDuration::seconds(4)
             );
             assert_eq!(
-                period.time_between(SignedDuration::seconds(6), SignedDuration::seconds(10)),
+                period.time_between(
+                    SignedDuration::seconds(6),
+                    SignedDuration::seconds(10),
+                    &config
+                ),
                 SignedDuration::seconds(-4)
             );
             period = period.next_period().unwrap();
         }
         assert_eq!(
-            GamePeriod::SuddenDeath
-                .time_between(SignedDuration::seconds(6), SignedDuration::seconds(2)),
+            GamePeriod::SuddenDeath.time_between(
+                SignedDuration::seconds(6),
+                SignedDuration::seconds(2),
+                &config
+            ),
             SignedDuration::seconds(-4)
         );
         assert_eq!(
-            GamePeriod::SuddenDeath
-                .time_between(SignedDuration::seconds(6), SignedDuration::seconds(10)),
+            GamePeriod::SuddenDeath.time_between(
+                SignedDuration::seconds(6),
+                SignedDuration::seconds(10),
+                &config
+            ),
+            SignedDuration::seconds(4)
+        );
+
+        let config = Game {
+            sudden_death_max_duration: Duration::from_secs(60),
+            ..config
+        };
+        assert_eq!(
+            GamePeriod::SuddenDeath.time_between(
+                SignedDuration::seconds(6),
+                SignedDuration::seconds(2),
+                &config
+            ),
             SignedDuration::seconds(4)
         );
     }
@@ -1044,7 +1488,11 @@ mod test {
             GamePeriod::PreSuddenDeath.next_period(),
             Some(GamePeriod::SuddenDeath)
         );
-        assert_eq!(GamePeriod::SuddenDeath.next_period(), None);
+        assert_eq!(
+            GamePeriod::SuddenDeath.next_period(),
+            Some(GamePeriod::Shootout)
+        );
+        assert_eq!(GamePeriod::Shootout.next_period(), None);
     }
 
     #[test]
@@ -1052,23 +1500,49 @@ mod test {
         let mut state = GameSnapshotNoHeap {
             current_period: GamePeriod::BetweenGames,
             secs_in_period: 0,
+            tenths_in_period: None,
             timeout: TimeoutSnapshot::None,
             b_score: 0,
             w_score: 0,
             b_penalties: ArrayVec::new(),
             w_penalties: ArrayVec::new(),
+            b_hidden_penalties: 0,
+            w_hidden_penalties: 0,
+            b_shootout: ShootoutTally::default(),
+            w_shootout: ShootoutTally::default(),
             is_old_game: false,
         };
 
         let test_state =
             |state: &mut GameSnapshotNoHeap| -> Result<(), Box<dyn std::error::Error>> {
+                let serialization = state.encode_v2()?;
+                let mut recreated = GameSnapshotNoHeap::decode_v2(array_ref![
+                    serialization,
+                    0,
+                    GameSnapshotNoHeap::ENCODED_LEN_V2
+                ])?;
+                assert_eq!(state, &mut recreated);
+
+                // The legacy frame only carries the fields that all panels understand
+                let mut legacy = GameSnapshotNoHeap {
+                    current_period: match state.current_period {
+                        GamePeriod::Shootout => GamePeriod::SuddenDeath,
+                        period => period,
+                    },
+                    tenths_in_period: None,
+                    b_hidden_penalties: 0,
+                    w_hidden_penalties: 0,
+                    b_shootout: Default::default(),
+                    w_shootout: Default::default(),
+                    ..state.clone()
+                };
                 let serialization = state.encode()?;
                 let mut recreated = GameSnapshotNoHeap::decode(array_ref![
                     serialization,
                     0,
                     GameSnapshotNoHeap::ENCODED_LEN
                 ])?;
-                assert_eq!(state, &mut recreated);
+                assert_eq!(&mut legacy, &mut recreated);
                 Ok(())
             };
 
@@ -1134,6 +1608,17 @@ mod test {
 
         test_state(&mut state)?;
 
+        state.current_period = GamePeriod::SecondHalf;
+        state.secs_in_period = 9;
+        state.tenths_in_period = Some(7);
+        state.timeout = TimeoutSnapshot::None;
+
+        test_state(&mut state)?;
+
+        state.tenths_in_period = Some(10);
+        assert_eq!(state.encode_v2(), Err(EncodingError::TenthsOutOfRange(10)));
+        state.tenths_in_period = None;
+
         state.current_period = GamePeriod::OvertimeFirstHalf;
         state.secs_in_period = 300;
 
@@ -1156,6 +1641,190 @@ mod test {
 
         test_state(&mut state)?;
 
+        state.b_hidden_penalties = 2;
+        state.w_hidden_penalties = 5;
+
+        test_state(&mut state)?;
+
+        state.current_period = GamePeriod::Shootout;
+        state.b_shootout = ShootoutTally { shots: 4, goals: 3 };
+        state.w_shootout = ShootoutTally { shots: 3, goals: 1 };
+
+        test_state(&mut state)?;
+
         Ok(())
     }
+
+    #[test]
+    fn test_legacy_frame_layout() {
+        let mut state = GameSnapshotNoHeap {
+            current_period: GamePeriod::SecondHalf,
+            secs_in_period: 345,
+            tenths_in_period: Some(3),
+            timeout: TimeoutSnapshot::Black(16),
+            b_score: 2,
+            w_score: 5,
+            b_hidden_penalties: 4,
+            w_hidden_penalties: 1,
+            is_old_game: true,
+            ..Default::default()
+        };
+        state.b_penalties.push(PenaltySnapshot {
+            player_number: 1,
+            time: PenaltyTime::Seconds(48),
+            infraction: Infraction::Unknown,
+        });
+
+        let expected = [
+            0x83, 0x01, 0x59, 0x20, 0x10, 0x02, 0x05, 0x02, 0x30, 0xfe, 0x00, 0xfe, 0x00, 0xfe,
+            0x00, 0xfe, 0x00, 0xfe, 0x00,
+        ];
+        assert_eq!(state.encode(), Ok(expected));
+
+        let v2 = state.encode_v2().unwrap();
+        assert_eq!(v2[..GameSnapshotNoHeap::ENCODED_LEN], expected);
+        assert_eq!(v2[GameSnapshotNoHeap::ENCODED_LEN..], [0x41, 0, 0, 0, 0, 3]);
+
+        state.current_period = GamePeriod::Shootout;
+        assert_eq!(state.encode().unwrap()[0], 0x89);
+        assert_eq!(state.encode_v2().unwrap()[0], 0x8a);
+    }
+
+    #[test]
+    fn test_shootout_winner() {
+        let mut shootout = ShootoutSnapshot {
+            rounds: 3,
+            ..Default::default()
+        };
+        let shoot = |shootout: &mut ShootoutSnapshot, scored| {
+            let shot = ShootoutShot {
+                player_number: 1,
+                scored,
+            };
+            match shootout.next_shooter() {
+                Color::Black => shootout.b_shots.push(shot),
+                Color::White => shootout.w_shots.push(shot),
+            }
+        };
+
+        assert_eq!(shootout.next_shooter(), Color::Black);
+        shoot(&mut shootout, true);
+        assert_eq!(shootout.next_shooter(), Color::White);
+        assert_eq!(
+            ShootoutTally::current_round(
+                shootout.tally(Color::Black),
+                shootout.tally(Color::White)
+            ),
+            1
+        );
+        shoot(&mut shootout, false);
+        assert_eq!(shootout.winner(), None);
+        shoot(&mut shootout, true);
+        assert_eq!(shootout.winner(), None);
+        shoot(&mut shootout, false);
+        // Black leads 2-0 and White has only one shot left
+        assert_eq!(shootout.winner(), Some(Color::Black));
+        assert_eq!(
+            shootout.tally(Color::Black),
+            ShootoutTally { shots: 2, goals: 2 }
+        );
+        assert_eq!(
+            shootout.tally(Color::White),
+            ShootoutTally { shots: 2, goals: 0 }
+        );
+
+        // Tied after the regular rounds, so it goes to extra rounds
+        let mut shootout = ShootoutSnapshot {
+            rounds: 2,
+            ..Default::default()
+        };
+        for scored in [true, true, false, false] {
+            shoot(&mut shootout, scored);
+        }
+        assert_eq!(shootout.winner(), None);
+        shoot(&mut shootout, false);
+        assert_eq!(shootout.winner(), None);
+        shoot(&mut shootout, true);
+        assert_eq!(shootout.winner(), Some(Color::White));
+    }
+
+    #[test]
+    fn test_penalty_paging() {
+        let pen = |player_number, secs| PenaltySnapshot {
+            player_number,
+            time: PenaltyTime::Seconds(secs),
+            infraction: Infraction::Unknown,
+        };
+        let nums = |pens: &ArrayVec<PenaltySnapshot, PANEL_PENALTY_COUNT>| {
+            pens.iter().map(|pen| pen.player_number).collect::<Vec<_>>()
+        };
+
+        let mut snapshot = GameSnapshot {
+            b_penalties: vec![pen(1, 30), pen(2, 10), pen(3, 20)],
+            w_penalties: vec![pen(4, 30), pen(5, 10), pen(6, 20), pen(7, 40), pen(8, 0)],
+            ..Default::default()
+        };
+
+        // Three penalties fit, so there is no paging
+        let paged = GameSnapshotNoHeap::from_page(snapshot.clone(), 1);
+        assert_eq!(nums(&paged.b_penalties), vec![2, 3, 1]);
+        assert_eq!(paged.b_hidden_penalties, 0);
+
+        // Four active penalties are shown two at a time, leaving room for the indicator
+        let paged = GameSnapshotNoHeap::from(snapshot.clone());
+        assert_eq!(nums(&paged.w_penalties), vec![5, 6]);
+        assert_eq!(paged.w_hidden_penalties, 2);
+        let paged = GameSnapshotNoHeap::from_page(snapshot.clone(), 1);
+        assert_eq!(nums(&paged.w_penalties), vec![4, 7]);
+        assert_eq!(paged.w_hidden_penalties, 2);
+        let paged = GameSnapshotNoHeap::from_page(snapshot.clone(), 2);
+        assert_eq!(nums(&paged.w_penalties), vec![5, 6]);
+
+        // The legacy frame has no indicator, so every slot is used
+        let paged = GameSnapshotNoHeap::from_legacy_page(snapshot.clone(), 0);
+        assert_eq!(nums(&paged.w_penalties), vec![5, 6, 4]);
+        assert_eq!(paged.w_hidden_penalties, 0);
+        let paged = GameSnapshotNoHeap::from_legacy_page(snapshot.clone(), 1);
+        assert_eq!(nums(&paged.w_penalties), vec![7]);
+
+        // A two digit score leaves room for only two penalties
+        snapshot.b_score = 10;
+        let paged = GameSnapshotNoHeap::from_page(snapshot.clone(), 0);
+        assert_eq!(nums(&paged.b_penalties), vec![2]);
+        assert_eq!(paged.b_hidden_penalties, 2);
+        let paged = GameSnapshotNoHeap::from_page(snapshot, 2);
+        assert_eq!(nums(&paged.b_penalties), vec![1]);
+        assert_eq!(paged.b_hidden_penalties, 2);
+    }
+
+    #[test]
+    fn test_infraction_serialization() {
+        let built_in = InfractionSnapshot {
+            player_number: Some(3),
+            infraction: Infraction::StickInfringement,
+        };
+        let serialized = serde_json::to_string(&built_in).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"player_number":3,"infraction":"StickInfringement"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<InfractionSnapshot>(&serialized).unwrap(),
+            built_in
+        );
+
+        let custom = InfractionSnapshot {
+            player_number: None,
+            infraction: Infraction::Custom(4),
+        };
+        let serialized = serde_json::to_string(&custom).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"player_number":null,"infraction":{"Custom":4}}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<InfractionSnapshot>(&serialized).unwrap(),
+            custom
+        );
+    }
 }